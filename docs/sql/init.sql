@@ -13,6 +13,10 @@ CREATE TABLE IF NOT EXISTS users (
     password_hash VARCHAR(255) NOT NULL,
     nickname VARCHAR(100),
     avatar VARCHAR(500),
+    locale VARCHAR(10) NULL DEFAULT NULL,
+    utc_offset INT NULL DEFAULT NULL,
+    week_start TINYINT UNSIGNED NULL DEFAULT NULL,
+    month_start_day TINYINT UNSIGNED NULL DEFAULT NULL,
     created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
     updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
     INDEX idx_email (email)
@@ -49,6 +53,17 @@ CREATE TABLE IF NOT EXISTS group_members (
     INDEX idx_user (user_id)
 ) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
 
+-- ================================
+-- 群组权限策略表 (无记录时使用默认策略)
+-- ================================
+CREATE TABLE IF NOT EXISTS group_policies (
+    group_id VARCHAR(36) PRIMARY KEY,
+    admin_capabilities VARCHAR(255) NOT NULL,
+    member_capabilities VARCHAR(255) NOT NULL,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    FOREIGN KEY (group_id) REFERENCES `groups`(id) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
+
 -- ================================
 -- 账本表
 -- ================================
@@ -62,10 +77,12 @@ CREATE TABLE IF NOT EXISTS ledgers (
     group_id VARCHAR(36),
     created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
     updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    deleted_at TIMESTAMP NULL DEFAULT NULL,
     FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
     FOREIGN KEY (group_id) REFERENCES `groups`(id) ON DELETE CASCADE,
     INDEX idx_user (user_id),
-    INDEX idx_group (group_id)
+    INDEX idx_group (group_id),
+    INDEX idx_deleted_at (deleted_at)
 ) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
 
 -- ================================
@@ -80,10 +97,13 @@ CREATE TABLE IF NOT EXISTS categories (
     ledger_id VARCHAR(36),
     sort_order INT DEFAULT 0,
     created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    deleted_at TIMESTAMP NULL DEFAULT NULL,
+    archived_at TIMESTAMP NULL DEFAULT NULL,
     FOREIGN KEY (parent_id) REFERENCES categories(id) ON DELETE SET NULL,
     FOREIGN KEY (ledger_id) REFERENCES ledgers(id) ON DELETE CASCADE,
     INDEX idx_type (type),
-    INDEX idx_ledger (ledger_id)
+    INDEX idx_ledger (ledger_id),
+    INDEX idx_deleted_at (deleted_at)
 ) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
 
 -- ================================
@@ -98,15 +118,135 @@ CREATE TABLE IF NOT EXISTS bills (
     amount DECIMAL(15, 2) NOT NULL,
     note TEXT,
     bill_date DATE NOT NULL,
+    bill_time TIME NULL DEFAULT NULL,
     created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
     updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    deleted_at TIMESTAMP NULL DEFAULT NULL,
     FOREIGN KEY (ledger_id) REFERENCES ledgers(id) ON DELETE CASCADE,
     FOREIGN KEY (category_id) REFERENCES categories(id) ON DELETE RESTRICT,
     FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
     INDEX idx_ledger_date (ledger_id, bill_date),
     INDEX idx_user (user_id),
     INDEX idx_category (category_id),
-    INDEX idx_type (type)
+    INDEX idx_type (type),
+    INDEX idx_deleted_at (deleted_at),
+    FULLTEXT INDEX ft_note (note) WITH PARSER ngram
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
+
+-- ================================
+-- 账单附件表 (文件本体存放在 BlobStore 中)
+-- ================================
+CREATE TABLE IF NOT EXISTS bill_attachments (
+    id VARCHAR(36) PRIMARY KEY,
+    bill_id VARCHAR(36) NOT NULL,
+    user_id VARCHAR(36) NOT NULL,
+    file_name VARCHAR(255) NOT NULL,
+    content_type VARCHAR(100) NOT NULL,
+    size BIGINT UNSIGNED NOT NULL,
+    storage_key VARCHAR(255) NOT NULL,
+    thumbnail_key VARCHAR(255),
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (bill_id) REFERENCES bills(id) ON DELETE CASCADE,
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    INDEX idx_bill (bill_id)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
+
+-- ================================
+-- 审计日志表 (只追加，不设外键以保留已清除数据的历史)
+-- ================================
+CREATE TABLE IF NOT EXISTS audit_events (
+    id VARCHAR(36) PRIMARY KEY,
+    entity_type VARCHAR(20) NOT NULL,
+    entity_id VARCHAR(36) NOT NULL,
+    action VARCHAR(20) NOT NULL,
+    actor_id VARCHAR(36) NOT NULL,
+    group_id VARCHAR(36),
+    changes JSON NOT NULL,
+    created_at TIMESTAMP(6) DEFAULT CURRENT_TIMESTAMP(6),
+    INDEX idx_entity (entity_type, entity_id, created_at),
+    INDEX idx_group (group_id, created_at)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
+
+-- ================================
+-- 自动分类规则表 (条件与动作为 JSON)
+-- ================================
+CREATE TABLE IF NOT EXISTS category_rules (
+    id VARCHAR(36) PRIMARY KEY,
+    ledger_id VARCHAR(36) NOT NULL,
+    name VARCHAR(50) NOT NULL,
+    priority INT NOT NULL DEFAULT 0,
+    enabled BOOLEAN NOT NULL DEFAULT TRUE,
+    conditions JSON NOT NULL,
+    actions JSON NOT NULL,
+    created_by VARCHAR(36) NOT NULL,
+    created_at TIMESTAMP(6) DEFAULT CURRENT_TIMESTAMP(6),
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    FOREIGN KEY (ledger_id) REFERENCES ledgers(id) ON DELETE CASCADE,
+    INDEX idx_ledger_priority (ledger_id, priority)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
+
+-- ================================
+-- 保存的账单视图表 (筛选条件为 JSON)
+-- ================================
+CREATE TABLE IF NOT EXISTS saved_views (
+    id VARCHAR(36) PRIMARY KEY,
+    user_id VARCHAR(36) NOT NULL,
+    ledger_id VARCHAR(36) NOT NULL,
+    name VARCHAR(50) NOT NULL,
+    filter JSON NOT NULL,
+    shared BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at TIMESTAMP(6) DEFAULT CURRENT_TIMESTAMP(6),
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    FOREIGN KEY (ledger_id) REFERENCES ledgers(id) ON DELETE CASCADE,
+    INDEX idx_ledger (ledger_id)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
+
+-- ================================
+-- 通知表
+-- ================================
+CREATE TABLE IF NOT EXISTS notifications (
+    id VARCHAR(36) PRIMARY KEY,
+    user_id VARCHAR(36) NOT NULL,
+    kind VARCHAR(20) NOT NULL,
+    ledger_id VARCHAR(36),
+    bill_id VARCHAR(36),
+    data JSON NOT NULL,
+    read_at TIMESTAMP(6) NULL DEFAULT NULL,
+    created_at TIMESTAMP(6) DEFAULT CURRENT_TIMESTAMP(6),
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    INDEX idx_user (user_id, created_at)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
+
+-- ================================
+-- 资产与负债表（手动估值）
+-- ================================
+CREATE TABLE IF NOT EXISTS assets (
+    id VARCHAR(36) PRIMARY KEY,
+    user_id VARCHAR(36) NOT NULL,
+    name VARCHAR(100) NOT NULL,
+    kind ENUM('asset', 'liability') NOT NULL,
+    currency VARCHAR(10) DEFAULT 'CNY',
+    value DECIMAL(15, 2) NOT NULL,
+    created_at TIMESTAMP(6) DEFAULT CURRENT_TIMESTAMP(6),
+    updated_at TIMESTAMP(6) DEFAULT CURRENT_TIMESTAMP(6),
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    INDEX idx_user (user_id)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
+
+-- ================================
+-- 净资产快照表（按币种）
+-- ================================
+CREATE TABLE IF NOT EXISTS net_worth_snapshots (
+    id VARCHAR(36) PRIMARY KEY,
+    user_id VARCHAR(36) NOT NULL,
+    snapshot_date DATE NOT NULL,
+    currency VARCHAR(10) NOT NULL,
+    assets DECIMAL(15, 2) NOT NULL,
+    liabilities DECIMAL(15, 2) NOT NULL,
+    created_at TIMESTAMP(6) DEFAULT CURRENT_TIMESTAMP(6),
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    UNIQUE KEY uk_user_date_currency (user_id, snapshot_date, currency)
 ) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
 
 -- ================================
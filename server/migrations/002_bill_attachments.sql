@@ -0,0 +1,19 @@
+-- Bill attachments (receipts, invoices)
+-- File contents live in the configured blob store; this table only keeps metadata.
+
+USE money_notes;
+
+CREATE TABLE IF NOT EXISTS bill_attachments (
+    id VARCHAR(36) PRIMARY KEY,
+    bill_id VARCHAR(36) NOT NULL,
+    user_id VARCHAR(36) NOT NULL,
+    file_name VARCHAR(255) NOT NULL,
+    content_type VARCHAR(100) NOT NULL,
+    size BIGINT UNSIGNED NOT NULL,
+    storage_key VARCHAR(255) NOT NULL,
+    thumbnail_key VARCHAR(255),
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (bill_id) REFERENCES bills(id) ON DELETE CASCADE,
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    INDEX idx_bill (bill_id)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
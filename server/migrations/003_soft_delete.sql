@@ -0,0 +1,16 @@
+-- Soft delete for bills, categories and ledgers
+-- Rows with deleted_at set are in the trash and are purged after the retention period.
+
+USE money_notes;
+
+ALTER TABLE bills
+    ADD COLUMN deleted_at TIMESTAMP NULL DEFAULT NULL,
+    ADD INDEX idx_deleted_at (deleted_at);
+
+ALTER TABLE categories
+    ADD COLUMN deleted_at TIMESTAMP NULL DEFAULT NULL,
+    ADD INDEX idx_deleted_at (deleted_at);
+
+ALTER TABLE ledgers
+    ADD COLUMN deleted_at TIMESTAMP NULL DEFAULT NULL,
+    ADD INDEX idx_deleted_at (deleted_at);
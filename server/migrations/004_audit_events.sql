@@ -0,0 +1,17 @@
+-- Append-only audit log of changes to bills, ledgers, categories and group membership
+-- No foreign keys: history must survive the entities being purged.
+
+USE money_notes;
+
+CREATE TABLE IF NOT EXISTS audit_events (
+    id VARCHAR(36) PRIMARY KEY,
+    entity_type VARCHAR(20) NOT NULL,
+    entity_id VARCHAR(36) NOT NULL,
+    action VARCHAR(20) NOT NULL,
+    actor_id VARCHAR(36) NOT NULL,
+    group_id VARCHAR(36),
+    changes JSON NOT NULL,
+    created_at TIMESTAMP(6) DEFAULT CURRENT_TIMESTAMP(6),
+    INDEX idx_entity (entity_type, entity_id, created_at),
+    INDEX idx_group (group_id, created_at)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
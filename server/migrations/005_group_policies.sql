@@ -0,0 +1,13 @@
+-- Per-group permission policies
+-- Capabilities are stored as comma separated lists, e.g. "create,edit_own".
+-- Groups without a row use the built-in defaults.
+
+USE money_notes;
+
+CREATE TABLE IF NOT EXISTS group_policies (
+    group_id VARCHAR(36) PRIMARY KEY,
+    admin_capabilities VARCHAR(255) NOT NULL,
+    member_capabilities VARCHAR(255) NOT NULL,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    FOREIGN KEY (group_id) REFERENCES `groups`(id) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
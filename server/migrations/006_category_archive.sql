@@ -0,0 +1,7 @@
+-- Archived categories
+-- Archived categories are hidden from pickers but stay attached to existing bills.
+
+USE money_notes;
+
+ALTER TABLE categories
+    ADD COLUMN archived_at TIMESTAMP NULL DEFAULT NULL;
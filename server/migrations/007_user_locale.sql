@@ -0,0 +1,7 @@
+-- Per-user language preference
+-- Overrides the Accept-Language header, NULL means follow the header.
+
+USE money_notes;
+
+ALTER TABLE users
+    ADD COLUMN locale VARCHAR(10) NULL DEFAULT NULL;
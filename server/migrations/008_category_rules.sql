@@ -0,0 +1,19 @@
+-- Auto-categorization rules per ledger
+-- Conditions and actions are JSON documents, see models/rule.rs.
+
+USE money_notes;
+
+CREATE TABLE IF NOT EXISTS category_rules (
+    id VARCHAR(36) PRIMARY KEY,
+    ledger_id VARCHAR(36) NOT NULL,
+    name VARCHAR(50) NOT NULL,
+    priority INT NOT NULL DEFAULT 0,
+    enabled BOOLEAN NOT NULL DEFAULT TRUE,
+    conditions JSON NOT NULL,
+    actions JSON NOT NULL,
+    created_by VARCHAR(36) NOT NULL,
+    created_at TIMESTAMP(6) DEFAULT CURRENT_TIMESTAMP(6),
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    FOREIGN KEY (ledger_id) REFERENCES ledgers(id) ON DELETE CASCADE,
+    INDEX idx_ledger_priority (ledger_id, priority)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
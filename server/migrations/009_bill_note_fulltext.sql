@@ -0,0 +1,6 @@
+-- Keyword search over bill notes
+-- The ngram parser splits CJK text without spaces; tokens are ngram_token_size (default 2) characters.
+
+USE money_notes;
+
+ALTER TABLE bills ADD FULLTEXT INDEX ft_note (note) WITH PARSER ngram;
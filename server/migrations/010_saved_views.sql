@@ -0,0 +1,18 @@
+-- Saved bill listing filters per user
+-- The filter is a JSON document, see models/saved_view.rs. Shared views are visible to the ledger's members.
+
+USE money_notes;
+
+CREATE TABLE IF NOT EXISTS saved_views (
+    id VARCHAR(36) PRIMARY KEY,
+    user_id VARCHAR(36) NOT NULL,
+    ledger_id VARCHAR(36) NOT NULL,
+    name VARCHAR(50) NOT NULL,
+    filter JSON NOT NULL,
+    shared BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at TIMESTAMP(6) DEFAULT CURRENT_TIMESTAMP(6),
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    FOREIGN KEY (ledger_id) REFERENCES ledgers(id) ON DELETE CASCADE,
+    INDEX idx_ledger (ledger_id)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
@@ -0,0 +1,11 @@
+-- Per-user calendar preferences
+-- Where "today", weeks and months start for statistics and relative periods.
+-- utc_offset is in minutes east of UTC, week_start 1 (Monday) to 7 (Sunday), month_start_day 1 to 28.
+-- NULL means UTC, Monday and the 1st.
+
+USE money_notes;
+
+ALTER TABLE users
+    ADD COLUMN utc_offset INT NULL DEFAULT NULL,
+    ADD COLUMN week_start TINYINT UNSIGNED NULL DEFAULT NULL,
+    ADD COLUMN month_start_day TINYINT UNSIGNED NULL DEFAULT NULL;
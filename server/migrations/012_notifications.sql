@@ -0,0 +1,17 @@
+-- Per-user notifications, such as alerts about unusual new bills
+-- `data` holds the details for the client to render and depends on `kind`.
+
+USE money_notes;
+
+CREATE TABLE IF NOT EXISTS notifications (
+    id VARCHAR(36) PRIMARY KEY,
+    user_id VARCHAR(36) NOT NULL,
+    kind VARCHAR(20) NOT NULL,
+    ledger_id VARCHAR(36),
+    bill_id VARCHAR(36),
+    data JSON NOT NULL,
+    read_at TIMESTAMP(6) NULL DEFAULT NULL,
+    created_at TIMESTAMP(6) DEFAULT CURRENT_TIMESTAMP(6),
+    INDEX idx_user (user_id, created_at),
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
@@ -0,0 +1,7 @@
+-- Optional time of day on bills
+-- In the local time of whoever entered the bill; NULL when only the date is known.
+
+USE money_notes;
+
+ALTER TABLE bills
+    ADD COLUMN bill_time TIME NULL DEFAULT NULL AFTER bill_date;
@@ -0,0 +1,30 @@
+-- Assets, liabilities and net worth snapshots
+-- Assets are valued by hand, e.g. property or a loan, on top of the ledgers' balances.
+-- Snapshots are kept per currency, amounts in different currencies are never added up.
+
+USE money_notes;
+
+CREATE TABLE IF NOT EXISTS assets (
+    id VARCHAR(36) PRIMARY KEY,
+    user_id VARCHAR(36) NOT NULL,
+    name VARCHAR(100) NOT NULL,
+    kind ENUM('asset', 'liability') NOT NULL,
+    currency VARCHAR(10) DEFAULT 'CNY',
+    value DECIMAL(15, 2) NOT NULL,
+    created_at TIMESTAMP(6) DEFAULT CURRENT_TIMESTAMP(6),
+    updated_at TIMESTAMP(6) DEFAULT CURRENT_TIMESTAMP(6),
+    INDEX idx_user (user_id),
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
+
+CREATE TABLE IF NOT EXISTS net_worth_snapshots (
+    id VARCHAR(36) PRIMARY KEY,
+    user_id VARCHAR(36) NOT NULL,
+    snapshot_date DATE NOT NULL,
+    currency VARCHAR(10) NOT NULL,
+    assets DECIMAL(15, 2) NOT NULL,
+    liabilities DECIMAL(15, 2) NOT NULL,
+    created_at TIMESTAMP(6) DEFAULT CURRENT_TIMESTAMP(6),
+    UNIQUE KEY uk_user_date_currency (user_id, snapshot_date, currency),
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
@@ -0,0 +1,192 @@
+use axum::{
+    extract::{Multipart, Path, State},
+    http::header,
+    response::{IntoResponse, Response},
+    Extension, Json,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::dto::{ApiResponse, AttachmentListResponse, AttachmentResponse};
+use crate::error::{AppError, AppResult};
+use crate::middleware::CurrentUser;
+use crate::models::{Attachment, Bill};
+use crate::repositories::BillRepository;
+use crate::services::{AttachmentService, LedgerAccess, PermissionService};
+
+pub struct AttachmentApi {
+    attachment_service: Arc<AttachmentService>,
+    bill_repo: Arc<dyn BillRepository>,
+    permission_service: Arc<PermissionService>,
+}
+
+impl AttachmentApi {
+    pub fn new(
+        attachment_service: Arc<AttachmentService>,
+        bill_repo: Arc<dyn BillRepository>,
+        permission_service: Arc<PermissionService>,
+    ) -> Self {
+        Self {
+            attachment_service,
+            bill_repo,
+            permission_service,
+        }
+    }
+
+    pub fn max_file_size(&self) -> u64 {
+        self.attachment_service.max_file_size()
+    }
+
+    /// Load a bill whose ledger the current user can at least view
+    async fn accessible_bill(&self, bill_id: &str, current_user: &CurrentUser) -> AppResult<(Bill, LedgerAccess)> {
+        let bill_id = Uuid::parse_str(bill_id)
+            .map_err(|_| AppError::Validation("Invalid bill ID".to_string()))?;
+
+        let bill = self
+            .bill_repo
+            .find_by_id(bill_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Bill not found".to_string()))?;
+
+        let access = self.permission_service.ledger_access(bill.ledger_id, current_user.id).await?;
+
+        Ok((bill, access))
+    }
+
+    async fn bill_attachment(&self, bill: &Bill, attachment_id: &str) -> AppResult<Attachment> {
+        let attachment_id = Uuid::parse_str(attachment_id)
+            .map_err(|_| AppError::Validation("Invalid attachment ID".to_string()))?;
+
+        self.attachment_service
+            .find_by_id(attachment_id)
+            .await?
+            .filter(|a| a.bill_id == bill.id)
+            .ok_or_else(|| AppError::NotFound("Attachment not found".to_string()))
+    }
+}
+
+fn attachment_to_response(attachment: &Attachment) -> AttachmentResponse {
+    AttachmentResponse {
+        id: attachment.id.to_string(),
+        bill_id: attachment.bill_id.to_string(),
+        file_name: attachment.file_name.clone(),
+        content_type: attachment.content_type.clone(),
+        size: attachment.size,
+        has_thumbnail: attachment.thumbnail_key.is_some(),
+        uploaded_by: attachment.user_id.to_string(),
+        created_at: attachment.created_at.to_rfc3339(),
+    }
+}
+
+fn file_response(data: Vec<u8>, content_type: &str, file_name: &str) -> Response {
+    let disposition = format!("inline; filename*=UTF-8''{}", encode_rfc5987(file_name));
+    (
+        [
+            (header::CONTENT_TYPE, content_type.to_string()),
+            (header::CONTENT_DISPOSITION, disposition),
+        ],
+        data,
+    )
+        .into_response()
+}
+
+/// Encode a file name for the `filename*` parameter of Content-Disposition
+fn encode_rfc5987(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => (b as char).to_string(),
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+pub async fn list_attachments(
+    State(api): State<Arc<AttachmentApi>>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<String>,
+) -> AppResult<Json<ApiResponse<AttachmentListResponse>>> {
+    let (bill, _) = api.accessible_bill(&id, &current_user).await?;
+
+    let attachments = api.attachment_service.find_by_bill_id(bill.id).await?;
+
+    Ok(Json(ApiResponse::success(AttachmentListResponse {
+        items: attachments.iter().map(attachment_to_response).collect(),
+    })))
+}
+
+pub async fn upload_attachment(
+    State(api): State<Arc<AttachmentApi>>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<String>,
+    mut multipart: Multipart,
+) -> AppResult<Json<ApiResponse<AttachmentResponse>>> {
+    let (bill, access) = api.accessible_bill(&id, &current_user).await?;
+    // Attaching a file changes the bill
+    access.require_edit_bill(&bill)?;
+
+    while let Some(field) = multipart
+        .next_field()
+        .await
+        .map_err(|e| AppError::Validation(format!("Invalid multipart body: {}", e)))?
+    {
+        if field.name() != Some("file") {
+            continue;
+        }
+
+        let file_name = field.file_name().unwrap_or("attachment").to_string();
+        let data = field
+            .bytes()
+            .await
+            .map_err(|e| AppError::Validation(format!("Failed to read upload: {}", e)))?;
+
+        let attachment = api
+            .attachment_service
+            .upload(bill.id, current_user.id, &file_name, data.to_vec())
+            .await?;
+
+        return Ok(Json(ApiResponse::success(attachment_to_response(&attachment))));
+    }
+
+    Err(AppError::Validation("Missing file field".to_string()))
+}
+
+pub async fn download_attachment(
+    State(api): State<Arc<AttachmentApi>>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path((id, attachment_id)): Path<(String, String)>,
+) -> AppResult<Response> {
+    let (bill, _) = api.accessible_bill(&id, &current_user).await?;
+    let attachment = api.bill_attachment(&bill, &attachment_id).await?;
+
+    let data = api.attachment_service.read(&attachment).await?;
+
+    Ok(file_response(data, &attachment.content_type, &attachment.file_name))
+}
+
+pub async fn get_attachment_thumbnail(
+    State(api): State<Arc<AttachmentApi>>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path((id, attachment_id)): Path<(String, String)>,
+) -> AppResult<Response> {
+    let (bill, _) = api.accessible_bill(&id, &current_user).await?;
+    let attachment = api.bill_attachment(&bill, &attachment_id).await?;
+
+    let (data, content_type) = api.attachment_service.read_thumbnail(&attachment).await?;
+
+    Ok(file_response(data, content_type, &attachment.file_name))
+}
+
+pub async fn delete_attachment(
+    State(api): State<Arc<AttachmentApi>>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path((id, attachment_id)): Path<(String, String)>,
+) -> AppResult<Json<ApiResponse<()>>> {
+    let (bill, access) = api.accessible_bill(&id, &current_user).await?;
+    access.require_edit_bill(&bill)?;
+    let attachment = api.bill_attachment(&bill, &attachment_id).await?;
+
+    api.attachment_service.delete(&attachment).await?;
+
+    Ok(Json(ApiResponse::success(())))
+}
@@ -0,0 +1,143 @@
+use axum::{
+    extract::{Path, Query, State},
+    Extension, Json,
+};
+use std::collections::{hash_map::Entry, HashMap};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::dto::{
+    ApiResponse, AuditEventListResponse, AuditEventResponse, GroupActivityResponse,
+    PaginationResponse, UserBriefResponse,
+};
+use crate::error::{AppError, AppResult};
+use crate::middleware::CurrentUser;
+use crate::models::{AuditEntityType, AuditEvent};
+use crate::repositories::{BillRepository, GroupRepository, LedgerRepository, UserRepository};
+use crate::services::AuditService;
+
+pub struct AuditApi {
+    audit_service: Arc<AuditService>,
+    bill_repo: Arc<dyn BillRepository>,
+    ledger_repo: Arc<dyn LedgerRepository>,
+    group_repo: Arc<dyn GroupRepository>,
+    user_repo: Arc<dyn UserRepository>,
+}
+
+impl AuditApi {
+    pub fn new(
+        audit_service: Arc<AuditService>,
+        bill_repo: Arc<dyn BillRepository>,
+        ledger_repo: Arc<dyn LedgerRepository>,
+        group_repo: Arc<dyn GroupRepository>,
+        user_repo: Arc<dyn UserRepository>,
+    ) -> Self {
+        Self {
+            audit_service,
+            bill_repo,
+            ledger_repo,
+            group_repo,
+            user_repo,
+        }
+    }
+
+    /// Resolve actors once per user rather than once per event
+    async fn to_responses(&self, events: Vec<AuditEvent>) -> AppResult<Vec<AuditEventResponse>> {
+        let mut actors: HashMap<Uuid, Option<String>> = HashMap::new();
+        let mut items = Vec::with_capacity(events.len());
+
+        for event in events {
+            if let Entry::Vacant(entry) = actors.entry(event.actor_id) {
+                let nickname = self.user_repo.find_by_id(event.actor_id).await?.and_then(|u| u.nickname);
+                entry.insert(nickname);
+            }
+
+            items.push(AuditEventResponse {
+                id: event.id.to_string(),
+                entity_type: event.entity_type.to_string(),
+                entity_id: event.entity_id.to_string(),
+                action: event.action.to_string(),
+                actor: UserBriefResponse {
+                    id: event.actor_id.to_string(),
+                    nickname: actors[&event.actor_id].clone(),
+                },
+                changes: event.changes,
+                created_at: event.created_at.to_rfc3339(),
+            });
+        }
+
+        Ok(items)
+    }
+}
+
+pub async fn get_bill_history(
+    State(api): State<Arc<AuditApi>>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<String>,
+) -> AppResult<Json<ApiResponse<AuditEventListResponse>>> {
+    let bill_id = Uuid::parse_str(&id)
+        .map_err(|_| AppError::Validation("Invalid bill ID".to_string()))?;
+
+    // History stays visible while the bill or its ledger is in the trash
+    let bill = match api.bill_repo.find_by_id(bill_id).await? {
+        Some(bill) => Some(bill),
+        None => api.bill_repo.find_trashed_by_id(bill_id).await?,
+    }
+    .ok_or_else(|| AppError::NotFound("Bill not found".to_string()))?;
+
+    let ledger = match api.ledger_repo.find_by_id(bill.ledger_id).await? {
+        Some(ledger) => Some(ledger),
+        None => api.ledger_repo.find_trashed_by_id(bill.ledger_id).await?,
+    }
+    .ok_or_else(|| AppError::NotFound("Ledger not found".to_string()))?;
+
+    let has_access = match ledger.group_id {
+        Some(group_id) => api.group_repo.get_member(group_id, current_user.id).await?.is_some(),
+        None => ledger.user_id == Some(current_user.id),
+    };
+    if !has_access {
+        return Err(AppError::Forbidden("Access denied".to_string()));
+    }
+
+    let events = api.audit_service.history(AuditEntityType::Bill, bill_id).await?;
+
+    Ok(Json(ApiResponse::success(AuditEventListResponse {
+        items: api.to_responses(events).await?,
+    })))
+}
+
+pub async fn get_group_activity(
+    State(api): State<Arc<AuditApi>>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<String>,
+    Query(params): Query<ActivityParams>,
+) -> AppResult<Json<ApiResponse<GroupActivityResponse>>> {
+    let group_id = Uuid::parse_str(&id)
+        .map_err(|_| AppError::Validation("Invalid group ID".to_string()))?;
+
+    if api.group_repo.get_member(group_id, current_user.id).await?.is_none() {
+        return Err(AppError::Forbidden("Not a member of this group".to_string()));
+    }
+
+    let page = params.page.unwrap_or(1).max(1);
+    let page_size = params.page_size.unwrap_or(20).clamp(1, 100);
+
+    let (events, total) = api.audit_service.group_activity(group_id, page, page_size).await?;
+    let total_pages = ((total as f64) / (page_size as f64)).ceil() as u32;
+
+    Ok(Json(ApiResponse::success(GroupActivityResponse {
+        items: api.to_responses(events).await?,
+        pagination: PaginationResponse {
+            page,
+            page_size,
+            total,
+            total_pages,
+        },
+    })))
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct ActivityParams {
+    pub page: Option<u32>,
+    pub page_size: Option<u32>,
+}
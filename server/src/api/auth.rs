@@ -4,8 +4,9 @@ use validator::Validate;
 
 use crate::dto::{
     ApiResponse, AuthResponse, LoginRequest, RefreshTokenRequest, RefreshTokenResponse,
-    RegisterRequest, UserResponse,
+    RegisterRequest,
 };
+use crate::api::user::user_response;
 use crate::error::{AppError, AppResult};
 use crate::services::AuthService;
 
@@ -36,13 +37,7 @@ pub async fn register(
         .await?;
 
     Ok(Json(ApiResponse::success(AuthResponse {
-        user: UserResponse {
-            id: user.id.to_string(),
-            email: user.email,
-            nickname: user.nickname,
-            avatar: user.avatar,
-            created_at: user.created_at.to_rfc3339(),
-        },
+        user: user_response(user),
         access_token,
         refresh_token,
         expires_in: api.expires_in,
@@ -62,13 +57,7 @@ pub async fn login(
         .await?;
 
     Ok(Json(ApiResponse::success(AuthResponse {
-        user: UserResponse {
-            id: user.id.to_string(),
-            email: user.email,
-            nickname: user.nickname,
-            avatar: user.avatar,
-            created_at: user.created_at.to_rfc3339(),
-        },
+        user: user_response(user),
         access_token,
         refresh_token,
         expires_in: api.expires_in,
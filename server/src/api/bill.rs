@@ -2,186 +2,763 @@ use axum::{
     extract::{Path, Query, State},
     Extension, Json,
 };
-use chrono::{Datelike, Duration, NaiveDate, Utc};
+use chrono::{Datelike, Duration, Months, NaiveDate, NaiveTime};
+use std::collections::{hash_map::Entry, BTreeMap, HashMap, HashSet};
 use std::sync::Arc;
 use uuid::Uuid;
+use validator::Validate;
 
+use crate::api::view::find_visible_view;
 use crate::dto::{
-    ApiResponse, BillListResponse, BillQueryParams, BillResponse, BillStatisticsResponse,
-    CategoryBriefResponse, CategoryStatisticsResponse, CreateBillRequest, DailyStatisticsResponse,
-    FullStatisticsResponse, PaginationResponse, TrendStatisticsResponse, UpdateBillRequest,
-    UserBriefResponse,
+    AnomalyListResponse, AnomalyResponse, ApiResponse, BatchBillFilter, BatchBillRequest,
+    BatchBillResponse, BatchItemErrorResponse, BatchItemResultResponse, BillListResponse,
+    BillQueryParams, BillResponse, BillStatisticsResponse, CategoryBriefResponse,
+    CategoryComparisonResponse, CategoryStatisticsNodeResponse, CategoryStatisticsResponse,
+    CategorySuggestionListResponse, CategorySuggestionResponse, ComparisonStatisticsResponse,
+    ConsolidatedLedgerResponse, ConsolidatedStatisticsResponse, CreateBillRequest,
+    DailyStatisticsResponse, DeltaResponse, DuplicateClusterListResponse, DuplicateClusterResponse,
+    DuplicateQueryParams, ForecastCategoryResponse, ForecastDayResponse, ForecastResponse,
+    FullStatisticsResponse, HeatmapDayResponse, HeatmapResponse, MemberCategoryStatisticsResponse,
+    MemberStatisticsListResponse, MemberStatisticsResponse, PaginationResponse,
+    PatternStatisticsResponse, PeriodStatisticsResponse, QuickBillPreviewResponse,
+    QuickBillRequest, QuickBillResponse, RecurringItemResponse, SummaryDeltaResponse,
+    TrendStatisticsResponse, UpdateBillRequest, UserBriefResponse, ViewBillsParams,
+    WeekdayAverageResponse, WeekdayHourResponse,
 };
 use crate::error::{AppError, AppResult};
-use crate::middleware::CurrentUser;
-use crate::models::{BillType, CreateBill, UpdateBill};
-use crate::repositories::{BillFilter, BillRepository, CategoryRepository, LedgerRepository, UserRepository};
+use crate::i18n::{default_category_name, translate_message, Locale};
+use crate::middleware::{CurrentUser, RequestLocale};
+use crate::models::{
+    AuditEntityType, Bill, BillSort, BillType, Category, CreateBill, GroupCapability, Ledger,
+    RuleInput, UpdateBill, ViewFilter,
+};
+use crate::repositories::{
+    BillFilter, BillRepository, CategoryRepository, CategoryStatistics, SavedViewRepository,
+    SearchTerm, UserRepository,
+};
+use crate::services::{
+    AnomalyService, AuditService, DuplicateService, ForecastService, LedgerAccess,
+    PermissionService, QuickEntryService, RuleService, SuggestionService,
+};
+use crate::utils::{round_cents, tokenize, Calendar};
+
+const MAX_BATCH_SIZE: usize = 500;
+/// Longest range the spending patterns cover, a calendar heatmap is at most a year
+const PATTERN_MAX_DAYS: i64 = 366;
+
+/// A batch item as checked before anything is written, labelled with the bill ID when known
+type BatchItem<T> = (Option<String>, AppResult<T>);
+
+/// An edit that passed its checks, with the destination's access when the bill changes ledger
+struct PreparedUpdate {
+    access: LedgerAccess,
+    destination: Option<LedgerAccess>,
+    existing: Bill,
+    update: UpdateBill,
+}
 
 pub struct BillApi {
     bill_repo: Arc<dyn BillRepository>,
-    ledger_repo: Arc<dyn LedgerRepository>,
     category_repo: Arc<dyn CategoryRepository>,
     user_repo: Arc<dyn UserRepository>,
+    audit_service: Arc<AuditService>,
+    permission_service: Arc<PermissionService>,
+    rule_service: Arc<RuleService>,
+    suggestion_service: Arc<SuggestionService>,
+    quick_entry_service: Arc<QuickEntryService>,
+    duplicate_service: Arc<DuplicateService>,
+    forecast_service: Arc<ForecastService>,
+    anomaly_service: Arc<AnomalyService>,
+    view_repo: Arc<dyn SavedViewRepository>,
 }
 
 impl BillApi {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         bill_repo: Arc<dyn BillRepository>,
-        ledger_repo: Arc<dyn LedgerRepository>,
         category_repo: Arc<dyn CategoryRepository>,
         user_repo: Arc<dyn UserRepository>,
+        audit_service: Arc<AuditService>,
+        permission_service: Arc<PermissionService>,
+        rule_service: Arc<RuleService>,
+        suggestion_service: Arc<SuggestionService>,
+        quick_entry_service: Arc<QuickEntryService>,
+        duplicate_service: Arc<DuplicateService>,
+        forecast_service: Arc<ForecastService>,
+        anomaly_service: Arc<AnomalyService>,
+        view_repo: Arc<dyn SavedViewRepository>,
     ) -> Self {
         Self {
             bill_repo,
-            ledger_repo,
             category_repo,
             user_repo,
+            audit_service,
+            permission_service,
+            rule_service,
+            suggestion_service,
+            quick_entry_service,
+            duplicate_service,
+            forecast_service,
+            anomaly_service,
+            view_repo,
         }
     }
-}
 
-pub async fn list_bills(
-    State(api): State<Arc<BillApi>>,
-    Extension(current_user): Extension<CurrentUser>,
-    Query(params): Query<BillQueryParams>,
-) -> AppResult<Json<ApiResponse<BillListResponse>>> {
-    let ledger_id = Uuid::parse_str(&params.ledger_id)
-        .map_err(|_| AppError::Validation("Invalid ledger ID".to_string()))?;
+    /// The user's calendar preferences, which every relative period and trend bucket follows
+    async fn calendar(&self, user_id: Uuid) -> AppResult<Calendar> {
+        Ok(self.user_repo.find_by_id(user_id).await?.map(|u| u.calendar()).unwrap_or_default())
+    }
 
-    // Check access to ledger
-    let ledger = api
-        .ledger_repo
-        .find_by_id(ledger_id)
-        .await?
-        .ok_or_else(|| AppError::NotFound("Ledger not found".to_string()))?;
+    /// Bills may only use system categories or the ledger's own categories
+    async fn check_category(&self, category_id: Uuid, ledger_id: Uuid) -> AppResult<()> {
+        self.category_repo
+            .find_by_id(category_id)
+            .await?
+            .filter(|c| c.is_usable_in(ledger_id))
+            .map(|_| ())
+            .ok_or_else(|| AppError::Validation("Category not found in this ledger".to_string()))
+    }
+
+    /// Splits a search query into words and finds, for each, the ledger's categories whose
+    /// name (in any language for system categories) contains it
+    async fn search_terms(&self, ledger_id: Uuid, query: &str) -> AppResult<Vec<SearchTerm>> {
+        let mut words = tokenize(query);
+        words.dedup();
+        if words.is_empty() {
+            return Ok(Vec::new());
+        }
 
-    if ledger.user_id != Some(current_user.id) && ledger.group_id.is_none() {
-        return Err(AppError::Forbidden("Access denied".to_string()));
+        let categories = self.category_repo.find_by_ledger_id(Some(ledger_id), None, true).await?;
+        let names: Vec<(Uuid, Vec<String>)> = categories
+            .iter()
+            .map(|category| {
+                let mut names = vec![category.name.to_lowercase()];
+                if let (true, Some(icon)) = (category.is_system(), &category.icon) {
+                    names.extend(
+                        Locale::ALL
+                            .iter()
+                            .filter_map(|locale| default_category_name(icon, *locale))
+                            .map(str::to_lowercase),
+                    );
+                }
+                (category.id, names)
+            })
+            .collect();
+
+        Ok(words
+            .into_iter()
+            .map(|word| SearchTerm {
+                category_ids: names
+                    .iter()
+                    .filter(|(_, names)| names.iter().any(|name| name.contains(&word)))
+                    .map(|(id, _)| *id)
+                    .collect(),
+                word,
+            })
+            .collect())
     }
 
-    let bill_type = params.bill_type.as_ref().and_then(|t| match t.as_str() {
-        "income" => Some(BillType::Income),
-        "expense" => Some(BillType::Expense),
-        _ => None,
-    });
+    /// Reads the form into a bill, letting the ledger's rules fill in the category and rewrite the note
+    async fn prepare_bill(&self, user_id: Uuid, req: CreateBillRequest) -> AppResult<(LedgerAccess, CreateBill)> {
+        let ledger_id = Uuid::parse_str(&req.ledger_id)
+            .map_err(|_| AppError::Validation("Invalid ledger ID".to_string()))?;
+        let category_id = match req.category_id {
+            Some(ref id) => Some(Uuid::parse_str(id).map_err(|_| AppError::Validation("Invalid category ID".to_string()))?),
+            None => None,
+        };
+
+        // Check access to ledger
+        let access = self.permission_service.ledger_access(ledger_id, user_id).await?;
+        access.require(GroupCapability::Create)?;
+
+        let bill_type = parse_bill_type(&req.bill_type)?;
+
+        let bill_date = NaiveDate::parse_from_str(&req.bill_date, "%Y-%m-%d")
+            .map_err(|_| AppError::Validation("Invalid date format".to_string()))?;
+        let bill_time = req.bill_time.as_deref().map(parse_bill_time).transpose()?;
+
+        // An explicit category wins over the rules, a rewritten note always applies
+        let outcome = self
+            .rule_service
+            .evaluate(
+                ledger_id,
+                &RuleInput {
+                    note: req.note.as_deref(),
+                    amount: req.amount,
+                    bill_type,
+                    bill_date,
+                },
+            )
+            .await?;
+        let (rule_category_id, rule_note) = outcome.map(|o| (o.category_id, o.note)).unwrap_or_default();
+
+        let category_id = category_id
+            .or(rule_category_id)
+            .ok_or_else(|| AppError::Validation("Category is required".to_string()))?;
+
+        Ok((
+            access,
+            CreateBill {
+                ledger_id,
+                category_id,
+                user_id,
+                bill_type,
+                amount: req.amount,
+                note: rule_note.or(req.note),
+                bill_date,
+                bill_time,
+            },
+        ))
+    }
 
-    let filter = BillFilter {
-        ledger_id,
-        start_date: params.start_date.as_ref().and_then(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").ok()),
-        end_date: params.end_date.as_ref().and_then(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").ok()),
-        bill_type,
-        category_id: params.category_id.as_ref().and_then(|id| Uuid::parse_str(id).ok()),
-        user_id: None,
-        page: params.page.unwrap_or(1),
-        page_size: params.page_size.unwrap_or(20),
-    };
+    /// Unless `force` is set, a bill that looks entered twice is refused with a conflict
+    async fn check_new_bill(&self, bill: &CreateBill, force: bool) -> AppResult<()> {
+        self.check_category(bill.category_id, bill.ledger_id).await?;
+
+        if !force {
+            let duplicates = self.duplicate_service.find_matches(bill).await?;
+            if !duplicates.is_empty() {
+                let ids: Vec<String> = duplicates.iter().map(|b| b.id.to_string()).collect();
+                return Err(AppError::Conflict(format!("Possible duplicate of bills: {}", ids.join(", "))));
+            }
+        }
+        Ok(())
+    }
 
-    let (bills, total) = api.bill_repo.find_by_filter(filter.clone()).await?;
+    /// Saves a bill whose category is already decided, shared by the form and quick entry
+    async fn insert_bill(
+        &self,
+        access: &LedgerAccess,
+        bill: CreateBill,
+        force: bool,
+        locale: Option<Locale>,
+    ) -> AppResult<BillResponse> {
+        self.check_new_bill(&bill, force).await?;
 
-    let mut items = Vec::new();
-    for bill in bills {
-        let category = api.category_repo.find_by_id(bill.category_id).await?.unwrap();
-        let user = api.user_repo.find_by_id(bill.user_id).await?.unwrap();
+        let bill = self.bill_repo.create(bill).await?;
+
+        self.audit_service
+            .record_create(access.user_id, AuditEntityType::Bill, bill.id, access.ledger.group_id, &bill)
+            .await;
+        self.anomaly_service.notify_if_unusual(vec![bill.clone()], access.ledger.clone());
 
-        items.push(BillResponse {
+        self.bill_response(bill, locale).await
+    }
+
+    async fn bill_response(&self, bill: Bill, locale: Option<Locale>) -> AppResult<BillResponse> {
+        // Either can be purged while the bill is being saved or read
+        let category = self
+            .category_repo
+            .find_by_id(bill.category_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Category not found".to_string()))?;
+        let user = self
+            .user_repo
+            .find_by_id(bill.user_id)
+            .await?
+            .ok_or_else(|| AppError::Internal(format!("Creator of bill {} not found", bill.id)))?;
+
+        Ok(BillResponse {
             id: bill.id.to_string(),
             bill_type: bill.bill_type.to_string(),
             amount: bill.amount,
             category: CategoryBriefResponse {
                 id: category.id.to_string(),
-                name: category.name,
+                name: category.localized_name(locale),
                 icon: category.icon,
             },
             note: bill.note,
             bill_date: bill.bill_date.to_string(),
+            bill_time: bill.bill_time.map(|t| t.format("%H:%M").to_string()),
             user: UserBriefResponse {
                 id: user.id.to_string(),
                 nickname: user.nickname,
             },
             created_at: bill.created_at.to_rfc3339(),
-        });
+        })
     }
 
-    let total_pages = ((total as f64) / (filter.page_size as f64)).ceil() as u32;
+    /// Turns listing criteria into a repository filter for `user_id`, relative periods resolve in their calendar
+    async fn bill_filter(
+        &self,
+        ledger_id: Uuid,
+        user_id: Uuid,
+        criteria: &ViewFilter,
+        page: Option<u32>,
+        page_size: Option<u32>,
+    ) -> AppResult<BillFilter> {
+        let (start_date, end_date) = criteria.date_range(&self.calendar(user_id).await?);
+        let search = match criteria.q.as_deref() {
+            Some(q) => self.search_terms(ledger_id, q).await?,
+            None => Vec::new(),
+        };
+
+        let mut user_ids = criteria.user_ids.clone();
+        if criteria.mine && !user_ids.contains(&user_id) {
+            user_ids.push(user_id);
+        }
 
-    Ok(Json(ApiResponse::success(BillListResponse {
-        items,
-        pagination: PaginationResponse {
-            page: filter.page,
-            page_size: filter.page_size,
-            total,
-            total_pages,
+        Ok(BillFilter {
+            ledger_id,
+            start_date,
+            end_date,
+            bill_type: criteria.bill_type,
+            category_ids: self.with_subcategories(ledger_id, &criteria.category_ids).await?,
+            user_ids,
+            search,
+            min_amount: criteria.min_amount,
+            max_amount: criteria.max_amount,
+            has_note: criteria.has_note,
+            sort: criteria.sort,
+            page: page.unwrap_or(1),
+            page_size: page_size.unwrap_or(20),
+        })
+    }
+
+    /// The categories plus everything below them in the ledger's tree
+    async fn with_subcategories(&self, ledger_id: Uuid, category_ids: &[Uuid]) -> AppResult<Vec<Uuid>> {
+        if category_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let categories = self.category_repo.find_by_ledger_id(Some(ledger_id), None, true).await?;
+        let mut ids = category_ids.to_vec();
+        let mut next = 0;
+        while next < ids.len() {
+            let parent_id = ids[next];
+            for category in &categories {
+                if category.parent_id == Some(parent_id) && !ids.contains(&category.id) {
+                    ids.push(category.id);
+                }
+            }
+            next += 1;
+        }
+        Ok(ids)
+    }
+
+    /// One page of bills with their categories and creators
+    async fn bill_page(&self, filter: BillFilter, locale: Option<Locale>) -> AppResult<BillListResponse> {
+        let (bills, total) = self.bill_repo.find_by_filter(filter.clone()).await?;
+
+        let mut items = Vec::new();
+        for bill in bills {
+            items.push(self.bill_response(bill, locale).await?);
+        }
+
+        let total_pages = ((total as f64) / (filter.page_size as f64)).ceil() as u32;
+
+        Ok(BillListResponse {
+            items,
+            pagination: PaginationResponse {
+                page: filter.page,
+                page_size: filter.page_size,
+                total,
+                total_pages,
+            },
+        })
+    }
+
+    async fn category_statistics_response(
+        &self,
+        stats: CategoryStatistics,
+        locale: Option<Locale>,
+    ) -> AppResult<CategoryStatisticsResponse> {
+        let category = self.category_repo.find_by_id(stats.category_id).await?;
+        Ok(CategoryStatisticsResponse {
+            category_id: stats.category_id.to_string(),
+            category_name: category.as_ref().map(|cat| cat.localized_name(locale)).unwrap_or(stats.category_name),
+            category_icon: category.and_then(|cat| cat.icon),
+            bill_type: stats.bill_type.to_string(),
+            amount: stats.amount,
+            count: stats.count,
+            percentage: stats.percentage,
+        })
+    }
+
+    /// Totals, categories and trend of one period of a comparison
+    async fn period_statistics(
+        &self,
+        ledger_id: Uuid,
+        (start_date, end_date): (NaiveDate, NaiveDate),
+        bill_type: Option<BillType>,
+        group_by: &str,
+        calendar: &Calendar,
+        locale: Option<Locale>,
+    ) -> AppResult<PeriodStatisticsResponse> {
+        let stats = self.bill_repo.get_statistics(ledger_id, Some(start_date), Some(end_date)).await?;
+
+        let mut by_category = Vec::new();
+        for c in self.bill_repo.get_category_statistics(ledger_id, Some(start_date), Some(end_date), bill_type).await? {
+            by_category.push(self.category_statistics_response(c, locale).await?);
+        }
+
+        let trend = self
+            .bill_repo
+            .get_trend_statistics(ledger_id, start_date, end_date, group_by, calendar)
+            .await?
+            .into_iter()
+            .map(|t| TrendStatisticsResponse {
+                period: t.period,
+                income: t.income,
+                expense: t.expense,
+                balance: t.balance,
+            })
+            .collect();
+
+        Ok(PeriodStatisticsResponse {
+            start_date: start_date.to_string(),
+            end_date: end_date.to_string(),
+            summary: BillStatisticsResponse {
+                total_income: stats.total_income,
+                total_expense: stats.total_expense,
+                balance: stats.balance,
+                by_category,
+            },
+            trend,
+        })
+    }
+
+    /// Bills picked by ID list or by filter; an unknown ID fails only its own item
+    async fn select_bills(
+        &self,
+        user_id: Uuid,
+        ids: Option<Vec<String>>,
+        filter: Option<BatchBillFilter>,
+    ) -> AppResult<Vec<BatchItem<Bill>>> {
+        match (ids, filter) {
+            (Some(ids), None) => {
+                check_batch_size(ids.len())?;
+
+                let mut seen = HashSet::new();
+                let mut selected = Vec::new();
+                for id in ids {
+                    let bill = match Uuid::parse_str(&id) {
+                        Err(_) => Err(AppError::Validation("Invalid bill ID".to_string())),
+                        Ok(bill_id) if !seen.insert(bill_id) => {
+                            Err(AppError::Validation("Bill is listed more than once".to_string()))
+                        }
+                        Ok(bill_id) => self
+                            .bill_repo
+                            .find_by_id(bill_id)
+                            .await?
+                            .ok_or_else(|| AppError::NotFound("Bill not found".to_string())),
+                    };
+                    selected.push((Some(id), bill));
+                }
+                Ok(selected)
+            }
+            (None, Some(filter)) => {
+                let ledger_id = Uuid::parse_str(&filter.ledger_id)
+                    .map_err(|_| AppError::Validation("Invalid ledger ID".to_string()))?;
+                self.permission_service.ledger_access(ledger_id, user_id).await?;
+
+                let bill_type = filter.bill_type.as_deref().map(parse_bill_type).transpose()?;
+                let (bills, total) = self
+                    .bill_repo
+                    .find_by_filter(BillFilter {
+                        ledger_id,
+                        start_date: parse_date(filter.start_date.as_deref(), "Invalid start date")?,
+                        end_date: parse_date(filter.end_date.as_deref(), "Invalid end date")?,
+                        bill_type,
+                        category_ids: parse_ids(filter.category_id.as_deref().into_iter(), "Invalid category ID")?,
+                        page: 1,
+                        page_size: MAX_BATCH_SIZE as u32,
+                        ..Default::default()
+                    })
+                    .await?;
+                check_batch_size(total as usize)?;
+
+                Ok(bills.into_iter().map(|bill| (Some(bill.id.to_string()), Ok(bill))).collect())
+            }
+            _ => Err(AppError::Validation("Provide either ids or filter".to_string())),
+        }
+    }
+
+    /// Checks an edit of an existing bill, shared by `PUT /bills/:id` and batch updates
+    async fn prepare_update(
+        &self,
+        user_id: Uuid,
+        existing: Bill,
+        changes: &UpdateBill,
+        exchange_rate: Option<f64>,
+    ) -> AppResult<PreparedUpdate> {
+        let access = self.permission_service.ledger_access(existing.ledger_id, user_id).await?;
+        access.require_edit_bill(&existing)?;
+
+        let mut update = changes.clone();
+        let mut destination = None;
+        match changes.ledger_id.filter(|id| *id != existing.ledger_id) {
+            Some(ledger_id) => {
+                // Moving out of a ledger removes the bill there, so it needs delete rights too
+                access.require_delete_bill(&existing)?;
+                let target = self.permission_service.ledger_access(ledger_id, user_id).await?;
+                target.require(GroupCapability::Create)?;
+
+                let category_id = match changes.category_id {
+                    Some(category_id) => {
+                        self.check_category(category_id, ledger_id).await?;
+                        category_id
+                    }
+                    None => self.destination_category(existing.category_id, ledger_id).await?,
+                };
+                update.category_id = Some(category_id);
+
+                if changes.amount.is_none() && target.ledger.currency != access.ledger.currency {
+                    let rate = exchange_rate.filter(|r| *r > 0.0).ok_or_else(|| {
+                        AppError::Validation("Exchange rate is required between ledgers with different currencies".to_string())
+                    })?;
+                    update.amount = Some(round_cents(existing.amount * rate));
+                }
+                destination = Some(target);
+            }
+            None => {
+                update.ledger_id = None;
+                // Keeping an archived category on an existing bill is fine, switching to one is not
+                if let Some(category_id) = changes.category_id.filter(|id| *id != existing.category_id) {
+                    self.check_category(category_id, existing.ledger_id).await?;
+                }
+            }
+        }
+
+        Ok(PreparedUpdate {
+            access,
+            destination,
+            existing,
+            update,
+        })
+    }
+
+    /// The category a moved bill takes in `ledger_id`: its own when still usable there, else a
+    /// same-named one, else its nearest usable ancestor, else the system "other" category
+    async fn destination_category(&self, category_id: Uuid, ledger_id: Uuid) -> AppResult<Uuid> {
+        let category = self
+            .category_repo
+            .find_by_id(category_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Category not found".to_string()))?;
+        if category.is_usable_in(ledger_id) {
+            return Ok(category.id);
+        }
+
+        let candidates = self
+            .category_repo
+            .find_by_ledger_id(Some(ledger_id), Some(category.category_type), false)
+            .await?;
+        if let Some(same_name) = candidates.iter().find(|c| c.name == category.name) {
+            return Ok(same_name.id);
+        }
+
+        let mut parent_id = category.parent_id;
+        while let Some(id) = parent_id {
+            let Some(parent) = self.category_repo.find_by_id(id).await? else {
+                break;
+            };
+            if parent.is_usable_in(ledger_id) {
+                return Ok(parent.id);
+            }
+            parent_id = parent.parent_id;
+        }
+
+        candidates
+            .iter()
+            .find(|c| c.is_system() && c.icon.as_deref() == Some("other"))
+            .map(|c| c.id)
+            .ok_or_else(|| AppError::Validation("No matching category in the destination ledger".to_string()))
+    }
+
+    /// A moved bill shows up in the history of both ledgers' groups
+    async fn record_update(&self, user_id: Uuid, prepared: &PreparedUpdate, bill: &Bill) {
+        let source_group = prepared.access.ledger.group_id;
+        self.audit_service
+            .record_update(user_id, AuditEntityType::Bill, bill.id, source_group, &prepared.existing, bill)
+            .await;
+
+        if let Some(group_id) = prepared.destination.as_ref().and_then(|d| d.ledger.group_id) {
+            if Some(group_id) != source_group {
+                self.audit_service
+                    .record_update(user_id, AuditEntityType::Bill, bill.id, Some(group_id), &prepared.existing, bill)
+                    .await;
+            }
+        }
+    }
+
+    async fn applied_batch(&self, bills: Vec<Bill>, with_bills: bool, locale: Option<Locale>) -> AppResult<BatchBillResponse> {
+        let mut results = Vec::new();
+        for (index, bill) in bills.into_iter().enumerate() {
+            let id = bill.id.to_string();
+            let bill = match with_bills {
+                true => Some(self.bill_response(bill, locale).await?),
+                false => None,
+            };
+            results.push(BatchItemResultResponse {
+                index,
+                id: Some(id),
+                status: "ok".to_string(),
+                bill,
+                error: None,
+            });
+        }
+
+        Ok(BatchBillResponse {
+            applied: true,
+            total: results.len(),
+            failed: 0,
+            results,
+        })
+    }
+}
+
+pub async fn list_bills(
+    State(api): State<Arc<BillApi>>,
+    RequestLocale(locale): RequestLocale,
+    Extension(current_user): Extension<CurrentUser>,
+    Query(params): Query<BillQueryParams>,
+) -> AppResult<Json<ApiResponse<BillListResponse>>> {
+    let ledger_id = Uuid::parse_str(&params.ledger_id)
+        .map_err(|_| AppError::Validation("Invalid ledger ID".to_string()))?;
+
+    // Check access to ledger
+    api.permission_service.ledger_access(ledger_id, current_user.id).await?;
+
+    let bill_type = params.bill_type.as_deref().map(parse_bill_type).transpose()?;
+
+    // `me` stands for the caller
+    let creators: Vec<&str> = params.user_id.as_deref().unwrap_or_default().split(',').map(str::trim).collect();
+
+    let criteria = ViewFilter {
+        period: params.period,
+        start_date: parse_date(params.start_date.as_deref(), "Invalid start date")?,
+        end_date: parse_date(params.end_date.as_deref(), "Invalid end date")?,
+        bill_type,
+        category_ids: parse_ids(params.category_id.as_deref().unwrap_or_default().split(','), "Invalid category ID")?,
+        user_ids: parse_ids(creators.iter().copied().filter(|id| *id != "me"), "Invalid user ID")?,
+        mine: creators.contains(&"me"),
+        q: params.q,
+        min_amount: params.min_amount,
+        max_amount: params.max_amount,
+        has_note: params.has_note,
+        sort: match params.sort.as_deref() {
+            Some(sort) => BillSort::parse(sort).ok_or_else(|| AppError::Validation("Invalid sort".to_string()))?,
+            None => BillSort::default(),
         },
-    })))
+    };
+    criteria.validate().map_err(AppError::Validation)?;
+
+    let filter = api.bill_filter(ledger_id, current_user.id, &criteria, params.page, params.page_size).await?;
+
+    Ok(Json(ApiResponse::success(api.bill_page(filter, locale).await?)))
+}
+
+/// Runs a saved view: the bills matching its filter as of today
+pub async fn list_view_bills(
+    State(api): State<Arc<BillApi>>,
+    RequestLocale(locale): RequestLocale,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<String>,
+    Query(params): Query<ViewBillsParams>,
+) -> AppResult<Json<ApiResponse<BillListResponse>>> {
+    let view_id = Uuid::parse_str(&id)
+        .map_err(|_| AppError::Validation("Invalid view ID".to_string()))?;
+
+    let (view, _) = find_visible_view(api.view_repo.as_ref(), &api.permission_service, view_id, current_user.id).await?;
+
+    let filter = api.bill_filter(view.ledger_id, current_user.id, &view.filter, params.page, params.page_size).await?;
+
+    Ok(Json(ApiResponse::success(api.bill_page(filter, locale).await?)))
 }
 
 pub async fn create_bill(
     State(api): State<Arc<BillApi>>,
+    RequestLocale(locale): RequestLocale,
     Extension(current_user): Extension<CurrentUser>,
     Json(req): Json<CreateBillRequest>,
 ) -> AppResult<Json<ApiResponse<BillResponse>>> {
+    let force = req.force.unwrap_or(false);
+    let (access, bill) = api.prepare_bill(current_user.id, req).await?;
+    let bill = api.insert_bill(&access, bill, force, locale).await?;
+
+    Ok(Json(ApiResponse::success(bill)))
+}
+
+/// Parses free text into a bill, saving it only when `commit` is set
+pub async fn quick_create_bill(
+    State(api): State<Arc<BillApi>>,
+    RequestLocale(locale): RequestLocale,
+    Extension(current_user): Extension<CurrentUser>,
+    Json(req): Json<QuickBillRequest>,
+) -> AppResult<Json<ApiResponse<QuickBillResponse>>> {
+    req.validate()
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+
     let ledger_id = Uuid::parse_str(&req.ledger_id)
         .map_err(|_| AppError::Validation("Invalid ledger ID".to_string()))?;
-    let category_id = Uuid::parse_str(&req.category_id)
-        .map_err(|_| AppError::Validation("Invalid category ID".to_string()))?;
-
-    // Check access to ledger
-    let ledger = api
-        .ledger_repo
-        .find_by_id(ledger_id)
-        .await?
-        .ok_or_else(|| AppError::NotFound("Ledger not found".to_string()))?;
 
-    if ledger.user_id != Some(current_user.id) && ledger.group_id.is_none() {
-        return Err(AppError::Forbidden("Access denied".to_string()));
+    let access = api.permission_service.ledger_access(ledger_id, current_user.id).await?;
+    let commit = req.commit.unwrap_or(false);
+    if commit {
+        access.require(GroupCapability::Create)?;
     }
 
-    let bill_type = match req.bill_type.as_str() {
-        "income" => BillType::Income,
-        "expense" => BillType::Expense,
-        _ => return Err(AppError::Validation("Invalid bill type".to_string())),
-    };
+    let entry = api
+        .quick_entry_service
+        .parse(ledger_id, &req.text, api.calendar(current_user.id).await?.today())
+        .await?;
 
-    let bill_date = NaiveDate::parse_from_str(&req.bill_date, "%Y-%m-%d")
-        .map_err(|_| AppError::Validation("Invalid date format".to_string()))?;
+    let mut missing = Vec::new();
+    if entry.amount.is_none() {
+        missing.push("amount".to_string());
+    }
+    if entry.category.is_none() {
+        missing.push("category".to_string());
+    }
 
-    let bill = api
-        .bill_repo
-        .create(CreateBill {
-            ledger_id,
-            category_id,
-            user_id: current_user.id,
-            bill_type,
-            amount: req.amount,
-            note: req.note,
-            bill_date,
-        })
-        .await?;
+    let bill = match (commit, entry.amount, &entry.category) {
+        (false, _, _) => None,
+        (true, None, _) => return Err(AppError::Validation("Could not find an amount in the text".to_string())),
+        (true, _, None) => return Err(AppError::Validation("Category is required".to_string())),
+        (true, Some(amount), Some(category)) => Some(
+            api.insert_bill(
+                &access,
+                CreateBill {
+                    ledger_id,
+                    category_id: category.id,
+                    user_id: current_user.id,
+                    bill_type: entry.bill_type,
+                    amount,
+                    note: entry.note.clone(),
+                    bill_date: entry.bill_date,
+                    bill_time: None,
+                },
+                req.force.unwrap_or(false),
+                locale,
+            )
+            .await?,
+        ),
+    };
 
-    let category = api.category_repo.find_by_id(bill.category_id).await?.unwrap();
-    let user = api.user_repo.find_by_id(bill.user_id).await?.unwrap();
-
-    Ok(Json(ApiResponse::success(BillResponse {
-        id: bill.id.to_string(),
-        bill_type: bill.bill_type.to_string(),
-        amount: bill.amount,
-        category: CategoryBriefResponse {
-            id: category.id.to_string(),
-            name: category.name,
-            icon: category.icon,
-        },
-        note: bill.note,
-        bill_date: bill.bill_date.to_string(),
-        user: UserBriefResponse {
-            id: user.id.to_string(),
-            nickname: user.nickname,
+    Ok(Json(ApiResponse::success(QuickBillResponse {
+        preview: QuickBillPreviewResponse {
+            amount: entry.amount,
+            bill_type: entry.bill_type.to_string(),
+            bill_date: entry.bill_date.to_string(),
+            category: entry.category.map(|category| CategoryBriefResponse {
+                id: category.id.to_string(),
+                name: category.localized_name(locale),
+                icon: category.icon,
+            }),
+            category_source: entry.category_source.map(|s| s.to_string()),
+            note: entry.note,
+            account: entry.account.map(str::to_string),
+            confidence: entry.confidence,
+            missing,
         },
-        created_at: bill.created_at.to_rfc3339(),
+        bill,
     })))
 }
 
 pub async fn get_bill(
     State(api): State<Arc<BillApi>>,
+    RequestLocale(locale): RequestLocale,
     Extension(current_user): Extension<CurrentUser>,
     Path(id): Path<String>,
 ) -> AppResult<Json<ApiResponse<BillResponse>>> {
@@ -195,41 +772,36 @@ pub async fn get_bill(
         .ok_or_else(|| AppError::NotFound("Bill not found".to_string()))?;
 
     // Check access
-    let ledger = api.ledger_repo.find_by_id(bill.ledger_id).await?.unwrap();
-    if ledger.user_id != Some(current_user.id) && ledger.group_id.is_none() {
-        return Err(AppError::Forbidden("Access denied".to_string()));
-    }
-
-    let category = api.category_repo.find_by_id(bill.category_id).await?.unwrap();
-    let user = api.user_repo.find_by_id(bill.user_id).await?.unwrap();
-
-    Ok(Json(ApiResponse::success(BillResponse {
-        id: bill.id.to_string(),
-        bill_type: bill.bill_type.to_string(),
-        amount: bill.amount,
-        category: CategoryBriefResponse {
-            id: category.id.to_string(),
-            name: category.name,
-            icon: category.icon,
-        },
-        note: bill.note,
-        bill_date: bill.bill_date.to_string(),
-        user: UserBriefResponse {
-            id: user.id.to_string(),
-            nickname: user.nickname,
-        },
-        created_at: bill.created_at.to_rfc3339(),
-    })))
+    api.permission_service.ledger_access(bill.ledger_id, current_user.id).await?;
+
+    Ok(Json(ApiResponse::success(api.bill_response(bill, locale).await?)))
 }
 
 pub async fn update_bill(
     State(api): State<Arc<BillApi>>,
+    RequestLocale(locale): RequestLocale,
     Extension(current_user): Extension<CurrentUser>,
     Path(id): Path<String>,
     Json(req): Json<UpdateBillRequest>,
 ) -> AppResult<Json<ApiResponse<BillResponse>>> {
     let bill_id = Uuid::parse_str(&id)
         .map_err(|_| AppError::Validation("Invalid bill ID".to_string()))?;
+    // Rejected the same way as a batch update's changes
+    let ledger_id = match req.ledger_id {
+        Some(ref id) => Some(Uuid::parse_str(id).map_err(|_| AppError::Validation("Invalid ledger ID".to_string()))?),
+        None => None,
+    };
+    let category_id = match req.category_id {
+        Some(ref id) => Some(Uuid::parse_str(id).map_err(|_| AppError::Validation("Invalid category ID".to_string()))?),
+        None => None,
+    };
+    let bill_type = req.bill_type.as_deref().map(parse_bill_type).transpose()?;
+    let bill_date = parse_date(req.bill_date.as_deref(), "Invalid date format")?;
+    let bill_time = match req.bill_time {
+        Some(Some(ref time)) => Some(Some(parse_bill_time(time)?)),
+        Some(None) => Some(None),
+        None => None,
+    };
 
     let existing = api
         .bill_repo
@@ -237,53 +809,23 @@ pub async fn update_bill(
         .await?
         .ok_or_else(|| AppError::NotFound("Bill not found".to_string()))?;
 
-    // Only creator can update
-    if existing.user_id != current_user.id {
-        return Err(AppError::Forbidden("Access denied".to_string()));
-    }
-
-    let bill_type = req.bill_type.as_ref().and_then(|t| match t.as_str() {
-        "income" => Some(BillType::Income),
-        "expense" => Some(BillType::Expense),
-        _ => None,
-    });
-
-    let bill_date = req.bill_date.as_ref().and_then(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").ok());
-
-    let bill = api
-        .bill_repo
-        .update(
-            bill_id,
-            UpdateBill {
-                category_id: req.category_id.as_ref().and_then(|id| Uuid::parse_str(id).ok()),
-                bill_type,
-                amount: req.amount,
-                note: req.note.clone(),
-                bill_date,
-            },
-        )
+    let changes = UpdateBill {
+        ledger_id,
+        category_id,
+        bill_type,
+        amount: req.amount,
+        note: req.note.clone(),
+        bill_date,
+        bill_time,
+    };
+    let prepared = api
+        .prepare_update(current_user.id, existing, &changes, req.exchange_rate)
         .await?;
 
-    let category = api.category_repo.find_by_id(bill.category_id).await?.unwrap();
-    let user = api.user_repo.find_by_id(bill.user_id).await?.unwrap();
-
-    Ok(Json(ApiResponse::success(BillResponse {
-        id: bill.id.to_string(),
-        bill_type: bill.bill_type.to_string(),
-        amount: bill.amount,
-        category: CategoryBriefResponse {
-            id: category.id.to_string(),
-            name: category.name,
-            icon: category.icon,
-        },
-        note: bill.note,
-        bill_date: bill.bill_date.to_string(),
-        user: UserBriefResponse {
-            id: user.id.to_string(),
-            nickname: user.nickname,
-        },
-        created_at: bill.created_at.to_rfc3339(),
-    })))
+    let bill = api.bill_repo.update(bill_id, prepared.update.clone()).await?;
+    api.record_update(current_user.id, &prepared, &bill).await;
+
+    Ok(Json(ApiResponse::success(api.bill_response(bill, locale).await?)))
 }
 
 pub async fn delete_bill(
@@ -300,16 +842,226 @@ pub async fn delete_bill(
         .await?
         .ok_or_else(|| AppError::NotFound("Bill not found".to_string()))?;
 
-    // Only creator can delete
-    if existing.user_id != current_user.id {
-        return Err(AppError::Forbidden("Access denied".to_string()));
-    }
+    let access = api.permission_service.ledger_access(existing.ledger_id, current_user.id).await?;
+    access.require_delete_bill(&existing)?;
 
     api.bill_repo.delete(bill_id).await?;
 
+    api.audit_service
+        .record_delete(current_user.id, AuditEntityType::Bill, bill_id, access.ledger.group_id, &existing)
+        .await;
+
     Ok(Json(ApiResponse::success(())))
 }
 
+/// Bulk create, update or delete. Every item gets the same checks as the single-bill endpoints;
+/// if any item fails nothing is written and the others are reported as skipped.
+pub async fn batch_bills(
+    State(api): State<Arc<BillApi>>,
+    RequestLocale(locale): RequestLocale,
+    Extension(current_user): Extension<CurrentUser>,
+    Json(req): Json<BatchBillRequest>,
+) -> AppResult<Json<ApiResponse<BatchBillResponse>>> {
+    let response = match req {
+        BatchBillRequest::Create { items } => {
+            check_batch_size(items.len())?;
+
+            let mut prepared = Vec::new();
+            for item in items {
+                let force = item.force.unwrap_or(false);
+                let result = match api.prepare_bill(current_user.id, item).await {
+                    Ok((access, bill)) => api.check_new_bill(&bill, force).await.map(|_| (access, bill)),
+                    Err(e) => Err(e),
+                };
+                prepared.push((None, result));
+            }
+            let Some(prepared) = all_prepared(&mut prepared) else {
+                return Ok(Json(ApiResponse::success(rejected_batch(prepared, locale))));
+            };
+
+            let (accesses, bills): (Vec<LedgerAccess>, Vec<CreateBill>) = prepared.into_iter().unzip();
+            let created = api.bill_repo.create_many(bills).await?;
+
+            let mut saved = Vec::new();
+            let mut by_ledger: HashMap<Uuid, (Ledger, Vec<Bill>)> = HashMap::new();
+            for (access, bill) in accesses.iter().zip(created) {
+                api.audit_service
+                    .record_create(current_user.id, AuditEntityType::Bill, bill.id, access.ledger.group_id, &bill)
+                    .await;
+                by_ledger
+                    .entry(access.ledger.id)
+                    .or_insert_with(|| (access.ledger.clone(), Vec::new()))
+                    .1
+                    .push(bill.clone());
+                saved.push(bill);
+            }
+            // One anomaly check per ledger rather than per bill
+            for (ledger, bills) in by_ledger.into_values() {
+                api.anomaly_service.notify_if_unusual(bills, ledger);
+            }
+            api.applied_batch(saved, true, locale).await?
+        }
+        BatchBillRequest::Update { ids, filter, changes } => {
+            let bill_type = changes.bill_type.as_deref().map(parse_bill_type).transpose()?;
+            let bill_date = parse_date(changes.bill_date.as_deref(), "Invalid date format")?;
+            let category_id = match changes.category_id {
+                Some(ref id) => Some(Uuid::parse_str(id).map_err(|_| AppError::Validation("Invalid category ID".to_string()))?),
+                None => None,
+            };
+            let ledger_id = match changes.ledger_id {
+                Some(ref id) => Some(Uuid::parse_str(id).map_err(|_| AppError::Validation("Invalid ledger ID".to_string()))?),
+                None => None,
+            };
+            let template = UpdateBill {
+                ledger_id,
+                category_id,
+                bill_type,
+                amount: None,
+                note: changes.note.clone(),
+                bill_date,
+                bill_time: None,
+            };
+
+            let mut prepared = Vec::new();
+            for (label, selected) in api.select_bills(current_user.id, ids, filter).await? {
+                let result = match selected {
+                    Ok(existing) => api.prepare_update(current_user.id, existing, &template, changes.exchange_rate).await,
+                    Err(e) => Err(e),
+                };
+                prepared.push((label, result));
+            }
+            let Some(prepared) = all_prepared(&mut prepared) else {
+                return Ok(Json(ApiResponse::success(rejected_batch(prepared, locale))));
+            };
+
+            let updates = prepared.iter().map(|p| (p.existing.id, p.update.clone())).collect();
+            let updated = api.bill_repo.update_many(updates).await?;
+
+            for (prepared, bill) in prepared.iter().zip(&updated) {
+                api.record_update(current_user.id, prepared, bill).await;
+            }
+            api.applied_batch(updated, true, locale).await?
+        }
+        BatchBillRequest::Delete { ids, filter } => {
+            let mut prepared = Vec::new();
+            for (label, selected) in api.select_bills(current_user.id, ids, filter).await? {
+                let result = match selected {
+                    Ok(existing) => api.permission_service.ledger_access(existing.ledger_id, current_user.id).await.and_then(
+                        |access| {
+                            access.require_delete_bill(&existing)?;
+                            Ok((access, existing))
+                        },
+                    ),
+                    Err(e) => Err(e),
+                };
+                prepared.push((label, result));
+            }
+            let Some(prepared) = all_prepared(&mut prepared) else {
+                return Ok(Json(ApiResponse::success(rejected_batch(prepared, locale))));
+            };
+
+            let ids: Vec<Uuid> = prepared.iter().map(|(_, bill)| bill.id).collect();
+            api.bill_repo.delete_many(&ids).await?;
+
+            for (access, existing) in &prepared {
+                api.audit_service
+                    .record_delete(current_user.id, AuditEntityType::Bill, existing.id, access.ledger.group_id, existing)
+                    .await;
+            }
+            api.applied_batch(prepared.into_iter().map(|(_, bill)| bill).collect(), false, locale).await?
+        }
+    };
+
+    Ok(Json(ApiResponse::success(response)))
+}
+
+/// IDs from a comma-separated query parameter, blanks are skipped
+fn parse_ids<'a>(ids: impl Iterator<Item = &'a str>, message: &str) -> AppResult<Vec<Uuid>> {
+    ids.map(str::trim)
+        .filter(|id| !id.is_empty())
+        .map(|id| Uuid::parse_str(id).map_err(|_| AppError::Validation(message.to_string())))
+        .collect()
+}
+
+/// An optional `YYYY-MM-DD` parameter, rejected rather than ignored when malformed
+fn parse_date(value: Option<&str>, message: &str) -> AppResult<Option<NaiveDate>> {
+    value
+        .map(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").map_err(|_| AppError::Validation(message.to_string())))
+        .transpose()
+}
+
+/// An explicit `start_date`..`end_date` range, None unless both ends are given
+fn parse_date_range(start: Option<&str>, end: Option<&str>) -> AppResult<Option<(NaiveDate, NaiveDate)>> {
+    let start = parse_date(start, "Invalid start date")?;
+    let end = parse_date(end, "Invalid end date")?;
+    match (start, end) {
+        (Some(start), Some(end)) if start > end => Err(AppError::Validation("Invalid date range".to_string())),
+        (Some(start), Some(end)) => Ok(Some((start, end))),
+        _ => Ok(None),
+    }
+}
+
+/// `income` or `expense`
+fn parse_bill_type(value: &str) -> AppResult<BillType> {
+    match value {
+        "income" => Ok(BillType::Income),
+        "expense" => Ok(BillType::Expense),
+        _ => Err(AppError::Validation("Invalid bill type".to_string())),
+    }
+}
+
+fn check_batch_size(size: usize) -> AppResult<()> {
+    match size {
+        0 => Err(AppError::Validation("Batch is empty".to_string())),
+        n if n > MAX_BATCH_SIZE => Err(AppError::Validation(format!("Too many bills in the batch, the limit is: {}", MAX_BATCH_SIZE))),
+        _ => Ok(()),
+    }
+}
+
+/// Takes the checked items out when none of them failed
+fn all_prepared<T>(items: &mut Vec<BatchItem<T>>) -> Option<Vec<T>> {
+    if items.iter().any(|(_, result)| result.is_err()) {
+        return None;
+    }
+    Some(items.drain(..).filter_map(|(_, result)| result.ok()).collect())
+}
+
+fn rejected_batch<T>(items: Vec<BatchItem<T>>, locale: Option<Locale>) -> BatchBillResponse {
+    let results: Vec<BatchItemResultResponse> = items
+        .into_iter()
+        .enumerate()
+        .map(|(index, (id, result))| match result {
+            Ok(_) => BatchItemResultResponse {
+                index,
+                id,
+                status: "skipped".to_string(),
+                bill: None,
+                error: None,
+            },
+            Err(e) => {
+                let message = e.message();
+                BatchItemResultResponse {
+                    index,
+                    id,
+                    status: "failed".to_string(),
+                    bill: None,
+                    error: Some(BatchItemErrorResponse {
+                        code: e.code(),
+                        message: locale.and_then(|l| translate_message(&message, l)).unwrap_or(message),
+                    }),
+                }
+            }
+        })
+        .collect();
+
+    BatchBillResponse {
+        applied: false,
+        total: results.len(),
+        failed: results.iter().filter(|r| r.error.is_some()).count(),
+        results,
+    }
+}
+
 #[derive(Debug, serde::Deserialize)]
 pub struct StatisticsParams {
     pub ledger_id: String,
@@ -321,55 +1073,28 @@ pub struct StatisticsParams {
 
 pub async fn get_statistics(
     State(api): State<Arc<BillApi>>,
+    RequestLocale(locale): RequestLocale,
     Extension(current_user): Extension<CurrentUser>,
     Query(params): Query<StatisticsParams>,
 ) -> AppResult<Json<ApiResponse<FullStatisticsResponse>>> {
     let ledger_id = Uuid::parse_str(&params.ledger_id)
         .map_err(|_| AppError::Validation("Invalid ledger ID".to_string()))?;
 
-    // Check access
-    let ledger = api
-        .ledger_repo
-        .find_by_id(ledger_id)
-        .await?
-        .ok_or_else(|| AppError::NotFound("Ledger not found".to_string()))?;
-
-    if ledger.user_id != Some(current_user.id) && ledger.group_id.is_none() {
-        return Err(AppError::Forbidden("Access denied".to_string()));
-    }
+    // Check access to ledger
+    api.permission_service.ledger_access(ledger_id, current_user.id).await?;
 
     let period = params.period.as_deref().unwrap_or("month");
 
     // Calculate date range based on period
-    let today = Utc::now().date_naive();
+    let calendar = api.calendar(current_user.id).await?;
+    let today = calendar.today();
     let (start_date, end_date) = if let (Some(s), Some(e)) = (&params.start_date, &params.end_date) {
         (
             NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap_or(today - Duration::days(30)),
             NaiveDate::parse_from_str(e, "%Y-%m-%d").unwrap_or(today),
         )
     } else {
-        match period {
-            "day" => (today, today),
-            "week" => {
-                let start = today - Duration::days(today.weekday().num_days_from_monday() as i64);
-                (start, start + Duration::days(6))
-            }
-            "month" => {
-                let start = NaiveDate::from_ymd_opt(today.year(), today.month(), 1).unwrap();
-                let next_month = if today.month() == 12 {
-                    NaiveDate::from_ymd_opt(today.year() + 1, 1, 1).unwrap()
-                } else {
-                    NaiveDate::from_ymd_opt(today.year(), today.month() + 1, 1).unwrap()
-                };
-                (start, next_month - Duration::days(1))
-            }
-            "year" => {
-                let start = NaiveDate::from_ymd_opt(today.year(), 1, 1).unwrap();
-                let end = NaiveDate::from_ymd_opt(today.year(), 12, 31).unwrap();
-                (start, end)
-            }
-            _ => (today - Duration::days(30), today),
-        }
+        calendar.period(period, today).unwrap_or((today - Duration::days(30), today))
     };
 
     let bill_type = params.bill_type.as_ref().and_then(|t| match t.as_str() {
@@ -386,16 +1111,7 @@ pub async fn get_statistics(
 
     let mut by_category: Vec<CategoryStatisticsResponse> = Vec::new();
     for c in category_stats {
-        let category = api.category_repo.find_by_id(c.category_id).await?;
-        by_category.push(CategoryStatisticsResponse {
-            category_id: c.category_id.to_string(),
-            category_name: category.as_ref().map(|cat| cat.name.clone()).unwrap_or_else(|| c.category_name),
-            category_icon: category.and_then(|cat| cat.icon),
-            bill_type: c.bill_type.to_string(),
-            amount: c.amount,
-            count: c.count,
-            percentage: c.percentage,
-        });
+        by_category.push(api.category_statistics_response(c, locale).await?);
     }
 
     // Get daily statistics
@@ -416,7 +1132,7 @@ pub async fn get_statistics(
         "year" => "month",
         _ => "day",
     };
-    let trend_stats = api.bill_repo.get_trend_statistics(ledger_id, start_date, end_date, group_by).await?;
+    let trend_stats = api.bill_repo.get_trend_statistics(ledger_id, start_date, end_date, group_by, &calendar).await?;
     let trend: Vec<TrendStatisticsResponse> = trend_stats
         .into_iter()
         .map(|t| TrendStatisticsResponse {
@@ -440,59 +1156,882 @@ pub async fn get_statistics(
 }
 
 #[derive(Debug, serde::Deserialize)]
-pub struct CategoryStatsParams {
+pub struct ComparisonParams {
     pub ledger_id: String,
     pub start_date: Option<String>,
     pub end_date: Option<String>,
-    pub bill_type: Option<String>,
+    pub period: Option<String>,  // day, week, month (default), year
+    pub bill_type: Option<String>,  // income, expense
 }
 
-pub async fn get_category_statistics(
+/// The current period next to the one before it and the same period a year earlier.
+/// A custom date range is compared with the equally long range right before it.
+pub async fn get_comparison_statistics(
     State(api): State<Arc<BillApi>>,
+    RequestLocale(locale): RequestLocale,
     Extension(current_user): Extension<CurrentUser>,
-    Query(params): Query<CategoryStatsParams>,
-) -> AppResult<Json<ApiResponse<Vec<CategoryStatisticsResponse>>>> {
+    Query(params): Query<ComparisonParams>,
+) -> AppResult<Json<ApiResponse<ComparisonStatisticsResponse>>> {
     let ledger_id = Uuid::parse_str(&params.ledger_id)
         .map_err(|_| AppError::Validation("Invalid ledger ID".to_string()))?;
 
-    // Check access
-    let ledger = api
-        .ledger_repo
-        .find_by_id(ledger_id)
-        .await?
-        .ok_or_else(|| AppError::NotFound("Ledger not found".to_string()))?;
-
-    if ledger.user_id != Some(current_user.id) && ledger.group_id.is_none() {
-        return Err(AppError::Forbidden("Access denied".to_string()));
+    api.permission_service.ledger_access(ledger_id, current_user.id).await?;
+
+    let calendar = api.calendar(current_user.id).await?;
+    let today = calendar.today();
+    let (current, previous, last_year, group_by) = match parse_date_range(params.start_date.as_deref(), params.end_date.as_deref())? {
+        Some((start, end)) => {
+            let length = end - start + Duration::days(1);
+            let group_by = if length > Duration::days(92) { "month" } else { "day" };
+            (
+                (start, end),
+                (start - length, start - Duration::days(1)),
+                (start - Months::new(12), end - Months::new(12)),
+                group_by,
+            )
+        }
+        None => {
+            let unit = params.period.as_deref().unwrap_or("month");
+            let current = calendar.period(unit, today)
+                .ok_or_else(|| AppError::Validation("Invalid period".to_string()))?;
+            let previous = calendar.period(unit, current.0 - Duration::days(1)).unwrap();
+            let last_year = calendar.period(unit, today - Months::new(12)).unwrap();
+            let group_by = if unit == "year" { "month" } else { "day" };
+            (current, previous, last_year, group_by)
+        }
+    };
+
+    let bill_type = params.bill_type.as_deref().map(parse_bill_type).transpose()?;
+
+    let current = api.period_statistics(ledger_id, current, bill_type, group_by, &calendar, locale).await?;
+    let previous = api.period_statistics(ledger_id, previous, bill_type, group_by, &calendar, locale).await?;
+    let last_year = api.period_statistics(ledger_id, last_year, bill_type, group_by, &calendar, locale).await?;
+
+    // Every category that has bills in any of the three periods
+    let mut categories: Vec<CategoryComparisonResponse> = Vec::new();
+    let periods = [&current, &previous, &last_year];
+    for period in periods {
+        for c in &period.summary.by_category {
+            if categories.iter().any(|e| e.category_id == c.category_id && e.bill_type == c.bill_type) {
+                continue;
+            }
+            let [amount_now, amount_before, amount_last_year] = periods.map(|p| {
+                p.summary
+                    .by_category
+                    .iter()
+                    .find(|other| other.category_id == c.category_id && other.bill_type == c.bill_type)
+                    .map_or(0.0, |other| other.amount)
+            });
+            categories.push(CategoryComparisonResponse {
+                category_id: c.category_id.clone(),
+                category_name: c.category_name.clone(),
+                category_icon: c.category_icon.clone(),
+                bill_type: c.bill_type.clone(),
+                current: amount_now,
+                previous: amount_before,
+                last_year: amount_last_year,
+                vs_previous: delta(amount_now, amount_before),
+                vs_last_year: delta(amount_now, amount_last_year),
+            });
+        }
     }
+    categories.sort_by(|a, b| b.current.total_cmp(&a.current).then(b.previous.total_cmp(&a.previous)));
+
+    Ok(Json(ApiResponse::success(ComparisonStatisticsResponse {
+        vs_previous: summary_delta(&current.summary, &previous.summary),
+        vs_last_year: summary_delta(&current.summary, &last_year.summary),
+        current,
+        previous,
+        last_year,
+        categories,
+    })))
+}
 
-    let start_date = params.start_date.as_ref().and_then(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").ok());
-    let end_date = params.end_date.as_ref().and_then(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").ok());
-    let bill_type = params.bill_type.as_ref().and_then(|t| match t.as_str() {
-        "income" => Some(BillType::Income),
-        "expense" => Some(BillType::Expense),
-        _ => None,
-    });
+fn delta(current: f64, base: f64) -> DeltaResponse {
+    let change = current - base;
+    DeltaResponse {
+        change,
+        percentage: (base != 0.0).then(|| change / base.abs() * 100.0),
+    }
+}
+
+fn summary_delta(current: &BillStatisticsResponse, base: &BillStatisticsResponse) -> SummaryDeltaResponse {
+    SummaryDeltaResponse {
+        income: delta(current.total_income, base.total_income),
+        expense: delta(current.total_expense, base.total_expense),
+        balance: delta(current.balance, base.balance),
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct MemberStatsParams {
+    pub ledger_id: String,
+    pub start_date: Option<String>,
+    pub end_date: Option<String>,
+}
+
+/// Who recorded what in a (usually shared) ledger
+pub async fn get_member_statistics(
+    State(api): State<Arc<BillApi>>,
+    RequestLocale(locale): RequestLocale,
+    Extension(current_user): Extension<CurrentUser>,
+    Query(params): Query<MemberStatsParams>,
+) -> AppResult<Json<ApiResponse<MemberStatisticsListResponse>>> {
+    let ledger_id = Uuid::parse_str(&params.ledger_id)
+        .map_err(|_| AppError::Validation("Invalid ledger ID".to_string()))?;
+
+    api.permission_service.ledger_access(ledger_id, current_user.id).await?;
+
+    let start_date = parse_date(params.start_date.as_deref(), "Invalid start date")?;
+    let end_date = parse_date(params.end_date.as_deref(), "Invalid end date")?;
+
+    let members = api.bill_repo.get_member_statistics(ledger_id, start_date, end_date).await?;
+    let total_income: f64 = members.iter().map(|m| m.total_income).sum();
+    let total_expense: f64 = members.iter().map(|m| m.total_expense).sum();
+    let share = |amount: f64, total: f64| if total > 0.0 { (amount / total) * 100.0 } else { 0.0 };
+
+    let mut categories: HashMap<Uuid, Option<Category>> = HashMap::new();
+    let mut items = Vec::new();
+    for member in members {
+        let user = api.user_repo.find_by_id(member.user_id).await?;
+
+        let mut member_categories = Vec::new();
+        for c in member.categories {
+            if let Entry::Vacant(entry) = categories.entry(c.category_id) {
+                entry.insert(api.category_repo.find_by_id(c.category_id).await?);
+            }
+            let category = categories[&c.category_id].as_ref();
+            let member_total = match c.bill_type {
+                BillType::Income => member.total_income,
+                BillType::Expense => member.total_expense,
+            };
+            member_categories.push(MemberCategoryStatisticsResponse {
+                category_id: c.category_id.to_string(),
+                category_name: category.map(|cat| cat.localized_name(locale)).unwrap_or_default(),
+                category_icon: category.and_then(|cat| cat.icon.clone()),
+                bill_type: c.bill_type.to_string(),
+                amount: c.amount,
+                count: c.count,
+                percentage: share(c.amount, member_total),
+            });
+        }
+
+        items.push(MemberStatisticsResponse {
+            user: UserBriefResponse {
+                id: member.user_id.to_string(),
+                nickname: user.and_then(|u| u.nickname),
+            },
+            total_income: member.total_income,
+            total_expense: member.total_expense,
+            balance: member.total_income - member.total_expense,
+            count: member.count,
+            income_share: share(member.total_income, total_income),
+            expense_share: share(member.total_expense, total_expense),
+            categories: member_categories,
+        });
+    }
+
+    Ok(Json(ApiResponse::success(MemberStatisticsListResponse {
+        total_income,
+        total_expense,
+        items,
+    })))
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct ConsolidatedParams {
+    pub ledger_ids: Option<String>,  // Comma-separated, `all` (the default) for every ledger the user can view
+    pub currency: Option<String>,  // Reporting currency, optional when all ledgers share one
+    pub rates: Option<String>,  // Such as `USD:7.1,EUR:7.8`, reporting currency units per unit of each other currency
+    pub start_date: Option<String>,
+    pub end_date: Option<String>,
+    pub period: Option<String>,  // day, week, month (default), year
+    pub bill_type: Option<String>,  // Categories to break down: expense (default) or income
+    pub group_by: Option<String>,  // day, week, month, year
+}
+
+/// Statistics over several ledgers at once, converted to one currency
+pub async fn get_consolidated_statistics(
+    State(api): State<Arc<BillApi>>,
+    RequestLocale(locale): RequestLocale,
+    Extension(current_user): Extension<CurrentUser>,
+    Query(params): Query<ConsolidatedParams>,
+) -> AppResult<Json<ApiResponse<ConsolidatedStatisticsResponse>>> {
+    // Every listed ledger must be viewable, `all` only picks the ones that are
+    let ledgers = match params.ledger_ids.as_deref().map(str::trim) {
+        None | Some("") | Some("all") => api.permission_service.accessible_ledgers(current_user.id).await?,
+        Some(ids) => {
+            let mut ledgers: Vec<LedgerAccess> = Vec::new();
+            for ledger_id in parse_ids(ids.split(','), "Invalid ledger ID")? {
+                if !ledgers.iter().any(|a| a.ledger.id == ledger_id) {
+                    ledgers.push(api.permission_service.ledger_access(ledger_id, current_user.id).await?);
+                }
+            }
+            ledgers
+        }
+    };
+
+    let currency = match params.currency.as_deref().map(str::trim).filter(|c| !c.is_empty()) {
+        Some(currency) => currency.to_uppercase(),
+        None => {
+            let mut currencies = ledgers.iter().map(|a| a.ledger.currency.to_uppercase());
+            let first = currencies.next().unwrap_or_else(|| "CNY".to_string());
+            if currencies.any(|c| c != first) {
+                return Err(AppError::Validation("Currency is required for ledgers with different currencies".to_string()));
+            }
+            first
+        }
+    };
+    let rates = match params.rates {
+        Some(ref rates) => parse_rates(rates)?,
+        None => HashMap::new(),
+    };
+    let mut ledger_rates = Vec::new();
+    for access in &ledgers {
+        let ledger_currency = access.ledger.currency.to_uppercase();
+        let rate = if ledger_currency == currency {
+            1.0
+        } else {
+            *rates
+                .get(&ledger_currency)
+                .ok_or_else(|| AppError::Validation(format!("Missing exchange rate: {}", ledger_currency)))?
+        };
+        ledger_rates.push(rate);
+    }
+
+    let calendar = api.calendar(current_user.id).await?;
+    let today = calendar.today();
+    let (start_date, end_date) = match parse_date_range(params.start_date.as_deref(), params.end_date.as_deref())? {
+        Some(range) => range,
+        None => calendar.period(params.period.as_deref().unwrap_or("month"), today)
+            .ok_or_else(|| AppError::Validation("Invalid period".to_string()))?,
+    };
+    let group_by = match params.group_by.as_deref() {
+        Some(group_by) => group_by,
+        None if end_date - start_date > Duration::days(92) => "month",
+        None => "day",
+    };
+
+    let bill_type = params.bill_type.as_deref().map(parse_bill_type).transpose()?;
+
+    let mut ledger_items = Vec::new();
+    let mut categories: Vec<CategoryStatistics> = Vec::new();
+    let mut trend: BTreeMap<String, (f64, f64)> = BTreeMap::new();
+    for (access, rate) in ledgers.iter().zip(ledger_rates) {
+        let ledger = &access.ledger;
+
+        let stats = api.bill_repo.get_statistics(ledger.id, Some(start_date), Some(end_date)).await?;
+        let (income, expense) = (convert(stats.total_income, rate), convert(stats.total_expense, rate));
+        ledger_items.push(ConsolidatedLedgerResponse {
+            id: ledger.id.to_string(),
+            name: ledger.name.clone(),
+            currency: ledger.currency.clone(),
+            exchange_rate: rate,
+            total_income: income,
+            total_expense: expense,
+            balance: income - expense,
+        });
+
+        // System categories are shared, so the same category can show up in several ledgers
+        for c in api.bill_repo.get_category_statistics(ledger.id, Some(start_date), Some(end_date), bill_type).await? {
+            let amount = convert(c.amount, rate);
+            match categories.iter_mut().find(|e| e.category_id == c.category_id && e.bill_type == c.bill_type) {
+                Some(existing) => {
+                    existing.amount += amount;
+                    existing.count += c.count;
+                }
+                None => categories.push(CategoryStatistics { amount, ..c }),
+            }
+        }
+
+        for t in api.bill_repo.get_trend_statistics(ledger.id, start_date, end_date, group_by, &calendar).await? {
+            let entry = trend.entry(t.period).or_default();
+            entry.0 += convert(t.income, rate);
+            entry.1 += convert(t.expense, rate);
+        }
+    }
+
+    let category_total: f64 = categories.iter().map(|c| c.amount).sum();
+    categories.sort_by(|a, b| b.amount.total_cmp(&a.amount));
+    let mut by_category = Vec::new();
+    for c in categories {
+        let percentage = if category_total > 0.0 { (c.amount / category_total) * 100.0 } else { 0.0 };
+        by_category.push(api.category_statistics_response(CategoryStatistics { percentage, ..c }, locale).await?);
+    }
+
+    let total_income: f64 = ledger_items.iter().map(|l| l.total_income).sum();
+    let total_expense: f64 = ledger_items.iter().map(|l| l.total_expense).sum();
+
+    Ok(Json(ApiResponse::success(ConsolidatedStatisticsResponse {
+        currency,
+        start_date: start_date.to_string(),
+        end_date: end_date.to_string(),
+        ledgers: ledger_items,
+        summary: BillStatisticsResponse {
+            total_income,
+            total_expense,
+            balance: total_income - total_expense,
+            by_category,
+        },
+        trend: trend
+            .into_iter()
+            .map(|(period, (income, expense))| TrendStatisticsResponse {
+                period,
+                income,
+                expense,
+                balance: income - expense,
+            })
+            .collect(),
+    })))
+}
+
+/// Exchange rates given as `CODE:rate` pairs separated by commas
+fn parse_rates(rates: &str) -> AppResult<HashMap<String, f64>> {
+    rates
+        .split(',')
+        .map(str::trim)
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            pair.split_once(':')
+                .and_then(|(code, rate)| {
+                    let rate: f64 = rate.trim().parse().ok().filter(|r: &f64| r.is_finite() && *r > 0.0)?;
+                    Some((code.trim().to_uppercase(), rate))
+                })
+                .ok_or_else(|| AppError::Validation("Invalid exchange rates".to_string()))
+        })
+        .collect()
+}
+
+/// A time of day, `HH:MM` or `HH:MM:SS`
+fn parse_bill_time(value: &str) -> AppResult<NaiveTime> {
+    NaiveTime::parse_from_str(value, "%H:%M")
+        .or_else(|_| NaiveTime::parse_from_str(value, "%H:%M:%S"))
+        .map_err(|_| AppError::Validation("Invalid time format".to_string()))
+}
+
+/// An amount in another currency, rounded to cents
+fn convert(amount: f64, rate: f64) -> f64 {
+    round_cents(amount * rate)
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct ForecastParams {
+    pub ledger_id: String,
+    pub days: Option<u32>,  // 1-365, defaults to the rest of the current month
+}
+
+/// Where the ledger's balance is heading over the next days
+pub async fn get_forecast(
+    State(api): State<Arc<BillApi>>,
+    RequestLocale(locale): RequestLocale,
+    Extension(current_user): Extension<CurrentUser>,
+    Query(params): Query<ForecastParams>,
+) -> AppResult<Json<ApiResponse<ForecastResponse>>> {
+    let ledger_id = Uuid::parse_str(&params.ledger_id)
+        .map_err(|_| AppError::Validation("Invalid ledger ID".to_string()))?;
+
+    api.permission_service.ledger_access(ledger_id, current_user.id).await?;
+
+    let calendar = api.calendar(current_user.id).await?;
+    let today = calendar.today();
+    let days = match params.days {
+        Some(days) if (1..=365).contains(&days) => days,
+        Some(_) => return Err(AppError::Validation("Days must be between 1 and 365".to_string())),
+        None => {
+            let (_, month_end) = calendar.period("month", today).unwrap();
+            ((month_end - today).num_days() as u32).max(1)
+        }
+    };
+
+    let forecast = api.forecast_service.forecast(ledger_id, today, days).await?;
+
+    let mut categories: HashMap<Uuid, Option<Category>> = HashMap::new();
+    for id in forecast
+        .recurring
+        .iter()
+        .map(|r| r.category_id)
+        .chain(forecast.categories.iter().map(|c| c.category_id))
+    {
+        if let Entry::Vacant(entry) = categories.entry(id) {
+            entry.insert(api.category_repo.find_by_id(id).await?);
+        }
+    }
+
+    let recurring = forecast
+        .recurring
+        .iter()
+        .map(|item| RecurringItemResponse {
+            bill_type: item.bill_type.to_string(),
+            category: categories[&item.category_id].as_ref().map(|category| CategoryBriefResponse {
+                id: category.id.to_string(),
+                name: category.localized_name(locale),
+                icon: category.icon.clone(),
+            }),
+            amount: item.amount,
+            note: item.note.clone(),
+            day_of_month: item.day_of_month,
+            occurrences: item.occurrences,
+            last_date: item.last_date.to_string(),
+        })
+        .collect();
+
+    let forecast_categories = forecast
+        .categories
+        .iter()
+        .map(|c| {
+            let category = categories[&c.category_id].as_ref();
+            ForecastCategoryResponse {
+                category_id: c.category_id.to_string(),
+                category_name: category.map(|cat| cat.localized_name(locale)).unwrap_or_default(),
+                category_icon: category.and_then(|cat| cat.icon.clone()),
+                bill_type: c.bill_type.to_string(),
+                daily_average: round_cents(c.daily_average),
+                projected: round_cents(c.daily_average * days as f64),
+            }
+        })
+        .collect();
+
+    let last = forecast.days.last();
+    Ok(Json(ApiResponse::success(ForecastResponse {
+        start_date: (today + Duration::days(1)).to_string(),
+        end_date: (today + Duration::days(days as i64)).to_string(),
+        opening_balance: forecast.opening_balance,
+        closing_balance: last.map_or(forecast.opening_balance, |d| d.balance),
+        lower: last.map_or(forecast.opening_balance, |d| d.lower),
+        upper: last.map_or(forecast.opening_balance, |d| d.upper),
+        history_start: forecast.history_start.to_string(),
+        history_end: forecast.history_end.to_string(),
+        days: forecast
+            .days
+            .iter()
+            .map(|d| ForecastDayResponse {
+                date: d.date.to_string(),
+                income: d.income,
+                expense: d.expense,
+                balance: d.balance,
+                lower: d.lower,
+                upper: d.upper,
+            })
+            .collect(),
+        recurring,
+        categories: forecast_categories,
+    })))
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct PatternParams {
+    pub ledger_id: String,
+    pub start_date: Option<String>,
+    pub end_date: Option<String>,
+    pub period: Option<String>,  // day, week, month, year; used when no dates are given
+    pub bill_type: Option<String>,  // expense (default) or income
+}
+
+/// When money is spent: weekday x hour matrices, a calendar heatmap and the average per weekday
+pub async fn get_pattern_statistics(
+    State(api): State<Arc<BillApi>>,
+    Extension(current_user): Extension<CurrentUser>,
+    Query(params): Query<PatternParams>,
+) -> AppResult<Json<ApiResponse<PatternStatisticsResponse>>> {
+    let ledger_id = Uuid::parse_str(&params.ledger_id)
+        .map_err(|_| AppError::Validation("Invalid ledger ID".to_string()))?;
+
+    api.permission_service.ledger_access(ledger_id, current_user.id).await?;
+
+    let calendar = api.calendar(current_user.id).await?;
+    let (start_date, end_date) = match parse_date_range(params.start_date.as_deref(), params.end_date.as_deref())? {
+        Some(range) => range,
+        None => calendar.period(params.period.as_deref().unwrap_or("year"), calendar.today())
+            .ok_or_else(|| AppError::Validation("Invalid period".to_string()))?,
+    };
+    if (end_date - start_date).num_days() >= PATTERN_MAX_DAYS {
+        return Err(AppError::Validation("Date range cannot exceed 366 days".to_string()));
+    }
+
+    let bill_type = params.bill_type.as_deref().map(parse_bill_type).transpose()?.unwrap_or(BillType::Expense);
+
+    let stats = api
+        .bill_repo
+        .get_hourly_statistics(ledger_id, start_date, end_date, bill_type)
+        .await?;
+
+    // Indexed by days from Monday
+    let mut matrix = [([0.0; 24], [0u32; 24]); 7];
+    let mut weekday_totals = [(0.0, 0u32); 7];
+    let mut daily: BTreeMap<NaiveDate, (f64, u32)> = BTreeMap::new();
+    let (mut untimed_amount, mut untimed_count) = (0.0, 0);
+    for stat in &stats {
+        let weekday = stat.date.weekday().num_days_from_monday() as usize;
+        match stat.hour {
+            Some(hour) => {
+                matrix[weekday].0[hour as usize] += stat.amount;
+                matrix[weekday].1[hour as usize] += stat.count;
+            }
+            None => {
+                untimed_amount += stat.amount;
+                untimed_count += stat.count;
+            }
+        }
+        weekday_totals[weekday].0 += stat.amount;
+        weekday_totals[weekday].1 += stat.count;
+        let day = daily.entry(stat.date).or_default();
+        day.0 += stat.amount;
+        day.1 += stat.count;
+    }
+
+    let mut amounts: Vec<f64> = daily.values().map(|(amount, _)| *amount).collect();
+    amounts.sort_by(f64::total_cmp);
+    let thresholds: Vec<f64> = match amounts.is_empty() {
+        true => Vec::new(),
+        false => [0.25, 0.5, 0.75].iter().map(|q| round_cents(quantile(&amounts, *q))).collect(),
+    };
+
+    let mut weekday_days = [0u32; 7];
+    let mut days = Vec::new();
+    for date in start_date.iter_days().take_while(|d| *d <= end_date) {
+        weekday_days[date.weekday().num_days_from_monday() as usize] += 1;
+        let (amount, count) = daily.get(&date).copied().unwrap_or_default();
+        days.push(HeatmapDayResponse {
+            date: date.to_string(),
+            amount: round_cents(amount),
+            count,
+            level: heatmap_level(amount, count, &thresholds),
+        });
+    }
+
+    let week_order = (0..7).map(|i| (calendar.week_start.num_days_from_monday() as usize + i) % 7);
+    let weekday_hour = week_order
+        .clone()
+        .map(|i| WeekdayHourResponse {
+            weekday: i as u32 + 1,
+            amounts: matrix[i].0.iter().map(|a| round_cents(*a)).collect(),
+            counts: matrix[i].1.to_vec(),
+        })
+        .collect();
+    let weekdays = week_order
+        .map(|i| {
+            let (total, count) = weekday_totals[i];
+            WeekdayAverageResponse {
+                weekday: i as u32 + 1,
+                total: round_cents(total),
+                count,
+                days: weekday_days[i],
+                average: match weekday_days[i] {
+                    0 => 0.0,
+                    n => round_cents(total / n as f64),
+                },
+            }
+        })
+        .collect();
+
+    Ok(Json(ApiResponse::success(PatternStatisticsResponse {
+        start_date: start_date.to_string(),
+        end_date: end_date.to_string(),
+        bill_type: bill_type.to_string(),
+        weekday_hour,
+        untimed_amount: round_cents(untimed_amount),
+        untimed_count,
+        heatmap: HeatmapResponse { thresholds, days },
+        weekdays,
+    })))
+}
+
+/// 0 for a day without bills, else 1 plus the number of thresholds its amount is above
+fn heatmap_level(amount: f64, count: u32, thresholds: &[f64]) -> u8 {
+    match count {
+        0 => 0,
+        _ => 1 + thresholds.iter().filter(|t| round_cents(amount) > **t).count() as u8,
+    }
+}
+
+/// The `q` quantile of sorted values, interpolating between neighbours
+fn quantile(sorted: &[f64], q: f64) -> f64 {
+    let position = q * (sorted.len() - 1) as f64;
+    let (lower, upper) = (position.floor() as usize, position.ceil() as usize);
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (position - lower as f64)
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct AnomalyParams {
+    pub ledger_id: String,
+    pub start_date: Option<String>,
+    pub end_date: Option<String>,
+    pub period: Option<String>,  // day, week, month, year; used when no dates are given
+}
+
+/// Unusual spending in a period: bills far above their category's usual amount,
+/// categories costing much more than before and sizeable bills at merchants never seen before
+pub async fn list_anomalies(
+    State(api): State<Arc<BillApi>>,
+    RequestLocale(locale): RequestLocale,
+    Extension(current_user): Extension<CurrentUser>,
+    Query(params): Query<AnomalyParams>,
+) -> AppResult<Json<ApiResponse<AnomalyListResponse>>> {
+    let ledger_id = Uuid::parse_str(&params.ledger_id)
+        .map_err(|_| AppError::Validation("Invalid ledger ID".to_string()))?;
+
+    api.permission_service.ledger_access(ledger_id, current_user.id).await?;
+
+    let calendar = api.calendar(current_user.id).await?;
+    let (start_date, end_date) = match parse_date_range(params.start_date.as_deref(), params.end_date.as_deref())? {
+        Some(range) => range,
+        None => calendar.period(params.period.as_deref().unwrap_or("month"), calendar.today())
+            .ok_or_else(|| AppError::Validation("Invalid period".to_string()))?,
+    };
+
+    let anomalies = api.anomaly_service.detect(ledger_id, start_date, end_date).await?;
+
+    let mut categories: HashMap<Uuid, Option<Category>> = HashMap::new();
+    let mut items = Vec::new();
+    for anomaly in anomalies {
+        if let Entry::Vacant(entry) = categories.entry(anomaly.category_id) {
+            entry.insert(api.category_repo.find_by_id(anomaly.category_id).await?);
+        }
+        let category = categories[&anomaly.category_id].as_ref();
+        let bill = match anomaly.bill {
+            Some(bill) => Some(api.bill_response(bill, locale).await?),
+            None => None,
+        };
+        items.push(AnomalyResponse {
+            kind: anomaly.kind.to_string(),
+            bill,
+            category_id: anomaly.category_id.to_string(),
+            category_name: category.map(|c| c.localized_name(locale)).unwrap_or_default(),
+            category_icon: category.and_then(|c| c.icon.clone()),
+            date: anomaly.date.to_string(),
+            amount: round_cents(anomaly.amount),
+            expected: round_cents(anomaly.expected),
+            score: round_cents(anomaly.score),
+            merchant: anomaly.merchant,
+        });
+    }
+
+    Ok(Json(ApiResponse::success(AnomalyListResponse {
+        start_date: start_date.to_string(),
+        end_date: end_date.to_string(),
+        items,
+    })))
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct CategoryStatsParams {
+    pub ledger_id: String,
+    pub start_date: Option<String>,
+    pub end_date: Option<String>,
+    pub bill_type: Option<String>,
+}
+
+pub async fn get_category_statistics(
+    State(api): State<Arc<BillApi>>,
+    RequestLocale(locale): RequestLocale,
+    Extension(current_user): Extension<CurrentUser>,
+    Query(params): Query<CategoryStatsParams>,
+) -> AppResult<Json<ApiResponse<Vec<CategoryStatisticsResponse>>>> {
+    let ledger_id = Uuid::parse_str(&params.ledger_id)
+        .map_err(|_| AppError::Validation("Invalid ledger ID".to_string()))?;
+
+    // Check access to ledger
+    api.permission_service.ledger_access(ledger_id, current_user.id).await?;
+
+    let start_date = parse_date(params.start_date.as_deref(), "Invalid start date")?;
+    let end_date = parse_date(params.end_date.as_deref(), "Invalid end date")?;
+    let bill_type = params.bill_type.as_deref().map(parse_bill_type).transpose()?;
 
     let category_stats = api.bill_repo.get_category_statistics(ledger_id, start_date, end_date, bill_type).await?;
 
     let mut result: Vec<CategoryStatisticsResponse> = Vec::new();
     for c in category_stats {
-        let category = api.category_repo.find_by_id(c.category_id).await?;
-        result.push(CategoryStatisticsResponse {
-            category_id: c.category_id.to_string(),
-            category_name: category.as_ref().map(|cat| cat.name.clone()).unwrap_or_else(|| c.category_name),
-            category_icon: category.and_then(|cat| cat.icon),
-            bill_type: c.bill_type.to_string(),
-            amount: c.amount,
-            count: c.count,
-            percentage: c.percentage,
-        });
+        result.push(api.category_statistics_response(c, locale).await?);
     }
 
     Ok(Json(ApiResponse::success(result)))
 }
 
+pub async fn get_category_statistics_tree(
+    State(api): State<Arc<BillApi>>,
+    RequestLocale(locale): RequestLocale,
+    Extension(current_user): Extension<CurrentUser>,
+    Query(params): Query<CategoryStatsParams>,
+) -> AppResult<Json<ApiResponse<Vec<CategoryStatisticsNodeResponse>>>> {
+    let ledger_id = Uuid::parse_str(&params.ledger_id)
+        .map_err(|_| AppError::Validation("Invalid ledger ID".to_string()))?;
+
+    // Check access to ledger
+    api.permission_service.ledger_access(ledger_id, current_user.id).await?;
+
+    let start_date = parse_date(params.start_date.as_deref(), "Invalid start date")?;
+    let end_date = parse_date(params.end_date.as_deref(), "Invalid end date")?;
+    let bill_type = params.bill_type.as_deref().map(parse_bill_type).transpose()?;
+
+    let category_stats = api.bill_repo.get_category_statistics(ledger_id, start_date, end_date, bill_type).await?;
+
+    let mut categories: HashMap<Uuid, Category> = api
+        .category_repo
+        .find_by_ledger_id(Some(ledger_id), None, true)
+        .await?
+        .into_iter()
+        .map(|c| (c.id, c))
+        .collect();
+
+    // Bills can still point at trashed categories, load those and their parents too
+    for c in &category_stats {
+        let mut missing = Some(c.category_id).filter(|id| !categories.contains_key(id));
+        while let Some(category_id) = missing {
+            let Some(category) = api.category_repo.find_by_id(category_id).await? else {
+                break;
+            };
+            missing = category.parent_id.filter(|id| !categories.contains_key(id));
+            categories.insert(category.id, category);
+        }
+    }
+
+    let own: HashMap<Uuid, (f64, u32)> = category_stats
+        .iter()
+        .map(|c| (c.category_id, (c.amount, c.count)))
+        .collect();
+    let total: f64 = category_stats.iter().map(|c| c.amount).sum();
+
+    let mut children_map: HashMap<Uuid, Vec<&Category>> = HashMap::new();
+    let mut roots: Vec<&Category> = Vec::new();
+    for category in categories.values() {
+        match category.parent_id.filter(|id| categories.contains_key(id)) {
+            Some(parent_id) => children_map.entry(parent_id).or_default().push(category),
+            None => roots.push(category),
+        }
+    }
+
+    let mut tree: Vec<CategoryStatisticsNodeResponse> = roots
+        .into_iter()
+        .filter_map(|category| build_statistics_node(category, &children_map, &own, total, locale))
+        .collect();
+    tree.sort_by(|a, b| b.amount.partial_cmp(&a.amount).unwrap_or(std::cmp::Ordering::Equal));
+
+    Ok(Json(ApiResponse::success(tree)))
+}
+
+/// Rolls bill totals up from the leaves, dropping branches without any bills
+fn build_statistics_node(
+    category: &Category,
+    children_map: &HashMap<Uuid, Vec<&Category>>,
+    own: &HashMap<Uuid, (f64, u32)>,
+    total: f64,
+    locale: Option<Locale>,
+) -> Option<CategoryStatisticsNodeResponse> {
+    let mut children: Vec<CategoryStatisticsNodeResponse> = children_map
+        .get(&category.id)
+        .map(|children| {
+            children
+                .iter()
+                .filter_map(|child| build_statistics_node(child, children_map, own, total, locale))
+                .collect()
+        })
+        .unwrap_or_default();
+    children.sort_by(|a, b| b.amount.partial_cmp(&a.amount).unwrap_or(std::cmp::Ordering::Equal));
+
+    let (own_amount, own_count) = own.get(&category.id).copied().unwrap_or((0.0, 0));
+    let amount = own_amount + children.iter().map(|c| c.amount).sum::<f64>();
+    let count = own_count + children.iter().map(|c| c.count).sum::<u32>();
+    if count == 0 {
+        return None;
+    }
+
+    Some(CategoryStatisticsNodeResponse {
+        category_id: category.id.to_string(),
+        category_name: category.localized_name(locale),
+        category_icon: category.icon.clone(),
+        bill_type: category.category_type.to_string(),
+        amount,
+        count,
+        own_amount,
+        own_count,
+        percentage: if total > 0.0 { (amount / total) * 100.0 } else { 0.0 },
+        children,
+    })
+}
+
+/// Groups of bills that look entered more than once, for cleanup
+pub async fn list_duplicates(
+    State(api): State<Arc<BillApi>>,
+    RequestLocale(locale): RequestLocale,
+    Extension(current_user): Extension<CurrentUser>,
+    Query(params): Query<DuplicateQueryParams>,
+) -> AppResult<Json<ApiResponse<DuplicateClusterListResponse>>> {
+    let ledger_id = Uuid::parse_str(&params.ledger_id)
+        .map_err(|_| AppError::Validation("Invalid ledger ID".to_string()))?;
+
+    api.permission_service.ledger_access(ledger_id, current_user.id).await?;
+
+    let start_date = parse_date(params.start_date.as_deref(), "Invalid start date")?;
+    let end_date = parse_date(params.end_date.as_deref(), "Invalid end date")?;
+    let window_days = params.window_days.unwrap_or(api.duplicate_service.window_days());
+    if !(0..=31).contains(&window_days) {
+        return Err(AppError::Validation("Window must be 0-31 days".to_string()));
+    }
+
+    let clusters = api
+        .duplicate_service
+        .find_clusters(ledger_id, start_date, end_date, window_days)
+        .await?;
+
+    let mut items = Vec::new();
+    for cluster in clusters {
+        let mut bills = Vec::new();
+        for bill in cluster {
+            bills.push(api.bill_response(bill, locale).await?);
+        }
+        items.push(DuplicateClusterResponse { bills });
+    }
+
+    Ok(Json(ApiResponse::success(DuplicateClusterListResponse { items, window_days })))
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct SuggestCategoryParams {
+    pub ledger_id: String,
+    pub note: Option<String>,
+    pub amount: Option<f64>,
+    #[serde(rename = "type")]
+    pub bill_type: Option<String>,
+    pub limit: Option<usize>,
+}
+
+pub async fn suggest_category(
+    State(api): State<Arc<BillApi>>,
+    RequestLocale(locale): RequestLocale,
+    Extension(current_user): Extension<CurrentUser>,
+    Query(params): Query<SuggestCategoryParams>,
+) -> AppResult<Json<ApiResponse<CategorySuggestionListResponse>>> {
+    let ledger_id = Uuid::parse_str(&params.ledger_id)
+        .map_err(|_| AppError::Validation("Invalid ledger ID".to_string()))?;
+
+    api.permission_service.ledger_access(ledger_id, current_user.id).await?;
+
+    let bill_type = params.bill_type.as_deref().map(parse_bill_type).transpose()?.unwrap_or(BillType::Expense);
+    let limit = params.limit.unwrap_or(3).clamp(1, 10);
+
+    let (suggestions, trained_on) = api
+        .suggestion_service
+        .suggest(ledger_id, params.note.as_deref(), params.amount, bill_type, limit)
+        .await?;
+
+    let mut items = Vec::new();
+    for suggestion in suggestions {
+        let Some(category) = api.category_repo.find_by_id(suggestion.category_id).await? else {
+            continue;
+        };
+        items.push(CategorySuggestionResponse {
+            category: CategoryBriefResponse {
+                id: category.id.to_string(),
+                name: category.localized_name(locale),
+                icon: category.icon,
+            },
+            confidence: suggestion.confidence,
+        });
+    }
+
+    Ok(Json(ApiResponse::success(CategorySuggestionListResponse { items, trained_on })))
+}
+
 #[derive(Debug, serde::Deserialize)]
 pub struct TrendParams {
     pub ledger_id: String,
@@ -509,24 +2048,15 @@ pub async fn get_trend_statistics(
     let ledger_id = Uuid::parse_str(&params.ledger_id)
         .map_err(|_| AppError::Validation("Invalid ledger ID".to_string()))?;
 
-    // Check access
-    let ledger = api
-        .ledger_repo
-        .find_by_id(ledger_id)
-        .await?
-        .ok_or_else(|| AppError::NotFound("Ledger not found".to_string()))?;
-
-    if ledger.user_id != Some(current_user.id) && ledger.group_id.is_none() {
-        return Err(AppError::Forbidden("Access denied".to_string()));
-    }
+    // Check access to ledger
+    api.permission_service.ledger_access(ledger_id, current_user.id).await?;
 
-    let start_date = NaiveDate::parse_from_str(&params.start_date, "%Y-%m-%d")
-        .map_err(|_| AppError::Validation("Invalid start date".to_string()))?;
-    let end_date = NaiveDate::parse_from_str(&params.end_date, "%Y-%m-%d")
-        .map_err(|_| AppError::Validation("Invalid end date".to_string()))?;
+    let (start_date, end_date) = parse_date_range(Some(&params.start_date), Some(&params.end_date))?
+        .ok_or_else(|| AppError::Validation("Invalid date range".to_string()))?;
     let group_by = params.group_by.as_deref().unwrap_or("month");
+    let calendar = api.calendar(current_user.id).await?;
 
-    let trend_stats = api.bill_repo.get_trend_statistics(ledger_id, start_date, end_date, group_by).await?;
+    let trend_stats = api.bill_repo.get_trend_statistics(ledger_id, start_date, end_date, group_by, &calendar).await?;
 
     let result: Vec<TrendStatisticsResponse> = trend_stats
         .into_iter()
@@ -540,3 +2070,67 @@ pub async fn get_trend_statistics(
 
     Ok(Json(ApiResponse::success(result)))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_rates_reads_code_rate_pairs() {
+        let rates = parse_rates(" usd:0.14, EUR : 0.13 ,").unwrap();
+        assert_eq!(rates.len(), 2);
+        assert_eq!(rates["USD"], 0.14);
+        assert_eq!(rates["EUR"], 0.13);
+        assert!(parse_rates("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn parse_rates_rejects_bad_pairs() {
+        for rates in ["USD", "USD:abc", "USD:0", "USD:-1", "USD:inf", "USD:0.14,EUR"] {
+            assert!(matches!(parse_rates(rates), Err(AppError::Validation(_))), "{}", rates);
+        }
+    }
+
+    #[test]
+    fn date_ranges_need_both_ends_in_order() {
+        let day = |s| NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap();
+        assert_eq!(
+            parse_date_range(Some("2026-10-01"), Some("2026-10-18")).unwrap(),
+            Some((day("2026-10-01"), day("2026-10-18")))
+        );
+        assert_eq!(parse_date_range(Some("2026-10-01"), None).unwrap(), None);
+        for (start, end) in [(Some("2026-10-18"), Some("2026-10-01")), (Some("2026-13-01"), None), (None, Some("yesterday"))] {
+            assert!(matches!(parse_date_range(start, end), Err(AppError::Validation(_))));
+        }
+    }
+
+    #[test]
+    fn bill_types_are_strict() {
+        assert_eq!(parse_bill_type("income").unwrap(), BillType::Income);
+        assert_eq!(parse_bill_type("expense").unwrap(), BillType::Expense);
+        assert!(matches!(parse_bill_type("Expense"), Err(AppError::Validation(_))));
+    }
+
+    #[test]
+    fn quantile_interpolates_between_values() {
+        let sorted = [10.0, 20.0, 30.0, 40.0, 50.0];
+        assert_eq!(quantile(&sorted, 0.0), 10.0);
+        assert_eq!(quantile(&sorted, 0.5), 30.0);
+        assert_eq!(quantile(&sorted, 1.0), 50.0);
+        assert_eq!(quantile(&[10.0, 20.0], 0.25), 12.5);
+        assert_eq!(quantile(&[7.0], 0.75), 7.0);
+    }
+
+    #[test]
+    fn heatmap_levels_follow_the_quartiles() {
+        let thresholds = [20.0, 30.0, 40.0];
+        assert_eq!(heatmap_level(0.0, 0, &thresholds), 0);
+        assert_eq!(heatmap_level(0.0, 1, &thresholds), 1);
+        assert_eq!(heatmap_level(20.0, 1, &thresholds), 1);
+        assert_eq!(heatmap_level(20.01, 2, &thresholds), 2);
+        assert_eq!(heatmap_level(35.0, 1, &thresholds), 3);
+        assert_eq!(heatmap_level(100.0, 3, &thresholds), 4);
+        // Without thresholds every day with bills is level 1
+        assert_eq!(heatmap_level(100.0, 3, &[]), 1);
+    }
+}
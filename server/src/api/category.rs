@@ -2,72 +2,202 @@ use axum::{
     extract::{Path, Query, State},
     Extension, Json,
 };
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::dto::{
-    ApiResponse, CategoryListResponse, CategoryResponse, CreateCategoryRequest,
+    ApiResponse, CategoryListResponse, CategoryMergeResponse, CategoryResponse,
+    CreateCategoryRequest, MergeCategoryRequest, MoveCategoryRequest,
 };
 use crate::error::{AppError, AppResult};
-use crate::middleware::CurrentUser;
-use crate::models::{BillType, Category, CreateCategory};
-use crate::repositories::CategoryRepository;
+use crate::i18n::Locale;
+use crate::middleware::{CurrentUser, RequestLocale};
+use crate::models::{AuditEntityType, BillType, Category, CreateCategory, GroupCapability};
+use crate::repositories::{BillRepository, CategoryRepository};
+use crate::services::{AuditService, PermissionService};
 
 pub struct CategoryApi {
     category_repo: Arc<dyn CategoryRepository>,
+    bill_repo: Arc<dyn BillRepository>,
+    audit_service: Arc<AuditService>,
+    permission_service: Arc<PermissionService>,
 }
 
 impl CategoryApi {
-    pub fn new(category_repo: Arc<dyn CategoryRepository>) -> Self {
-        Self { category_repo }
+    pub fn new(
+        category_repo: Arc<dyn CategoryRepository>,
+        bill_repo: Arc<dyn BillRepository>,
+        audit_service: Arc<AuditService>,
+        permission_service: Arc<PermissionService>,
+    ) -> Self {
+        Self {
+            category_repo,
+            bill_repo,
+            audit_service,
+            permission_service,
+        }
+    }
+
+    /// Custom categories need `manage_categories` in their ledger, system defaults are read-only.
+    /// Returns the ledger's group so the change shows up in its activity feed.
+    async fn check_manage(&self, category: &Category, user_id: Uuid) -> AppResult<Option<Uuid>> {
+        let ledger_id = category
+            .ledger_id
+            .ok_or_else(|| AppError::Forbidden("System categories cannot be modified".to_string()))?;
+
+        let access = self.permission_service.ledger_access(ledger_id, user_id).await?;
+        access.require(GroupCapability::ManageCategories)?;
+        Ok(access.ledger.group_id)
+    }
+
+    async fn find_category(&self, category_id: Uuid) -> AppResult<Category> {
+        self.category_repo
+            .find_by_id(category_id)
+            .await?
+            .filter(|c| c.deleted_at.is_none())
+            .ok_or_else(|| AppError::NotFound("Category not found".to_string()))
+    }
+
+    /// The category itself plus everything below it, parents before children
+    async fn subtree(&self, category: &Category) -> AppResult<Vec<Category>> {
+        let ledger_categories = self
+            .category_repo
+            .find_by_ledger_id(category.ledger_id, None, true)
+            .await?;
+
+        let mut subtree = vec![category.clone()];
+        let mut next = 0;
+        while next < subtree.len() {
+            let parent_id = subtree[next].id;
+            subtree.extend(
+                ledger_categories
+                    .iter()
+                    .filter(|c| c.parent_id == Some(parent_id))
+                    .cloned(),
+            );
+            next += 1;
+        }
+        Ok(subtree)
+    }
+
+    /// Resolves the category `source` is merged into or moved under.
+    /// It has to be usable in the source's ledger, of the same type, and outside the source's subtree.
+    async fn find_destination(&self, source: &Category, destination_id: &str, subtree: &[Category]) -> AppResult<Category> {
+        let destination_id = Uuid::parse_str(destination_id)
+            .map_err(|_| AppError::Validation("Invalid category ID".to_string()))?;
+        let ledger_id = source.ledger_id.unwrap_or_default();
+
+        let destination = self
+            .category_repo
+            .find_by_id(destination_id)
+            .await?
+            .filter(|c| c.is_usable_in(ledger_id))
+            .ok_or_else(|| AppError::Validation("Category not found in this ledger".to_string()))?;
+
+        if destination.category_type != source.category_type {
+            return Err(AppError::Validation("Categories have different types".to_string()));
+        }
+        if subtree.iter().any(|c| c.id == destination.id) {
+            return Err(AppError::Validation("Target is inside this category".to_string()));
+        }
+        Ok(destination)
+    }
+
+    async fn set_archived(&self, category_id: Uuid, user_id: Uuid, archived: bool) -> AppResult<Category> {
+        let existing = self.find_category(category_id).await?;
+        let group_id = self.check_manage(&existing, user_id).await?;
+
+        if !archived {
+            if let Some(parent_id) = existing.parent_id {
+                let parent = self.find_category(parent_id).await?;
+                if parent.archived_at.is_some() {
+                    return Err(AppError::Validation("Parent category is archived".to_string()));
+                }
+            }
+        }
+
+        // Archiving hides the whole branch, unarchiving brings it back
+        for before in self.subtree(&existing).await? {
+            if before.archived_at.is_some() == archived {
+                continue;
+            }
+            let after = self.category_repo.set_archived(before.id, archived).await?;
+            self.audit_service
+                .record_update(user_id, AuditEntityType::Category, after.id, group_id, &before, &after)
+                .await;
+        }
+
+        self.find_category(category_id).await
     }
 }
 
-fn category_to_response(category: &Category, children: Vec<CategoryResponse>) -> CategoryResponse {
+fn category_to_response(category: &Category, children: Vec<CategoryResponse>, locale: Option<Locale>) -> CategoryResponse {
     CategoryResponse {
         id: category.id.to_string(),
-        name: category.name.clone(),
+        name: category.localized_name(locale),
         icon: category.icon.clone(),
         category_type: category.category_type.to_string(),
+        ledger_id: category.ledger_id.map(|id| id.to_string()),
+        archived: category.archived_at.is_some(),
         children,
     }
 }
 
+fn build_category_tree(
+    category: &Category,
+    children_map: &HashMap<Uuid, Vec<&Category>>,
+    locale: Option<Locale>,
+) -> CategoryResponse {
+    let children = children_map
+        .get(&category.id)
+        .map(|children| children.iter().map(|child| build_category_tree(child, children_map, locale)).collect())
+        .unwrap_or_default();
+    category_to_response(category, children, locale)
+}
+
 pub async fn list_categories(
     State(api): State<Arc<CategoryApi>>,
+    RequestLocale(locale): RequestLocale,
+    current_user: Option<Extension<CurrentUser>>,
     Query(params): Query<ListCategoriesParams>,
 ) -> AppResult<Json<ApiResponse<CategoryListResponse>>> {
-    let ledger_id = params.ledger_id.as_ref().and_then(|id| Uuid::parse_str(id).ok());
+    let ledger_id = match params.ledger_id {
+        Some(ref id) => Some(Uuid::parse_str(id).map_err(|_| AppError::Validation("Invalid ledger ID".to_string()))?),
+        None => None,
+    };
+
+    // System defaults are public, a ledger's own categories are not
+    if let Some(ledger_id) = ledger_id {
+        let Extension(current_user) = current_user.ok_or(AppError::Unauthorized)?;
+        api.permission_service.ledger_access(ledger_id, current_user.id).await?;
+    }
     let category_type = params.category_type.as_ref().and_then(|t| match t.as_str() {
         "income" => Some(BillType::Income),
         "expense" => Some(BillType::Expense),
         _ => None,
     });
 
-    let categories = api.category_repo.find_by_ledger_id(ledger_id, category_type).await?;
-
-    // Build tree structure
-    let mut root_categories: Vec<CategoryResponse> = Vec::new();
-    let mut children_map: std::collections::HashMap<Uuid, Vec<Category>> = std::collections::HashMap::new();
+    let categories = api
+        .category_repo
+        .find_by_ledger_id(ledger_id, category_type, params.include_archived.unwrap_or(false))
+        .await?;
 
-    // Group children by parent_id
+    // Group children by parent_id, categories whose parent is filtered out become roots
+    let known: HashSet<Uuid> = categories.iter().map(|c| c.id).collect();
+    let mut children_map: HashMap<Uuid, Vec<&Category>> = HashMap::new();
+    let mut roots: Vec<&Category> = Vec::new();
     for cat in &categories {
-        if let Some(parent_id) = cat.parent_id {
-            children_map.entry(parent_id).or_default().push(cat.clone());
+        match cat.parent_id.filter(|id| known.contains(id)) {
+            Some(parent_id) => children_map.entry(parent_id).or_default().push(cat),
+            None => roots.push(cat),
         }
     }
 
-    // Build response
-    for cat in &categories {
-        if cat.parent_id.is_none() {
-            let children: Vec<CategoryResponse> = children_map
-                .get(&cat.id)
-                .map(|c| c.iter().map(|child| category_to_response(child, vec![])).collect())
-                .unwrap_or_default();
-
-            root_categories.push(category_to_response(cat, children));
-        }
-    }
+    let root_categories: Vec<CategoryResponse> = roots
+        .into_iter()
+        .map(|cat| build_category_tree(cat, &children_map, locale))
+        .collect();
 
     Ok(Json(ApiResponse::success(CategoryListResponse {
         items: root_categories,
@@ -76,7 +206,8 @@ pub async fn list_categories(
 
 pub async fn create_category(
     State(api): State<Arc<CategoryApi>>,
-    Extension(_current_user): Extension<CurrentUser>,
+    RequestLocale(locale): RequestLocale,
+    Extension(current_user): Extension<CurrentUser>,
     Json(req): Json<CreateCategoryRequest>,
 ) -> AppResult<Json<ApiResponse<CategoryResponse>>> {
     let category_type = match req.category_type.as_str() {
@@ -85,7 +216,29 @@ pub async fn create_category(
         _ => return Err(AppError::Validation("Invalid category type".to_string())),
     };
 
-    let parent_id = req.parent_id.as_ref().and_then(|id| Uuid::parse_str(id).ok());
+    let ledger_id = Uuid::parse_str(&req.ledger_id)
+        .map_err(|_| AppError::Validation("Invalid ledger ID".to_string()))?;
+
+    let access = api.permission_service.ledger_access(ledger_id, current_user.id).await?;
+    access.require(GroupCapability::ManageCategories)?;
+
+    let parent_id = match req.parent_id {
+        Some(ref id) => {
+            let parent_id = Uuid::parse_str(id)
+                .map_err(|_| AppError::Validation("Invalid parent ID".to_string()))?;
+            let parent = api
+                .category_repo
+                .find_by_id(parent_id)
+                .await?
+                .filter(|p| p.is_usable_in(ledger_id))
+                .ok_or_else(|| AppError::Validation("Parent category not found".to_string()))?;
+            if parent.category_type != category_type {
+                return Err(AppError::Validation("Parent category has a different type".to_string()));
+            }
+            Some(parent_id)
+        }
+        None => None,
+    };
 
     let category = api
         .category_repo
@@ -94,49 +247,166 @@ pub async fn create_category(
             icon: req.icon,
             category_type,
             parent_id,
-            ledger_id: None, // Custom categories linked to ledger
+            ledger_id: Some(ledger_id),
             sort_order: None,
         })
         .await?;
 
-    Ok(Json(ApiResponse::success(category_to_response(&category, vec![]))))
+    api.audit_service
+        .record_create(current_user.id, AuditEntityType::Category, category.id, access.ledger.group_id, &category)
+        .await;
+
+    Ok(Json(ApiResponse::success(category_to_response(&category, vec![], locale))))
 }
 
 pub async fn update_category(
     State(api): State<Arc<CategoryApi>>,
-    Extension(_current_user): Extension<CurrentUser>,
+    RequestLocale(locale): RequestLocale,
+    Extension(current_user): Extension<CurrentUser>,
     Path(id): Path<String>,
     Json(req): Json<UpdateCategoryRequest>,
 ) -> AppResult<Json<ApiResponse<CategoryResponse>>> {
     let category_id = Uuid::parse_str(&id)
         .map_err(|_| AppError::Validation("Invalid category ID".to_string()))?;
 
+    let existing = api.find_category(category_id).await?;
+    let group_id = api.check_manage(&existing, current_user.id).await?;
+
     let category = api
         .category_repo
         .update(category_id, req.name, req.icon, req.sort_order)
         .await?;
 
-    Ok(Json(ApiResponse::success(category_to_response(&category, vec![]))))
+    api.audit_service
+        .record_update(current_user.id, AuditEntityType::Category, category.id, group_id, &existing, &category)
+        .await;
+
+    Ok(Json(ApiResponse::success(category_to_response(&category, vec![], locale))))
 }
 
 pub async fn delete_category(
     State(api): State<Arc<CategoryApi>>,
-    Extension(_current_user): Extension<CurrentUser>,
+    Extension(current_user): Extension<CurrentUser>,
     Path(id): Path<String>,
 ) -> AppResult<Json<ApiResponse<()>>> {
     let category_id = Uuid::parse_str(&id)
         .map_err(|_| AppError::Validation("Invalid category ID".to_string()))?;
 
+    let existing = api.find_category(category_id).await?;
+    let group_id = api.check_manage(&existing, current_user.id).await?;
+
     api.category_repo.delete(category_id).await?;
 
+    api.audit_service
+        .record_delete(current_user.id, AuditEntityType::Category, category_id, group_id, &existing)
+        .await;
+
     Ok(Json(ApiResponse::success(())))
 }
 
+pub async fn merge_category(
+    State(api): State<Arc<CategoryApi>>,
+    RequestLocale(locale): RequestLocale,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<String>,
+    Json(req): Json<MergeCategoryRequest>,
+) -> AppResult<Json<ApiResponse<CategoryMergeResponse>>> {
+    let category_id = Uuid::parse_str(&id)
+        .map_err(|_| AppError::Validation("Invalid category ID".to_string()))?;
+
+    let source = api.find_category(category_id).await?;
+    let group_id = api.check_manage(&source, current_user.id).await?;
+    let subtree = api.subtree(&source).await?;
+    let target = api.find_destination(&source, &req.target_id, &subtree).await?;
+
+    // Bills in the trash move too so restoring them never points at a merged-away category
+    let children: Vec<&Category> = subtree.iter().filter(|c| c.parent_id == Some(source.id)).collect();
+    let child_ids: Vec<Uuid> = children.iter().map(|c| c.id).collect();
+    let moved_bills = api
+        .category_repo
+        .merge(api.bill_repo.as_ref(), source.id, target.id, &child_ids)
+        .await?;
+
+    for child in children {
+        if let Some(moved) = api.category_repo.find_by_id(child.id).await? {
+            api.audit_service
+                .record_update(current_user.id, AuditEntityType::Category, moved.id, group_id, child, &moved)
+                .await;
+        }
+    }
+
+    api.audit_service
+        .record_delete(current_user.id, AuditEntityType::Category, source.id, group_id, &source)
+        .await;
+
+    Ok(Json(ApiResponse::success(CategoryMergeResponse {
+        target: category_to_response(&target, vec![], locale),
+        moved_bills,
+    })))
+}
+
+pub async fn move_category(
+    State(api): State<Arc<CategoryApi>>,
+    RequestLocale(locale): RequestLocale,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<String>,
+    Json(req): Json<MoveCategoryRequest>,
+) -> AppResult<Json<ApiResponse<CategoryResponse>>> {
+    let category_id = Uuid::parse_str(&id)
+        .map_err(|_| AppError::Validation("Invalid category ID".to_string()))?;
+
+    let existing = api.find_category(category_id).await?;
+    let group_id = api.check_manage(&existing, current_user.id).await?;
+
+    let parent_id = match req.parent_id {
+        Some(ref parent_id) => {
+            let subtree = api.subtree(&existing).await?;
+            Some(api.find_destination(&existing, parent_id, &subtree).await?.id)
+        }
+        None => None,
+    };
+
+    let category = api.category_repo.set_parent(category_id, parent_id).await?;
+
+    api.audit_service
+        .record_update(current_user.id, AuditEntityType::Category, category.id, group_id, &existing, &category)
+        .await;
+
+    Ok(Json(ApiResponse::success(category_to_response(&category, vec![], locale))))
+}
+
+pub async fn archive_category(
+    State(api): State<Arc<CategoryApi>>,
+    RequestLocale(locale): RequestLocale,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<String>,
+) -> AppResult<Json<ApiResponse<CategoryResponse>>> {
+    let category_id = Uuid::parse_str(&id)
+        .map_err(|_| AppError::Validation("Invalid category ID".to_string()))?;
+
+    let category = api.set_archived(category_id, current_user.id, true).await?;
+    Ok(Json(ApiResponse::success(category_to_response(&category, vec![], locale))))
+}
+
+pub async fn unarchive_category(
+    State(api): State<Arc<CategoryApi>>,
+    RequestLocale(locale): RequestLocale,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<String>,
+) -> AppResult<Json<ApiResponse<CategoryResponse>>> {
+    let category_id = Uuid::parse_str(&id)
+        .map_err(|_| AppError::Validation("Invalid category ID".to_string()))?;
+
+    let category = api.set_archived(category_id, current_user.id, false).await?;
+    Ok(Json(ApiResponse::success(category_to_response(&category, vec![], locale))))
+}
+
 #[derive(Debug, serde::Deserialize)]
 pub struct ListCategoriesParams {
     pub ledger_id: Option<String>,
     #[serde(rename = "type")]
     pub category_type: Option<String>,
+    pub include_archived: Option<bool>,
 }
 
 #[derive(Debug, serde::Deserialize)]
@@ -6,19 +6,24 @@ use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::dto::{
-    ApiResponse, CreateGroupRequest, CreateGroupResponse, GroupDetailResponse,
-    GroupListResponse, GroupMemberResponse, GroupResponse, JoinGroupRequest,
-    LedgerBriefResponse, TransferGroupRequest, UpdateMemberRoleRequest, UserBriefResponse,
+    ApiResponse, CreateGroupRequest, CreateGroupResponse, GroupDetailResponse, GroupListResponse,
+    GroupMemberResponse, GroupPolicyResponse, GroupResponse, JoinGroupRequest, LedgerBriefResponse,
+    TransferGroupRequest, UpdateGroupPolicyRequest, UpdateMemberRoleRequest, UserBriefResponse,
 };
 use crate::error::{AppError, AppResult};
 use crate::middleware::CurrentUser;
-use crate::models::{CreateGroup, GroupRole};
+use crate::models::{
+    AuditEntityType, CreateGroup, GroupCapability, GroupMember, GroupPolicy, GroupRole,
+};
 use crate::repositories::{GroupRepository, LedgerRepository, UserRepository};
+use crate::services::{AuditService, PermissionService};
 
 pub struct GroupApi {
     group_repo: Arc<dyn GroupRepository>,
     user_repo: Arc<dyn UserRepository>,
     ledger_repo: Arc<dyn LedgerRepository>,
+    audit_service: Arc<AuditService>,
+    permission_service: Arc<PermissionService>,
 }
 
 impl GroupApi {
@@ -26,12 +31,47 @@ impl GroupApi {
         group_repo: Arc<dyn GroupRepository>,
         user_repo: Arc<dyn UserRepository>,
         ledger_repo: Arc<dyn LedgerRepository>,
+        audit_service: Arc<AuditService>,
+        permission_service: Arc<PermissionService>,
     ) -> Self {
         Self {
             group_repo,
             user_repo,
             ledger_repo,
+            audit_service,
+            permission_service,
+        }
+    }
+
+    /// Change a member's role and record it in the group activity feed
+    async fn change_member_role(&self, actor_id: Uuid, group_id: Uuid, user_id: Uuid, role: GroupRole) -> AppResult<()> {
+        let before = self.group_repo.get_member(group_id, user_id).await?;
+
+        self.group_repo.update_member_role(group_id, user_id, role).await?;
+
+        if let Some(before) = before {
+            let after = GroupMember { role, ..before.clone() };
+            self.audit_service
+                .record_update(actor_id, AuditEntityType::GroupMember, before.id, Some(group_id), &before, &after)
+                .await;
+        }
+
+        Ok(())
+    }
+
+    /// Remove a member and record it in the group activity feed
+    async fn remove_group_member(&self, actor_id: Uuid, group_id: Uuid, user_id: Uuid) -> AppResult<()> {
+        let before = self.group_repo.get_member(group_id, user_id).await?;
+
+        self.group_repo.remove_member(group_id, user_id).await?;
+
+        if let Some(before) = before {
+            self.audit_service
+                .record_delete(actor_id, AuditEntityType::GroupMember, before.id, Some(group_id), &before)
+                .await;
         }
+
+        Ok(())
     }
 }
 
@@ -74,10 +114,15 @@ pub async fn create_group(
         .await?;
 
     // Add owner as member
-    api.group_repo
+    let member = api
+        .group_repo
         .add_member(group.id, current_user.id, GroupRole::Owner)
         .await?;
 
+    api.audit_service
+        .record_create(current_user.id, AuditEntityType::GroupMember, member.id, Some(group.id), &member)
+        .await;
+
     Ok(Json(ApiResponse::success(CreateGroupResponse {
         id: group.id.to_string(),
         name: group.name,
@@ -223,7 +268,11 @@ pub async fn join_group(
         return Err(AppError::Conflict("Already a member".to_string()));
     }
 
-    api.group_repo.add_member(group.id, current_user.id, GroupRole::Member).await?;
+    let member = api.group_repo.add_member(group.id, current_user.id, GroupRole::Member).await?;
+
+    api.audit_service
+        .record_create(current_user.id, AuditEntityType::GroupMember, member.id, Some(group.id), &member)
+        .await;
 
     let members = api.group_repo.get_members(group.id).await?;
 
@@ -255,7 +304,7 @@ pub async fn leave_group(
         return Err(AppError::Forbidden("Owner cannot leave. Transfer ownership first.".to_string()));
     }
 
-    api.group_repo.remove_member(group_id, current_user.id).await?;
+    api.remove_group_member(current_user.id, group_id, current_user.id).await?;
 
     Ok(Json(ApiResponse::success(())))
 }
@@ -287,7 +336,7 @@ pub async fn remove_member(
         return Err(AppError::Forbidden("Cannot remove owner".to_string()));
     }
 
-    api.group_repo.remove_member(group_id, user_id).await?;
+    api.remove_group_member(current_user.id, group_id, user_id).await?;
 
     Ok(Json(ApiResponse::success(())))
 }
@@ -320,7 +369,7 @@ pub async fn update_member_role(
         _ => return Err(AppError::Validation("Invalid role".to_string())),
     };
 
-    api.group_repo.update_member_role(group_id, user_id, role).await?;
+    api.change_member_role(current_user.id, group_id, user_id, role).await?;
 
     Ok(Json(ApiResponse::success(())))
 }
@@ -352,8 +401,8 @@ pub async fn transfer_group(
     }
 
     api.group_repo.transfer_ownership(group_id, new_owner_id).await?;
-    api.group_repo.update_member_role(group_id, new_owner_id, GroupRole::Owner).await?;
-    api.group_repo.update_member_role(group_id, current_user.id, GroupRole::Admin).await?;
+    api.change_member_role(current_user.id, group_id, new_owner_id, GroupRole::Owner).await?;
+    api.change_member_role(current_user.id, group_id, current_user.id, GroupRole::Admin).await?;
 
     Ok(Json(ApiResponse::success(())))
 }
@@ -385,6 +434,68 @@ pub async fn reset_invite_code(
     })))
 }
 
+fn policy_to_response(policy: &GroupPolicy) -> GroupPolicyResponse {
+    let names = |caps: Vec<GroupCapability>| caps.iter().map(|c| c.to_string()).collect();
+    GroupPolicyResponse {
+        owner: names(policy.capabilities(GroupRole::Owner)),
+        admin: names(policy.capabilities(GroupRole::Admin)),
+        member: names(policy.capabilities(GroupRole::Member)),
+        updated_at: policy.updated_at.to_rfc3339(),
+    }
+}
+
+fn parse_capabilities(values: &[String]) -> AppResult<Vec<GroupCapability>> {
+    values
+        .iter()
+        .map(|v| v.parse().map_err(AppError::Validation))
+        .collect()
+}
+
+pub async fn get_group_policy(
+    State(api): State<Arc<GroupApi>>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<String>,
+) -> AppResult<Json<ApiResponse<GroupPolicyResponse>>> {
+    let group_id = Uuid::parse_str(&id)
+        .map_err(|_| AppError::Validation("Invalid group ID".to_string()))?;
+
+    if api.group_repo.get_member(group_id, current_user.id).await?.is_none() {
+        return Err(AppError::Forbidden("Not a member of this group".to_string()));
+    }
+
+    let policy = api.permission_service.policy(group_id).await?;
+
+    Ok(Json(ApiResponse::success(policy_to_response(&policy))))
+}
+
+pub async fn update_group_policy(
+    State(api): State<Arc<GroupApi>>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<String>,
+    Json(req): Json<UpdateGroupPolicyRequest>,
+) -> AppResult<Json<ApiResponse<GroupPolicyResponse>>> {
+    let group_id = Uuid::parse_str(&id)
+        .map_err(|_| AppError::Validation("Invalid group ID".to_string()))?;
+
+    let group = api
+        .group_repo
+        .find_by_id(group_id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Group not found".to_string()))?;
+
+    // Only owner can change permissions
+    if group.owner_id != current_user.id {
+        return Err(AppError::Forbidden("Only owner can change permissions".to_string()));
+    }
+
+    let policy = api
+        .permission_service
+        .save_policy(group_id, parse_capabilities(&req.admin)?, parse_capabilities(&req.member)?)
+        .await?;
+
+    Ok(Json(ApiResponse::success(policy_to_response(&policy))))
+}
+
 fn generate_invite_code() -> String {
     use rand::Rng;
     const CHARSET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
@@ -5,21 +5,24 @@ use axum::{
 use std::sync::Arc;
 use uuid::Uuid;
 
-use crate::dto::{
-    ApiResponse, CreateLedgerRequest, LedgerListResponse, LedgerResponse,
-};
+use crate::dto::{ApiResponse, CreateLedgerRequest, LedgerListResponse, LedgerResponse};
 use crate::error::{AppError, AppResult};
 use crate::middleware::CurrentUser;
-use crate::models::{CreateLedger, Ledger, LedgerType};
+use crate::models::{AuditEntityType, CreateLedger, Ledger, LedgerType};
 use crate::repositories::LedgerRepository;
+use crate::services::AuditService;
 
 pub struct LedgerApi {
     ledger_repo: Arc<dyn LedgerRepository>,
+    audit_service: Arc<AuditService>,
 }
 
 impl LedgerApi {
-    pub fn new(ledger_repo: Arc<dyn LedgerRepository>) -> Self {
-        Self { ledger_repo }
+    pub fn new(ledger_repo: Arc<dyn LedgerRepository>, audit_service: Arc<AuditService>) -> Self {
+        Self {
+            ledger_repo,
+            audit_service,
+        }
     }
 }
 
@@ -72,6 +75,10 @@ pub async fn create_ledger(
         })
         .await?;
 
+    api.audit_service
+        .record_create(current_user.id, AuditEntityType::Ledger, ledger.id, ledger.group_id, &ledger)
+        .await;
+
     Ok(Json(ApiResponse::success(ledger_to_response(&ledger))))
 }
 
@@ -120,6 +127,10 @@ pub async fn update_ledger(
         .update(ledger_id, req.name, req.description)
         .await?;
 
+    api.audit_service
+        .record_update(current_user.id, AuditEntityType::Ledger, ledger.id, ledger.group_id, &existing, &ledger)
+        .await;
+
     Ok(Json(ApiResponse::success(ledger_to_response(&ledger))))
 }
 
@@ -143,6 +154,10 @@ pub async fn delete_ledger(
 
     api.ledger_repo.delete(ledger_id).await?;
 
+    api.audit_service
+        .record_delete(current_user.id, AuditEntityType::Ledger, ledger_id, existing.group_id, &existing)
+        .await;
+
     Ok(Json(ApiResponse::success(())))
 }
 
@@ -1,15 +1,29 @@
+pub mod attachment;
+pub mod audit;
 pub mod auth;
 pub mod bill;
 pub mod category;
 pub mod group;
 pub mod ledger;
+pub mod net_worth;
+pub mod notification;
 pub mod routes;
+pub mod rule;
+pub mod trash;
 pub mod user;
+pub mod view;
 
+pub use attachment::AttachmentApi;
+pub use audit::AuditApi;
 pub use auth::AuthApi;
 pub use bill::BillApi;
 pub use category::CategoryApi;
 pub use group::GroupApi;
 pub use ledger::LedgerApi;
+pub use net_worth::NetWorthApi;
+pub use notification::NotificationApi;
 pub use routes::create_routes;
+pub use rule::RuleApi;
+pub use trash::TrashApi;
 pub use user::UserApi;
+pub use view::ViewApi;
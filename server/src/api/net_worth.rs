@@ -0,0 +1,255 @@
+use axum::{
+    extract::{Path, Query, State},
+    Extension, Json,
+};
+use chrono::Months;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::dto::{
+    ApiResponse, AssetListResponse, AssetResponse, CreateAssetRequest, NetWorthHistoryResponse,
+    NetWorthItemResponse, NetWorthMonthResponse, NetWorthResponse, NetWorthSnapshotResponse,
+    NetWorthTotalResponse, UpdateAssetRequest,
+};
+use crate::error::{AppError, AppResult};
+use crate::middleware::CurrentUser;
+use crate::models::{Asset, AssetKind, CreateAsset, NetWorthSnapshot, UpdateAsset};
+use crate::repositories::{AssetRepository, NetWorthRepository, UserRepository};
+use crate::services::NetWorthService;
+use crate::utils::{round_cents, Calendar};
+
+pub struct NetWorthApi {
+    asset_repo: Arc<dyn AssetRepository>,
+    net_worth_repo: Arc<dyn NetWorthRepository>,
+    user_repo: Arc<dyn UserRepository>,
+    net_worth_service: Arc<NetWorthService>,
+}
+
+impl NetWorthApi {
+    pub fn new(
+        asset_repo: Arc<dyn AssetRepository>,
+        net_worth_repo: Arc<dyn NetWorthRepository>,
+        user_repo: Arc<dyn UserRepository>,
+        net_worth_service: Arc<NetWorthService>,
+    ) -> Self {
+        Self {
+            asset_repo,
+            net_worth_repo,
+            user_repo,
+            net_worth_service,
+        }
+    }
+
+    async fn calendar(&self, user_id: Uuid) -> AppResult<Calendar> {
+        Ok(self.user_repo.find_by_id(user_id).await?.map(|u| u.calendar()).unwrap_or_default())
+    }
+
+    async fn find_owned_asset(&self, id: &str, user_id: Uuid) -> AppResult<Asset> {
+        let asset_id = Uuid::parse_str(id)
+            .map_err(|_| AppError::Validation("Invalid asset ID".to_string()))?;
+
+        self.asset_repo
+            .find_by_id(asset_id)
+            .await?
+            .filter(|a| a.user_id == user_id)
+            .ok_or_else(|| AppError::NotFound("Asset not found".to_string()))
+    }
+}
+
+fn parse_kind(kind: &str) -> AppResult<AssetKind> {
+    kind.parse()
+        .map_err(|_| AppError::Validation("Invalid asset kind".to_string()))
+}
+
+fn asset_response(asset: Asset) -> AssetResponse {
+    AssetResponse {
+        id: asset.id.to_string(),
+        name: asset.name,
+        kind: asset.kind.to_string(),
+        currency: asset.currency,
+        value: asset.value,
+        created_at: asset.created_at.to_rfc3339(),
+        updated_at: asset.updated_at.to_rfc3339(),
+    }
+}
+
+fn total_response(currency: &str, assets: f64, liabilities: f64) -> NetWorthTotalResponse {
+    NetWorthTotalResponse {
+        currency: currency.to_string(),
+        assets,
+        liabilities,
+        net_worth: round_cents(assets - liabilities),
+    }
+}
+
+fn snapshot_total(snapshot: &NetWorthSnapshot) -> NetWorthTotalResponse {
+    total_response(&snapshot.currency, snapshot.assets, snapshot.liabilities)
+}
+
+/// The current user's assets and liabilities, by name
+pub async fn list_assets(
+    State(api): State<Arc<NetWorthApi>>,
+    Extension(current_user): Extension<CurrentUser>,
+) -> AppResult<Json<ApiResponse<AssetListResponse>>> {
+    let assets = api.asset_repo.find_by_user_id(current_user.id).await?;
+
+    Ok(Json(ApiResponse::success(AssetListResponse {
+        items: assets.into_iter().map(asset_response).collect(),
+    })))
+}
+
+pub async fn create_asset(
+    State(api): State<Arc<NetWorthApi>>,
+    Extension(current_user): Extension<CurrentUser>,
+    Json(req): Json<CreateAssetRequest>,
+) -> AppResult<Json<ApiResponse<AssetResponse>>> {
+    req.validate()
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+
+    let asset = api
+        .asset_repo
+        .create(CreateAsset {
+            user_id: current_user.id,
+            name: req.name,
+            kind: parse_kind(&req.kind)?,
+            currency: req.currency,
+            value: req.value,
+        })
+        .await?;
+
+    Ok(Json(ApiResponse::success(asset_response(asset))))
+}
+
+/// Updating the value is how an asset gets revalued
+pub async fn update_asset(
+    State(api): State<Arc<NetWorthApi>>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<String>,
+    Json(req): Json<UpdateAssetRequest>,
+) -> AppResult<Json<ApiResponse<AssetResponse>>> {
+    req.validate()
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+
+    let existing = api.find_owned_asset(&id, current_user.id).await?;
+
+    let asset = api
+        .asset_repo
+        .update(
+            existing.id,
+            UpdateAsset {
+                name: req.name,
+                kind: req.kind.as_deref().map(parse_kind).transpose()?,
+                currency: req.currency,
+                value: req.value,
+            },
+        )
+        .await?;
+
+    Ok(Json(ApiResponse::success(asset_response(asset))))
+}
+
+pub async fn delete_asset(
+    State(api): State<Arc<NetWorthApi>>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<String>,
+) -> AppResult<Json<ApiResponse<()>>> {
+    let asset = api.find_owned_asset(&id, current_user.id).await?;
+    api.asset_repo.delete(asset.id).await?;
+
+    Ok(Json(ApiResponse::success(())))
+}
+
+/// The current user's net worth as of today, with what it is made of
+pub async fn get_net_worth(
+    State(api): State<Arc<NetWorthApi>>,
+    Extension(current_user): Extension<CurrentUser>,
+) -> AppResult<Json<ApiResponse<NetWorthResponse>>> {
+    let today = api.calendar(current_user.id).await?.today();
+    let net_worth = api.net_worth_service.net_worth(current_user.id, today).await?;
+
+    Ok(Json(ApiResponse::success(NetWorthResponse {
+        date: net_worth.date.to_string(),
+        items: net_worth
+            .items
+            .into_iter()
+            .map(|item| NetWorthItemResponse {
+                source: item.source.to_string(),
+                id: item.id.to_string(),
+                name: item.name,
+                currency: item.currency,
+                value: item.value,
+            })
+            .collect(),
+        totals: net_worth
+            .totals
+            .into_iter()
+            .map(|t| total_response(&t.currency, t.assets, t.liabilities))
+            .collect(),
+    })))
+}
+
+/// Records today's net worth now rather than waiting for the month to end.
+/// Recording again the same day replaces the earlier snapshot.
+pub async fn create_net_worth_snapshot(
+    State(api): State<Arc<NetWorthApi>>,
+    Extension(current_user): Extension<CurrentUser>,
+) -> AppResult<Json<ApiResponse<NetWorthSnapshotResponse>>> {
+    let today = api.calendar(current_user.id).await?.today();
+    let snapshots = api.net_worth_service.record(current_user.id, today).await?;
+
+    Ok(Json(ApiResponse::success(NetWorthSnapshotResponse {
+        snapshot_date: today.to_string(),
+        totals: snapshots.iter().map(snapshot_total).collect(),
+    })))
+}
+
+/// Net worth month by month from the snapshots, each month showing its latest one
+pub async fn get_net_worth_history(
+    State(api): State<Arc<NetWorthApi>>,
+    Extension(current_user): Extension<CurrentUser>,
+    Query(params): Query<NetWorthHistoryParams>,
+) -> AppResult<Json<ApiResponse<NetWorthHistoryResponse>>> {
+    let months = params.months.unwrap_or(12);
+    if !(1..=120).contains(&months) {
+        return Err(AppError::Validation("Months must be between 1 and 120".to_string()));
+    }
+
+    let calendar = api.calendar(current_user.id).await?;
+    let today = calendar.today();
+    let current_start = calendar.period("month", today).map_or(today, |(start, _)| start);
+    let start_date = current_start
+        .checked_sub_months(Months::new(months - 1))
+        .ok_or_else(|| AppError::Validation("Invalid date range".to_string()))?;
+
+    // Snapshots come oldest first, so the last day seen in a month is its latest
+    let mut latest: BTreeMap<String, Vec<NetWorthSnapshot>> = BTreeMap::new();
+    for snapshot in api.net_worth_repo.find_by_user_id(current_user.id, start_date).await? {
+        let day = latest.entry(calendar.bucket("month", snapshot.snapshot_date)).or_default();
+        if day.first().is_some_and(|s| s.snapshot_date != snapshot.snapshot_date) {
+            day.clear();
+        }
+        day.push(snapshot);
+    }
+
+    let items = (0..months)
+        .filter_map(|i| start_date.checked_add_months(Months::new(i)))
+        .map(|month_start| {
+            let month = calendar.bucket("month", month_start);
+            let snapshots = latest.remove(&month).unwrap_or_default();
+            NetWorthMonthResponse {
+                month,
+                snapshot_date: snapshots.first().map(|s| s.snapshot_date.to_string()),
+                totals: snapshots.iter().map(snapshot_total).collect(),
+            }
+        })
+        .collect();
+
+    Ok(Json(ApiResponse::success(NetWorthHistoryResponse { months: items })))
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct NetWorthHistoryParams {
+    pub months: Option<u32>,  // Months back including the current one, default 12, max 120
+}
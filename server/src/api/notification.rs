@@ -0,0 +1,97 @@
+use axum::{
+    extract::{Path, Query, State},
+    Extension, Json,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::dto::{ApiResponse, NotificationListResponse, NotificationResponse, PaginationResponse};
+use crate::error::{AppError, AppResult};
+use crate::middleware::CurrentUser;
+use crate::models::Notification;
+use crate::repositories::NotificationRepository;
+
+pub struct NotificationApi {
+    notification_repo: Arc<dyn NotificationRepository>,
+}
+
+impl NotificationApi {
+    pub fn new(notification_repo: Arc<dyn NotificationRepository>) -> Self {
+        Self { notification_repo }
+    }
+}
+
+fn notification_response(notification: Notification) -> NotificationResponse {
+    NotificationResponse {
+        id: notification.id.to_string(),
+        kind: notification.kind.to_string(),
+        ledger_id: notification.ledger_id.map(|id| id.to_string()),
+        bill_id: notification.bill_id.map(|id| id.to_string()),
+        data: notification.data,
+        read: notification.read_at.is_some(),
+        created_at: notification.created_at.to_rfc3339(),
+    }
+}
+
+/// The current user's notifications, newest first
+pub async fn list_notifications(
+    State(api): State<Arc<NotificationApi>>,
+    Extension(current_user): Extension<CurrentUser>,
+    Query(params): Query<ListNotificationsParams>,
+) -> AppResult<Json<ApiResponse<NotificationListResponse>>> {
+    let page = params.page.unwrap_or(1).max(1);
+    let page_size = params.page_size.unwrap_or(20).clamp(1, 100);
+
+    let (notifications, total) = api
+        .notification_repo
+        .find_by_user_id(current_user.id, params.unread.unwrap_or(false), page, page_size)
+        .await?;
+    let unread = api.notification_repo.count_unread(current_user.id).await?;
+    let total_pages = ((total as f64) / (page_size as f64)).ceil() as u32;
+
+    Ok(Json(ApiResponse::success(NotificationListResponse {
+        items: notifications.into_iter().map(notification_response).collect(),
+        unread,
+        pagination: PaginationResponse {
+            page,
+            page_size,
+            total,
+            total_pages,
+        },
+    })))
+}
+
+pub async fn read_notification(
+    State(api): State<Arc<NotificationApi>>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<String>,
+) -> AppResult<Json<ApiResponse<()>>> {
+    let notification_id = Uuid::parse_str(&id)
+        .map_err(|_| AppError::Validation("Invalid notification ID".to_string()))?;
+
+    api.notification_repo
+        .find_by_id(notification_id)
+        .await?
+        .filter(|n| n.user_id == current_user.id)
+        .ok_or_else(|| AppError::NotFound("Notification not found".to_string()))?;
+
+    api.notification_repo.mark_read(notification_id).await?;
+
+    Ok(Json(ApiResponse::success(())))
+}
+
+pub async fn read_all_notifications(
+    State(api): State<Arc<NotificationApi>>,
+    Extension(current_user): Extension<CurrentUser>,
+) -> AppResult<Json<ApiResponse<()>>> {
+    api.notification_repo.mark_all_read(current_user.id).await?;
+
+    Ok(Json(ApiResponse::success(())))
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct ListNotificationsParams {
+    pub unread: Option<bool>,  // Only the unread ones
+    pub page: Option<u32>,
+    pub page_size: Option<u32>,
+}
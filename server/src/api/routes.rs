@@ -1,20 +1,50 @@
 use axum::{
+    extract::DefaultBodyLimit,
     middleware,
     routing::{delete, get, post, put},
     Router,
 };
 use std::sync::Arc;
 
+use crate::api::attachment::{
+    delete_attachment, download_attachment, get_attachment_thumbnail, list_attachments,
+    upload_attachment, AttachmentApi,
+};
+use crate::api::audit::{get_bill_history, get_group_activity, AuditApi};
 use crate::api::auth::{login, refresh, register, AuthApi};
-use crate::api::bill::{create_bill, delete_bill, get_bill, get_category_statistics, get_statistics, get_trend_statistics, list_bills, update_bill, BillApi};
-use crate::api::category::{create_category, delete_category, list_categories, update_category, CategoryApi};
+use crate::api::bill::{
+    batch_bills, create_bill, delete_bill, get_bill, get_category_statistics,
+    get_category_statistics_tree, get_comparison_statistics, get_consolidated_statistics,
+    get_forecast, get_member_statistics, get_pattern_statistics, get_statistics,
+    get_trend_statistics, list_anomalies, list_bills, list_duplicates, list_view_bills,
+    quick_create_bill, suggest_category, update_bill, BillApi,
+};
+use crate::api::category::{
+    archive_category, create_category, delete_category, list_categories, merge_category,
+    move_category, unarchive_category, update_category, CategoryApi,
+};
 use crate::api::group::{
-    create_group, delete_group, get_group, join_group, leave_group, list_groups,
-    remove_member, reset_invite_code, transfer_group, update_group, update_member_role, GroupApi,
+    create_group, delete_group, get_group, get_group_policy, join_group, leave_group, list_groups,
+    remove_member, reset_invite_code, transfer_group, update_group, update_group_policy,
+    update_member_role, GroupApi,
+};
+use crate::api::ledger::{
+    create_ledger, delete_ledger, get_ledger, list_ledgers, update_ledger, LedgerApi,
 };
-use crate::api::ledger::{create_ledger, delete_ledger, get_ledger, list_ledgers, update_ledger, LedgerApi};
+use crate::api::net_worth::{
+    create_asset, create_net_worth_snapshot, delete_asset, get_net_worth, get_net_worth_history,
+    list_assets, update_asset, NetWorthApi,
+};
+use crate::api::notification::{
+    list_notifications, read_all_notifications, read_notification, NotificationApi,
+};
+use crate::api::rule::{create_rule, delete_rule, list_rules, test_rule, update_rule, RuleApi};
+use crate::api::trash::{list_trash, restore_trash_item, TrashApi};
 use crate::api::user::{change_password, get_me, update_me, UserApi};
-use crate::middleware::{auth_middleware, AuthState};
+use crate::api::view::{create_view, delete_view, get_view, list_views, update_view, ViewApi};
+use crate::middleware::{
+    auth_middleware, locale_middleware, optional_auth_middleware, AuthState, LocaleState,
+};
 
 pub struct AppState {
     pub auth_api: Arc<AuthApi>,
@@ -23,7 +53,15 @@ pub struct AppState {
     pub bill_api: Arc<BillApi>,
     pub category_api: Arc<CategoryApi>,
     pub group_api: Arc<GroupApi>,
+    pub attachment_api: Arc<AttachmentApi>,
+    pub trash_api: Arc<TrashApi>,
+    pub audit_api: Arc<AuditApi>,
+    pub rule_api: Arc<RuleApi>,
+    pub view_api: Arc<ViewApi>,
+    pub notification_api: Arc<NotificationApi>,
+    pub net_worth_api: Arc<NetWorthApi>,
     pub auth_state: AuthState,
+    pub locale_state: LocaleState,
 }
 
 pub fn create_routes(state: AppState) -> Router {
@@ -34,9 +72,14 @@ pub fn create_routes(state: AppState) -> Router {
         .route("/refresh", post(refresh))
         .with_state(state.auth_api.clone());
 
+    // Defaults are public; listing a ledger's own categories needs a token
     let public_category_routes = Router::new()
         .route("/", get(list_categories))
-        .with_state(state.category_api.clone());
+        .with_state(state.category_api.clone())
+        .layer(middleware::from_fn_with_state(
+            state.auth_state.clone(),
+            optional_auth_middleware,
+        ));
 
     // Protected routes (auth required)
     let user_routes = Router::new()
@@ -64,9 +107,20 @@ pub fn create_routes(state: AppState) -> Router {
     let bill_routes = Router::new()
         .route("/", get(list_bills))
         .route("/", post(create_bill))
+        .route("/quick", post(quick_create_bill))
+        .route("/batch", post(batch_bills))
         .route("/statistics", get(get_statistics))
         .route("/statistics/category", get(get_category_statistics))
+        .route("/statistics/category/tree", get(get_category_statistics_tree))
         .route("/statistics/trend", get(get_trend_statistics))
+        .route("/statistics/compare", get(get_comparison_statistics))
+        .route("/statistics/members", get(get_member_statistics))
+        .route("/statistics/consolidated", get(get_consolidated_statistics))
+        .route("/statistics/patterns", get(get_pattern_statistics))
+        .route("/forecast", get(get_forecast))
+        .route("/anomalies", get(list_anomalies))
+        .route("/suggest-category", get(suggest_category))
+        .route("/duplicates", get(list_duplicates))
         .route("/:id", get(get_bill))
         .route("/:id", put(update_bill))
         .route("/:id", delete(delete_bill))
@@ -76,10 +130,29 @@ pub fn create_routes(state: AppState) -> Router {
             auth_middleware,
         ));
 
+    // Leave some headroom over the file limit for the multipart framing
+    let attachment_body_limit = state.attachment_api.max_file_size() as usize + 64 * 1024;
+    let attachment_routes = Router::new()
+        .route("/:id/attachments", get(list_attachments))
+        .route("/:id/attachments", post(upload_attachment))
+        .route("/:id/attachments/:attachment_id", get(download_attachment))
+        .route("/:id/attachments/:attachment_id", delete(delete_attachment))
+        .route("/:id/attachments/:attachment_id/thumbnail", get(get_attachment_thumbnail))
+        .with_state(state.attachment_api.clone())
+        .layer(DefaultBodyLimit::max(attachment_body_limit))
+        .layer(middleware::from_fn_with_state(
+            state.auth_state.clone(),
+            auth_middleware,
+        ));
+
     let protected_category_routes = Router::new()
         .route("/", post(create_category))
         .route("/:id", put(update_category))
         .route("/:id", delete(delete_category))
+        .route("/:id/merge", post(merge_category))
+        .route("/:id/move", post(move_category))
+        .route("/:id/archive", post(archive_category))
+        .route("/:id/unarchive", post(unarchive_category))
         .with_state(state.category_api.clone())
         .layer(middleware::from_fn_with_state(
             state.auth_state.clone(),
@@ -96,6 +169,8 @@ pub fn create_routes(state: AppState) -> Router {
         .route("/:id/leave", post(leave_group))
         .route("/:id/transfer", post(transfer_group))
         .route("/:id/invite-code", post(reset_invite_code))
+        .route("/:id/policy", get(get_group_policy))
+        .route("/:id/policy", put(update_group_policy))
         .route("/:group_id/members/:user_id", delete(remove_member))
         .route("/:group_id/members/:user_id/role", put(update_member_role))
         .with_state(state.group_api.clone())
@@ -104,11 +179,110 @@ pub fn create_routes(state: AppState) -> Router {
             auth_middleware,
         ));
 
+    let bill_audit_routes = Router::new()
+        .route("/:id/history", get(get_bill_history))
+        .with_state(state.audit_api.clone())
+        .layer(middleware::from_fn_with_state(
+            state.auth_state.clone(),
+            auth_middleware,
+        ));
+
+    let group_audit_routes = Router::new()
+        .route("/:id/activity", get(get_group_activity))
+        .with_state(state.audit_api.clone())
+        .layer(middleware::from_fn_with_state(
+            state.auth_state.clone(),
+            auth_middleware,
+        ));
+
+    let trash_routes = Router::new()
+        .route("/", get(list_trash))
+        .route("/:item_type/:id/restore", post(restore_trash_item))
+        .with_state(state.trash_api.clone())
+        .layer(middleware::from_fn_with_state(
+            state.auth_state.clone(),
+            auth_middleware,
+        ));
+
+    let rule_routes = Router::new()
+        .route("/", get(list_rules))
+        .route("/", post(create_rule))
+        .route("/test", post(test_rule))
+        .route("/:id", put(update_rule))
+        .route("/:id", delete(delete_rule))
+        .with_state(state.rule_api.clone())
+        .layer(middleware::from_fn_with_state(
+            state.auth_state.clone(),
+            auth_middleware,
+        ));
+
+    let view_routes = Router::new()
+        .route("/", get(list_views))
+        .route("/", post(create_view))
+        .route("/:id", get(get_view))
+        .route("/:id", put(update_view))
+        .route("/:id", delete(delete_view))
+        .with_state(state.view_api.clone())
+        .layer(middleware::from_fn_with_state(
+            state.auth_state.clone(),
+            auth_middleware,
+        ));
+
+    // Running a view is a bill listing
+    let view_bill_routes = Router::new()
+        .route("/:id/bills", get(list_view_bills))
+        .with_state(state.bill_api.clone())
+        .layer(middleware::from_fn_with_state(
+            state.auth_state.clone(),
+            auth_middleware,
+        ));
+
+    let notification_routes = Router::new()
+        .route("/", get(list_notifications))
+        .route("/read-all", post(read_all_notifications))
+        .route("/:id/read", post(read_notification))
+        .with_state(state.notification_api.clone())
+        .layer(middleware::from_fn_with_state(
+            state.auth_state.clone(),
+            auth_middleware,
+        ));
+
+    let asset_routes = Router::new()
+        .route("/", get(list_assets))
+        .route("/", post(create_asset))
+        .route("/:id", put(update_asset))
+        .route("/:id", delete(delete_asset))
+        .with_state(state.net_worth_api.clone())
+        .layer(middleware::from_fn_with_state(
+            state.auth_state.clone(),
+            auth_middleware,
+        ));
+
+    let net_worth_routes = Router::new()
+        .route("/", get(get_net_worth))
+        .route("/history", get(get_net_worth_history))
+        .route("/snapshots", post(create_net_worth_snapshot))
+        .with_state(state.net_worth_api.clone())
+        .layer(middleware::from_fn_with_state(
+            state.auth_state.clone(),
+            auth_middleware,
+        ));
+
     Router::new()
         .nest("/api/v1/auth", auth_routes)
         .nest("/api/v1/users", user_routes)
         .nest("/api/v1/ledgers", ledger_routes)
-        .nest("/api/v1/bills", bill_routes)
+        .nest("/api/v1/bills", bill_routes.merge(attachment_routes).merge(bill_audit_routes))
         .nest("/api/v1/categories", public_category_routes.merge(protected_category_routes))
-        .nest("/api/v1/groups", group_routes)
+        .nest("/api/v1/groups", group_routes.merge(group_audit_routes))
+        .nest("/api/v1/trash", trash_routes)
+        .nest("/api/v1/rules", rule_routes)
+        .nest("/api/v1/views", view_routes.merge(view_bill_routes))
+        .nest("/api/v1/notifications", notification_routes)
+        .nest("/api/v1/assets", asset_routes)
+        .nest("/api/v1/net-worth", net_worth_routes)
+        .layer(middleware::from_fn_with_state(
+            state.locale_state.clone(),
+            locale_middleware,
+        ))
 }
@@ -0,0 +1,271 @@
+use axum::{
+    extract::{Path, Query, State},
+    Extension, Json,
+};
+use chrono::NaiveDate;
+use std::sync::Arc;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::dto::{
+    ApiResponse, CreateRuleRequest, RuleActionsRequest, RuleActionsResponse, RuleListResponse,
+    RuleResponse, RuleTestMatchResponse, RuleTestResponse, TestRuleRequest, UpdateRuleRequest,
+};
+use crate::error::{AppError, AppResult};
+use crate::middleware::CurrentUser;
+use crate::models::{
+    evaluate, CategoryRule, CreateCategoryRule, GroupCapability, RuleActions, RuleInput,
+    UpdateCategoryRule,
+};
+use crate::repositories::{BillFilter, BillRepository, RuleRepository};
+use crate::services::{PermissionService, RuleService};
+
+const DEFAULT_TEST_LIMIT: u32 = 50;
+const MAX_TEST_LIMIT: u32 = 200;
+
+pub struct RuleApi {
+    rule_repo: Arc<dyn RuleRepository>,
+    bill_repo: Arc<dyn BillRepository>,
+    rule_service: Arc<RuleService>,
+    permission_service: Arc<PermissionService>,
+}
+
+impl RuleApi {
+    pub fn new(
+        rule_repo: Arc<dyn RuleRepository>,
+        bill_repo: Arc<dyn BillRepository>,
+        rule_service: Arc<RuleService>,
+        permission_service: Arc<PermissionService>,
+    ) -> Self {
+        Self {
+            rule_repo,
+            bill_repo,
+            rule_service,
+            permission_service,
+        }
+    }
+
+    /// Rules belong to the ledger's category setup, so editing them needs `manage_categories`
+    async fn find_managed_rule(&self, rule_id: Uuid, user_id: Uuid) -> AppResult<CategoryRule> {
+        let rule = self
+            .rule_repo
+            .find_by_id(rule_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Rule not found".to_string()))?;
+
+        let access = self.permission_service.ledger_access(rule.ledger_id, user_id).await?;
+        access.require(GroupCapability::ManageCategories)?;
+        Ok(rule)
+    }
+}
+
+fn parse_actions(req: RuleActionsRequest) -> AppResult<RuleActions> {
+    let category_id = match req.category_id {
+        Some(ref id) => Some(Uuid::parse_str(id).map_err(|_| AppError::Validation("Invalid category ID".to_string()))?),
+        None => None,
+    };
+    Ok(RuleActions {
+        category_id,
+        note: req.note,
+    })
+}
+
+fn rule_to_response(rule: CategoryRule) -> RuleResponse {
+    RuleResponse {
+        id: rule.id.to_string(),
+        ledger_id: rule.ledger_id.to_string(),
+        name: rule.name,
+        priority: rule.priority,
+        enabled: rule.enabled,
+        conditions: rule.conditions,
+        actions: RuleActionsResponse {
+            category_id: rule.actions.category_id.map(|id| id.to_string()),
+            note: rule.actions.note,
+        },
+        created_at: rule.created_at.to_rfc3339(),
+        updated_at: rule.updated_at.to_rfc3339(),
+    }
+}
+
+pub async fn list_rules(
+    State(api): State<Arc<RuleApi>>,
+    Extension(current_user): Extension<CurrentUser>,
+    Query(params): Query<ListRulesParams>,
+) -> AppResult<Json<ApiResponse<RuleListResponse>>> {
+    let ledger_id = Uuid::parse_str(&params.ledger_id)
+        .map_err(|_| AppError::Validation("Invalid ledger ID".to_string()))?;
+
+    api.permission_service.ledger_access(ledger_id, current_user.id).await?;
+
+    let rules = api.rule_repo.find_by_ledger_id(ledger_id).await?;
+
+    Ok(Json(ApiResponse::success(RuleListResponse {
+        items: rules.into_iter().map(rule_to_response).collect(),
+    })))
+}
+
+pub async fn create_rule(
+    State(api): State<Arc<RuleApi>>,
+    Extension(current_user): Extension<CurrentUser>,
+    Json(req): Json<CreateRuleRequest>,
+) -> AppResult<Json<ApiResponse<RuleResponse>>> {
+    req.validate()
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+
+    let ledger_id = Uuid::parse_str(&req.ledger_id)
+        .map_err(|_| AppError::Validation("Invalid ledger ID".to_string()))?;
+
+    let access = api.permission_service.ledger_access(ledger_id, current_user.id).await?;
+    access.require(GroupCapability::ManageCategories)?;
+
+    let actions = parse_actions(req.actions)?;
+    api.rule_service.validate(ledger_id, &req.conditions, &actions).await?;
+
+    let rule = api
+        .rule_repo
+        .create(CreateCategoryRule {
+            ledger_id,
+            name: req.name,
+            priority: req.priority.unwrap_or(0),
+            enabled: req.enabled.unwrap_or(true),
+            conditions: req.conditions,
+            actions,
+            created_by: current_user.id,
+        })
+        .await?;
+
+    Ok(Json(ApiResponse::success(rule_to_response(rule))))
+}
+
+pub async fn update_rule(
+    State(api): State<Arc<RuleApi>>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<String>,
+    Json(req): Json<UpdateRuleRequest>,
+) -> AppResult<Json<ApiResponse<RuleResponse>>> {
+    req.validate()
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+
+    let rule_id = Uuid::parse_str(&id)
+        .map_err(|_| AppError::Validation("Invalid rule ID".to_string()))?;
+
+    let existing = api.find_managed_rule(rule_id, current_user.id).await?;
+
+    let actions = match req.actions {
+        Some(actions) => Some(parse_actions(actions)?),
+        None => None,
+    };
+
+    // Validate the rule as it will look after the update
+    api.rule_service
+        .validate(
+            existing.ledger_id,
+            req.conditions.as_ref().unwrap_or(&existing.conditions),
+            actions.as_ref().unwrap_or(&existing.actions),
+        )
+        .await?;
+
+    let rule = api
+        .rule_repo
+        .update(
+            rule_id,
+            UpdateCategoryRule {
+                name: req.name,
+                priority: req.priority,
+                enabled: req.enabled,
+                conditions: req.conditions,
+                actions,
+            },
+        )
+        .await?;
+
+    Ok(Json(ApiResponse::success(rule_to_response(rule))))
+}
+
+pub async fn delete_rule(
+    State(api): State<Arc<RuleApi>>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<String>,
+) -> AppResult<Json<ApiResponse<()>>> {
+    let rule_id = Uuid::parse_str(&id)
+        .map_err(|_| AppError::Validation("Invalid rule ID".to_string()))?;
+
+    api.find_managed_rule(rule_id, current_user.id).await?;
+    api.rule_repo.delete(rule_id).await?;
+
+    Ok(Json(ApiResponse::success(())))
+}
+
+/// Dry run: reports which existing bills a rule would change without touching them
+pub async fn test_rule(
+    State(api): State<Arc<RuleApi>>,
+    Extension(current_user): Extension<CurrentUser>,
+    Json(req): Json<TestRuleRequest>,
+) -> AppResult<Json<ApiResponse<RuleTestResponse>>> {
+    let ledger_id = Uuid::parse_str(&req.ledger_id)
+        .map_err(|_| AppError::Validation("Invalid ledger ID".to_string()))?;
+
+    api.permission_service.ledger_access(ledger_id, current_user.id).await?;
+
+    let actions = parse_actions(req.actions)?;
+    api.rule_service.validate(ledger_id, &req.conditions, &actions).await?;
+
+    let start_date = match req.start_date {
+        Some(ref d) => Some(NaiveDate::parse_from_str(d, "%Y-%m-%d").map_err(|_| AppError::Validation("Invalid start date".to_string()))?),
+        None => None,
+    };
+    let end_date = match req.end_date {
+        Some(ref d) => Some(NaiveDate::parse_from_str(d, "%Y-%m-%d").map_err(|_| AppError::Validation("Invalid end date".to_string()))?),
+        None => None,
+    };
+    let limit = req.limit.unwrap_or(DEFAULT_TEST_LIMIT).clamp(1, MAX_TEST_LIMIT) as usize;
+
+    let bills = api
+        .bill_repo
+        .find_all_by_filter(BillFilter {
+            ledger_id,
+            start_date,
+            end_date,
+            ..Default::default()
+        })
+        .await?;
+
+    let note_regex = req.conditions.compile_note_regex();
+    let mut matched = 0;
+    let mut items = Vec::new();
+    for bill in &bills {
+        let input = RuleInput {
+            note: bill.note.as_deref(),
+            amount: bill.amount,
+            bill_type: bill.bill_type,
+            bill_date: bill.bill_date,
+        };
+        let Some(outcome) = evaluate(Uuid::nil(), &req.conditions, &actions, note_regex.as_ref(), &input) else {
+            continue;
+        };
+
+        matched += 1;
+        if items.len() < limit {
+            items.push(RuleTestMatchResponse {
+                bill_id: bill.id.to_string(),
+                bill_date: bill.bill_date.to_string(),
+                amount: bill.amount,
+                note: bill.note.clone(),
+                category_id: bill.category_id.to_string(),
+                new_category_id: outcome.category_id.map(|id| id.to_string()),
+                new_note: outcome.note,
+            });
+        }
+    }
+
+    Ok(Json(ApiResponse::success(RuleTestResponse {
+        scanned: bills.len() as u64,
+        matched,
+        items,
+    })))
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct ListRulesParams {
+    pub ledger_id: String,
+}
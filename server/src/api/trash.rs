@@ -0,0 +1,210 @@
+use axum::{
+    extract::{Path, Query, State},
+    Extension, Json,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::dto::{ApiResponse, TrashItemResponse, TrashListResponse};
+use crate::error::{AppError, AppResult};
+use crate::middleware::CurrentUser;
+use crate::models::{AuditEntityType, Bill, Category, GroupCapability, Ledger};
+use crate::repositories::{BillRepository, CategoryRepository, LedgerRepository};
+use crate::services::{AuditService, PermissionService, TrashService};
+
+pub struct TrashApi {
+    bill_repo: Arc<dyn BillRepository>,
+    ledger_repo: Arc<dyn LedgerRepository>,
+    category_repo: Arc<dyn CategoryRepository>,
+    trash_service: Arc<TrashService>,
+    audit_service: Arc<AuditService>,
+    permission_service: Arc<PermissionService>,
+}
+
+impl TrashApi {
+    pub fn new(
+        bill_repo: Arc<dyn BillRepository>,
+        ledger_repo: Arc<dyn LedgerRepository>,
+        category_repo: Arc<dyn CategoryRepository>,
+        trash_service: Arc<TrashService>,
+        audit_service: Arc<AuditService>,
+        permission_service: Arc<PermissionService>,
+    ) -> Self {
+        Self {
+            bill_repo,
+            ledger_repo,
+            category_repo,
+            trash_service,
+            audit_service,
+            permission_service,
+        }
+    }
+
+    fn bill_to_item(&self, bill: &Bill) -> TrashItemResponse {
+        TrashItemResponse {
+            id: bill.id.to_string(),
+            item_type: "bill".to_string(),
+            name: bill.note.clone().unwrap_or_default(),
+            ledger_id: Some(bill.ledger_id.to_string()),
+            amount: Some(bill.amount),
+            bill_date: Some(bill.bill_date.to_string()),
+            deleted_at: bill.deleted_at.map(|d| d.to_rfc3339()),
+            purge_at: bill.deleted_at.map(|d| self.trash_service.purge_at(d).to_rfc3339()),
+        }
+    }
+
+    fn ledger_to_item(&self, ledger: &Ledger) -> TrashItemResponse {
+        TrashItemResponse {
+            id: ledger.id.to_string(),
+            item_type: "ledger".to_string(),
+            name: ledger.name.clone(),
+            ledger_id: None,
+            amount: None,
+            bill_date: None,
+            deleted_at: ledger.deleted_at.map(|d| d.to_rfc3339()),
+            purge_at: ledger.deleted_at.map(|d| self.trash_service.purge_at(d).to_rfc3339()),
+        }
+    }
+
+    fn category_to_item(&self, category: &Category) -> TrashItemResponse {
+        TrashItemResponse {
+            id: category.id.to_string(),
+            item_type: "category".to_string(),
+            name: category.name.clone(),
+            ledger_id: category.ledger_id.map(|id| id.to_string()),
+            amount: None,
+            bill_date: None,
+            deleted_at: category.deleted_at.map(|d| d.to_rfc3339()),
+            purge_at: category.deleted_at.map(|d| self.trash_service.purge_at(d).to_rfc3339()),
+        }
+    }
+}
+
+pub async fn list_trash(
+    State(api): State<Arc<TrashApi>>,
+    Extension(current_user): Extension<CurrentUser>,
+    Query(params): Query<ListTrashParams>,
+) -> AppResult<Json<ApiResponse<TrashListResponse>>> {
+    let item_type = params.item_type.as_deref();
+    if !matches!(item_type, None | Some("bill") | Some("ledger") | Some("category")) {
+        return Err(AppError::Validation("Invalid item type".to_string()));
+    }
+
+    let mut items = Vec::new();
+
+    if item_type.is_none() || item_type == Some("bill") {
+        let bills = api.bill_repo.find_trashed_by_user_id(current_user.id).await?;
+        items.extend(bills.iter().map(|b| api.bill_to_item(b)));
+    }
+
+    if item_type.is_none() || item_type == Some("ledger") {
+        let ledgers = api.ledger_repo.find_trashed_by_user_id(current_user.id).await?;
+        items.extend(ledgers.iter().map(|l| api.ledger_to_item(l)));
+    }
+
+    if item_type.is_none() || item_type == Some("category") {
+        // Categories of every ledger the user may manage categories in, personal or group
+        let ledger_ids: Vec<Uuid> = api
+            .permission_service
+            .accessible_ledgers(current_user.id)
+            .await?
+            .iter()
+            .filter(|access| access.can(GroupCapability::ManageCategories))
+            .map(|access| access.ledger.id)
+            .collect();
+        let categories = api.category_repo.find_trashed(&ledger_ids).await?;
+        items.extend(categories.iter().map(|c| api.category_to_item(c)));
+    }
+
+    // Most recently deleted first
+    items.sort_by(|a, b| b.deleted_at.cmp(&a.deleted_at));
+
+    Ok(Json(ApiResponse::success(TrashListResponse { items })))
+}
+
+pub async fn restore_trash_item(
+    State(api): State<Arc<TrashApi>>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path((item_type, id)): Path<(String, String)>,
+) -> AppResult<Json<ApiResponse<TrashItemResponse>>> {
+    let id = Uuid::parse_str(&id).map_err(|_| AppError::Validation("Invalid ID".to_string()))?;
+
+    let item = match item_type.as_str() {
+        "bill" => {
+            let bill = api
+                .bill_repo
+                .find_trashed_by_id(id)
+                .await?
+                .ok_or_else(|| AppError::NotFound("Bill not found in trash".to_string()))?;
+
+            // Only creator can restore; their trash is the only place it is listed
+            if bill.user_id != current_user.id {
+                return Err(AppError::Forbidden("Access denied".to_string()));
+            }
+
+            let ledger = api
+                .ledger_repo
+                .find_by_id(bill.ledger_id)
+                .await?
+                .ok_or_else(|| AppError::Validation("Restore the ledger first".to_string()))?;
+
+            let bill = api.bill_repo.restore(id).await?;
+            api.audit_service
+                .record_restore(current_user.id, AuditEntityType::Bill, id, ledger.group_id)
+                .await;
+            api.bill_to_item(&bill)
+        }
+        "ledger" => {
+            let ledger = api
+                .ledger_repo
+                .find_trashed_by_id(id)
+                .await?
+                .ok_or_else(|| AppError::NotFound("Ledger not found in trash".to_string()))?;
+
+            if ledger.user_id != Some(current_user.id) {
+                return Err(AppError::Forbidden("Access denied".to_string()));
+            }
+
+            let ledger = api.ledger_repo.restore(id).await?;
+            api.audit_service
+                .record_restore(current_user.id, AuditEntityType::Ledger, id, ledger.group_id)
+                .await;
+            api.ledger_to_item(&ledger)
+        }
+        "category" => {
+            let category = api
+                .category_repo
+                .find_by_id(id)
+                .await?
+                .filter(|c| c.deleted_at.is_some())
+                .ok_or_else(|| AppError::NotFound("Category not found in trash".to_string()))?;
+
+            // System categories are shared by everyone, no single user may bring one back
+            let ledger_id = category
+                .ledger_id
+                .ok_or_else(|| AppError::Forbidden("Access denied".to_string()))?;
+            let ledger = api
+                .ledger_repo
+                .find_by_id(ledger_id)
+                .await?
+                .ok_or_else(|| AppError::Validation("Restore the ledger first".to_string()))?;
+            let access = api.permission_service.access_for(ledger, current_user.id).await?;
+            access.require(GroupCapability::ManageCategories)?;
+
+            let category = api.category_repo.restore(id).await?;
+            api.audit_service
+                .record_restore(current_user.id, AuditEntityType::Category, id, access.ledger.group_id)
+                .await;
+            api.category_to_item(&category)
+        }
+        _ => return Err(AppError::Validation("Invalid item type".to_string())),
+    };
+
+    Ok(Json(ApiResponse::success(item)))
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct ListTrashParams {
+    #[serde(rename = "type")]
+    pub item_type: Option<String>,
+}
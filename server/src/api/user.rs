@@ -1,15 +1,12 @@
-use axum::{
-    extract::State,
-    Extension, Json,
-};
+use axum::{extract::State, Extension, Json};
+use chrono::FixedOffset;
 use std::sync::Arc;
 
-use crate::dto::{
-    ApiResponse, ChangePasswordRequest, UpdateUserRequest, UserResponse,
-};
+use crate::dto::{ApiResponse, ChangePasswordRequest, UpdateUserRequest, UserResponse};
 use crate::error::{AppError, AppResult};
+use crate::i18n::Locale;
 use crate::middleware::CurrentUser;
-use crate::models::UpdateUser;
+use crate::models::{UpdateUser, User};
 use crate::repositories::UserRepository;
 use crate::utils::{hash_password, verify_password};
 
@@ -33,13 +30,7 @@ pub async fn get_me(
         .await?
         .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
 
-    Ok(Json(ApiResponse::success(UserResponse {
-        id: user.id.to_string(),
-        email: user.email,
-        nickname: user.nickname,
-        avatar: user.avatar,
-        created_at: user.created_at.to_rfc3339(),
-    })))
+    Ok(Json(ApiResponse::success(user_response(user))))
 }
 
 pub async fn update_me(
@@ -47,6 +38,26 @@ pub async fn update_me(
     Extension(current_user): Extension<CurrentUser>,
     Json(req): Json<UpdateUserRequest>,
 ) -> AppResult<Json<ApiResponse<UserResponse>>> {
+    let locale = match req.locale {
+        Some(ref locale) => Some(
+            locale
+                .parse::<Locale>()
+                .map_err(|_| AppError::Validation("Unsupported locale".to_string()))?,
+        ),
+        None => None,
+    };
+
+    let utc_offset = match req.timezone {
+        Some(ref timezone) => Some(parse_utc_offset(timezone)?),
+        None => None,
+    };
+    if req.week_start.is_some_and(|day| !(1..=7).contains(&day)) {
+        return Err(AppError::Validation("Week start must be between 1 and 7".to_string()));
+    }
+    if req.month_start_day.is_some_and(|day| !(1..=28).contains(&day)) {
+        return Err(AppError::Validation("Month start day must be between 1 and 28".to_string()));
+    }
+
     let user = api
         .user_repo
         .update(
@@ -54,17 +65,42 @@ pub async fn update_me(
             UpdateUser {
                 nickname: req.nickname,
                 avatar: req.avatar,
+                locale,
+                utc_offset,
+                week_start: req.week_start,
+                month_start_day: req.month_start_day,
             },
         )
         .await?;
 
-    Ok(Json(ApiResponse::success(UserResponse {
+    Ok(Json(ApiResponse::success(user_response(user))))
+}
+
+pub fn user_response(user: User) -> UserResponse {
+    let calendar = user.calendar();
+    UserResponse {
         id: user.id.to_string(),
         email: user.email,
         nickname: user.nickname,
         avatar: user.avatar,
+        locale: user.locale.map(|l| l.to_string()),
+        timezone: calendar.utc_offset.to_string(),
+        week_start: calendar.week_start.number_from_monday(),
+        month_start_day: calendar.month_start_day,
         created_at: user.created_at.to_rfc3339(),
-    })))
+    }
+}
+
+/// Minutes east of UTC from an offset such as "+08:00", "-0530" or "UTC"
+fn parse_utc_offset(timezone: &str) -> AppResult<i32> {
+    let timezone = timezone.trim();
+    if timezone.eq_ignore_ascii_case("utc") || timezone.eq_ignore_ascii_case("z") {
+        return Ok(0);
+    }
+    timezone
+        .parse::<FixedOffset>()
+        .map(|offset| offset.local_minus_utc() / 60)
+        .map_err(|_| AppError::Validation("Invalid timezone".to_string()))
 }
 
 pub async fn change_password(
@@ -0,0 +1,243 @@
+use axum::{
+    extract::{Path, Query, State},
+    Extension, Json,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::dto::{
+    ApiResponse, CreateViewRequest, SavedViewListResponse, SavedViewResponse, UpdateViewRequest,
+    UserBriefResponse,
+};
+use crate::error::{AppError, AppResult};
+use crate::middleware::CurrentUser;
+use crate::models::{CreateSavedView, LedgerType, SavedView, UpdateSavedView, ViewFilter};
+use crate::repositories::{CategoryRepository, SavedViewRepository, UserRepository};
+use crate::services::{LedgerAccess, PermissionService};
+use crate::utils::Calendar;
+
+pub struct ViewApi {
+    view_repo: Arc<dyn SavedViewRepository>,
+    category_repo: Arc<dyn CategoryRepository>,
+    user_repo: Arc<dyn UserRepository>,
+    permission_service: Arc<PermissionService>,
+}
+
+impl ViewApi {
+    pub fn new(
+        view_repo: Arc<dyn SavedViewRepository>,
+        category_repo: Arc<dyn CategoryRepository>,
+        user_repo: Arc<dyn UserRepository>,
+        permission_service: Arc<PermissionService>,
+    ) -> Self {
+        Self {
+            view_repo,
+            category_repo,
+            user_repo,
+            permission_service,
+        }
+    }
+
+    async fn find_visible_view(&self, view_id: Uuid, user_id: Uuid) -> AppResult<(SavedView, LedgerAccess)> {
+        find_visible_view(self.view_repo.as_ref(), &self.permission_service, view_id, user_id).await
+    }
+
+    async fn find_owned_view(&self, view_id: Uuid, user_id: Uuid) -> AppResult<(SavedView, LedgerAccess)> {
+        let (view, access) = self.find_visible_view(view_id, user_id).await?;
+        if view.user_id != user_id {
+            return Err(AppError::Forbidden("Only the owner can change a view".to_string()));
+        }
+        Ok((view, access))
+    }
+
+    /// Checks a view definition before it is saved
+    async fn validate(&self, access: &LedgerAccess, filter: &ViewFilter, shared: bool) -> AppResult<()> {
+        filter.validate().map_err(AppError::Validation)?;
+
+        // Archived categories still have bills worth looking at
+        for &category_id in &filter.category_ids {
+            self.category_repo
+                .find_by_id(category_id)
+                .await?
+                .filter(|c| c.deleted_at.is_none() && (c.is_system() || c.ledger_id == Some(access.ledger.id)))
+                .ok_or_else(|| AppError::Validation("Category not found in this ledger".to_string()))?;
+        }
+        if shared && access.ledger.ledger_type != LedgerType::Group {
+            return Err(AppError::Validation("Only views of group ledgers can be shared".to_string()));
+        }
+        Ok(())
+    }
+
+    /// The user's calendar preferences, relative periods resolve in the calendar of whoever looks at the view
+    async fn calendar(&self, user_id: Uuid) -> AppResult<Calendar> {
+        Ok(self.user_repo.find_by_id(user_id).await?.map(|u| u.calendar()).unwrap_or_default())
+    }
+
+    async fn view_response(&self, view: SavedView, calendar: &Calendar) -> AppResult<SavedViewResponse> {
+        let owner = self
+            .user_repo
+            .find_by_id(view.user_id)
+            .await?
+            .ok_or_else(|| AppError::Internal(format!("Owner of view {} not found", view.id)))?;
+        let (start_date, end_date) = view.filter.date_range(calendar);
+
+        Ok(SavedViewResponse {
+            id: view.id.to_string(),
+            ledger_id: view.ledger_id.to_string(),
+            name: view.name,
+            filter: view.filter,
+            shared: view.shared,
+            owner: UserBriefResponse {
+                id: owner.id.to_string(),
+                nickname: owner.nickname,
+            },
+            start_date: start_date.map(|d| d.to_string()),
+            end_date: end_date.map(|d| d.to_string()),
+            created_at: view.created_at.to_rfc3339(),
+            updated_at: view.updated_at.to_rfc3339(),
+        })
+    }
+}
+
+/// A view the user owns or that was shared in a ledger they can still view.
+/// Also used when running a view, so seeing and running one follow the same rules.
+pub async fn find_visible_view(
+    view_repo: &dyn SavedViewRepository,
+    permission_service: &PermissionService,
+    view_id: Uuid,
+    user_id: Uuid,
+) -> AppResult<(SavedView, LedgerAccess)> {
+    let view = view_repo
+        .find_by_id(view_id)
+        .await?
+        .filter(|v| v.is_visible_to(user_id))
+        .ok_or_else(|| AppError::NotFound("View not found".to_string()))?;
+
+    let access = permission_service.ledger_access(view.ledger_id, user_id).await?;
+    Ok((view, access))
+}
+
+/// The user's own views of a ledger plus those other members shared
+pub async fn list_views(
+    State(api): State<Arc<ViewApi>>,
+    Extension(current_user): Extension<CurrentUser>,
+    Query(params): Query<ListViewsParams>,
+) -> AppResult<Json<ApiResponse<SavedViewListResponse>>> {
+    let ledger_id = Uuid::parse_str(&params.ledger_id)
+        .map_err(|_| AppError::Validation("Invalid ledger ID".to_string()))?;
+
+    api.permission_service.ledger_access(ledger_id, current_user.id).await?;
+
+    let calendar = api.calendar(current_user.id).await?;
+    let mut items = Vec::new();
+    for view in api.view_repo.find_by_ledger_id(ledger_id).await? {
+        if view.is_visible_to(current_user.id) {
+            items.push(api.view_response(view, &calendar).await?);
+        }
+    }
+
+    Ok(Json(ApiResponse::success(SavedViewListResponse { items })))
+}
+
+pub async fn create_view(
+    State(api): State<Arc<ViewApi>>,
+    Extension(current_user): Extension<CurrentUser>,
+    Json(req): Json<CreateViewRequest>,
+) -> AppResult<Json<ApiResponse<SavedViewResponse>>> {
+    req.validate()
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+
+    let ledger_id = Uuid::parse_str(&req.ledger_id)
+        .map_err(|_| AppError::Validation("Invalid ledger ID".to_string()))?;
+
+    let access = api.permission_service.ledger_access(ledger_id, current_user.id).await?;
+
+    let shared = req.shared.unwrap_or(false);
+    api.validate(&access, &req.filter, shared).await?;
+
+    let view = api
+        .view_repo
+        .create(CreateSavedView {
+            user_id: current_user.id,
+            ledger_id,
+            name: req.name,
+            filter: req.filter,
+            shared,
+        })
+        .await?;
+
+    let calendar = api.calendar(current_user.id).await?;
+    Ok(Json(ApiResponse::success(api.view_response(view, &calendar).await?)))
+}
+
+pub async fn get_view(
+    State(api): State<Arc<ViewApi>>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<String>,
+) -> AppResult<Json<ApiResponse<SavedViewResponse>>> {
+    let view_id = Uuid::parse_str(&id)
+        .map_err(|_| AppError::Validation("Invalid view ID".to_string()))?;
+
+    let (view, _) = api.find_visible_view(view_id, current_user.id).await?;
+
+    let calendar = api.calendar(current_user.id).await?;
+    Ok(Json(ApiResponse::success(api.view_response(view, &calendar).await?)))
+}
+
+pub async fn update_view(
+    State(api): State<Arc<ViewApi>>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<String>,
+    Json(req): Json<UpdateViewRequest>,
+) -> AppResult<Json<ApiResponse<SavedViewResponse>>> {
+    req.validate()
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+
+    let view_id = Uuid::parse_str(&id)
+        .map_err(|_| AppError::Validation("Invalid view ID".to_string()))?;
+
+    let (existing, access) = api.find_owned_view(view_id, current_user.id).await?;
+
+    // Validate the view as it will look after the update
+    api.validate(
+        &access,
+        req.filter.as_ref().unwrap_or(&existing.filter),
+        req.shared.unwrap_or(existing.shared),
+    )
+    .await?;
+
+    let view = api
+        .view_repo
+        .update(
+            view_id,
+            UpdateSavedView {
+                name: req.name,
+                filter: req.filter,
+                shared: req.shared,
+            },
+        )
+        .await?;
+
+    let calendar = api.calendar(current_user.id).await?;
+    Ok(Json(ApiResponse::success(api.view_response(view, &calendar).await?)))
+}
+
+pub async fn delete_view(
+    State(api): State<Arc<ViewApi>>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<String>,
+) -> AppResult<Json<ApiResponse<()>>> {
+    let view_id = Uuid::parse_str(&id)
+        .map_err(|_| AppError::Validation("Invalid view ID".to_string()))?;
+
+    api.find_owned_view(view_id, current_user.id).await?;
+    api.view_repo.delete(view_id).await?;
+
+    Ok(Json(ApiResponse::success(())))
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct ListViewsParams {
+    pub ledger_id: String,
+}
@@ -7,14 +7,23 @@ use tower_http::trace::TraceLayer;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
 use money_notes_server::api::{
-    create_routes, routes::AppState, AuthApi, BillApi, CategoryApi, GroupApi, LedgerApi, UserApi,
+    create_routes, routes::AppState, AttachmentApi, AuditApi, AuthApi, BillApi, CategoryApi,
+    GroupApi, LedgerApi, NetWorthApi, NotificationApi, RuleApi, TrashApi, UserApi, ViewApi,
 };
-use money_notes_server::middleware::AuthState;
+use money_notes_server::config::Settings;
+use money_notes_server::middleware::{AuthState, LocaleState};
 use money_notes_server::repositories::memory::{
-    MemoryBillRepository, MemoryCategoryRepository, MemoryGroupRepository, MemoryLedgerRepository,
-    MemoryUserRepository,
+    MemoryAssetRepository, MemoryAttachmentRepository, MemoryAuditRepository, MemoryBillRepository,
+    MemoryCategoryRepository, MemoryGroupRepository, MemoryLedgerRepository,
+    MemoryNetWorthRepository, MemoryNotificationRepository, MemoryRuleRepository,
+    MemorySavedViewRepository, MemoryUserRepository,
 };
-use money_notes_server::services::AuthService;
+use money_notes_server::services::{
+    AnomalyService, AttachmentService, AuditService, AuthService, DuplicateService,
+    ForecastService, NetWorthService, PermissionService, QuickEntryService, RuleService,
+    SuggestionService, TrashService,
+};
+use money_notes_server::storage::{BlobStore, LocalBlobStore};
 use money_notes_server::utils::JwtUtil;
 
 #[tokio::main]
@@ -41,6 +50,24 @@ async fn main() {
         Arc::new(MemoryCategoryRepository::new());
     let group_repo: Arc<dyn money_notes_server::repositories::GroupRepository> =
         Arc::new(MemoryGroupRepository::new());
+    let attachment_repo: Arc<dyn money_notes_server::repositories::AttachmentRepository> =
+        Arc::new(MemoryAttachmentRepository::new());
+    let audit_repo: Arc<dyn money_notes_server::repositories::AuditRepository> =
+        Arc::new(MemoryAuditRepository::new());
+    let rule_repo: Arc<dyn money_notes_server::repositories::RuleRepository> =
+        Arc::new(MemoryRuleRepository::new());
+    let view_repo: Arc<dyn money_notes_server::repositories::SavedViewRepository> =
+        Arc::new(MemorySavedViewRepository::new());
+    let notification_repo: Arc<dyn money_notes_server::repositories::NotificationRepository> =
+        Arc::new(MemoryNotificationRepository::new());
+    let asset_repo: Arc<dyn money_notes_server::repositories::AssetRepository> =
+        Arc::new(MemoryAssetRepository::new());
+    let net_worth_repo: Arc<dyn money_notes_server::repositories::NetWorthRepository> =
+        Arc::new(MemoryNetWorthRepository::new());
+
+    // Attachments are written to the local filesystem
+    let storage_settings = Settings::default().storage;
+    let blob_store: Arc<dyn BlobStore> = Arc::new(LocalBlobStore::new(&storage_settings.local_path));
 
     // Initialize default categories
     if let Err(e) = category_repo.init_default_categories().await {
@@ -56,20 +83,129 @@ async fn main() {
 
     // Initialize services
     let auth_service = Arc::new(AuthService::new(user_repo.clone(), jwt_util.clone()));
+    let audit_service = Arc::new(AuditService::new(audit_repo.clone()));
+    let permission_service = Arc::new(PermissionService::new(ledger_repo.clone(), group_repo.clone()));
+    let rule_service = Arc::new(RuleService::new(rule_repo.clone(), category_repo.clone()));
+    let suggestion_service = Arc::new(SuggestionService::new(bill_repo.clone(), category_repo.clone()));
+    let quick_entry_service = Arc::new(QuickEntryService::new(
+        category_repo.clone(),
+        rule_service.clone(),
+        suggestion_service.clone(),
+    ));
+    let attachment_service = Arc::new(AttachmentService::new(
+        attachment_repo.clone(),
+        blob_store,
+        &storage_settings,
+    ));
+
+    let trash_settings = Settings::default().trash;
+    let trash_service = Arc::new(TrashService::new(
+        bill_repo.clone(),
+        ledger_repo.clone(),
+        category_repo.clone(),
+        attachment_service.clone(),
+        trash_settings.retention_days,
+    ));
+    trash_service.clone().spawn_purge_job(trash_settings.purge_interval);
+
+    let net_worth_service = Arc::new(NetWorthService::new(
+        user_repo.clone(),
+        ledger_repo.clone(),
+        bill_repo.clone(),
+        asset_repo.clone(),
+        net_worth_repo.clone(),
+    ));
+    net_worth_service.clone().spawn_snapshot_job(Settings::default().net_worth.snapshot_interval);
 
     // Initialize API handlers
+    let duplicate_settings = Settings::default().duplicates;
+    let duplicate_service = Arc::new(DuplicateService::new(
+        bill_repo.clone(),
+        duplicate_settings.window_days,
+        duplicate_settings.note_similarity,
+    ));
+    let forecast_service = Arc::new(ForecastService::new(bill_repo.clone()));
+    let anomaly_service = Arc::new(AnomalyService::new(
+        bill_repo.clone(),
+        group_repo.clone(),
+        notification_repo.clone(),
+    ));
+
     let auth_api = Arc::new(AuthApi::new(auth_service, 3600));
     let user_api = Arc::new(UserApi::new(user_repo.clone()));
-    let ledger_api = Arc::new(LedgerApi::new(ledger_repo.clone()));
+    let ledger_api = Arc::new(LedgerApi::new(ledger_repo.clone(), audit_service.clone()));
     let bill_api = Arc::new(BillApi::new(
+        bill_repo.clone(),
+        category_repo.clone(),
+        user_repo.clone(),
+        audit_service.clone(),
+        permission_service.clone(),
+        rule_service.clone(),
+        suggestion_service,
+        quick_entry_service,
+        duplicate_service,
+        forecast_service,
+        anomaly_service,
+        view_repo.clone(),
+    ));
+    let category_api = Arc::new(CategoryApi::new(
+        category_repo.clone(),
+        bill_repo.clone(),
+        audit_service.clone(),
+        permission_service.clone(),
+    ));
+    let group_api = Arc::new(GroupApi::new(
+        group_repo.clone(),
+        user_repo.clone(),
+        ledger_repo.clone(),
+        audit_service.clone(),
+        permission_service.clone(),
+    ));
+    let attachment_api = Arc::new(AttachmentApi::new(
+        attachment_service.clone(),
+        bill_repo.clone(),
+        permission_service.clone(),
+    ));
+
+    let trash_api = Arc::new(TrashApi::new(
         bill_repo.clone(),
         ledger_repo.clone(),
         category_repo.clone(),
+        trash_service,
+        audit_service.clone(),
+        permission_service.clone(),
+    ));
+    let audit_api = Arc::new(AuditApi::new(
+        audit_service,
+        bill_repo.clone(),
+        ledger_repo.clone(),
+        group_repo.clone(),
         user_repo.clone(),
     ));
-    let category_api = Arc::new(CategoryApi::new(category_repo.clone()));
-    let group_api = Arc::new(GroupApi::new(group_repo.clone(), user_repo.clone(), ledger_repo.clone()));
+    let rule_api = Arc::new(RuleApi::new(
+        rule_repo.clone(),
+        bill_repo.clone(),
+        rule_service,
+        permission_service.clone(),
+    ));
+    let view_api = Arc::new(ViewApi::new(
+        view_repo,
+        category_repo.clone(),
+        user_repo.clone(),
+        permission_service.clone(),
+    ));
+    let notification_api = Arc::new(NotificationApi::new(notification_repo));
+    let net_worth_api = Arc::new(NetWorthApi::new(
+        asset_repo,
+        net_worth_repo,
+        user_repo.clone(),
+        net_worth_service,
+    ));
 
+    let locale_state = LocaleState {
+        jwt_util: jwt_util.clone(),
+        user_repo: user_repo.clone(),
+    };
     let auth_state = AuthState { jwt_util };
 
     // Build router
@@ -80,7 +216,15 @@ async fn main() {
         bill_api,
         category_api,
         group_api,
+        attachment_api,
+        trash_api,
+        audit_api,
+        rule_api,
+        view_api,
+        notification_api,
+        net_worth_api,
         auth_state,
+        locale_state,
     };
 
     let cors = CorsLayer::new()
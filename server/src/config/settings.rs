@@ -5,6 +5,10 @@ pub struct Settings {
     pub server: ServerConfig,
     pub database: DatabaseConfig,
     pub jwt: JwtConfig,
+    pub storage: StorageConfig,
+    pub trash: TrashConfig,
+    pub duplicates: DuplicateConfig,
+    pub net_worth: NetWorthConfig,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -27,6 +31,42 @@ pub struct JwtConfig {
     pub refresh_token_expires: i64,  // seconds
 }
 
+#[derive(Debug, Deserialize, Clone)]
+pub struct StorageConfig {
+    pub driver: String,  // "local" or "s3"
+    pub local_path: String,
+    pub max_file_size: u64,  // bytes
+    pub allowed_types: Vec<String>,
+    pub thumbnail_size: u32,  // pixels, longest edge
+    pub s3: S3Config,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct S3Config {
+    pub endpoint: String,  // e.g. "http://localhost:9000" for MinIO
+    pub bucket: String,
+    pub region: String,
+    pub access_key: String,
+    pub secret_key: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct TrashConfig {
+    pub retention_days: i64,
+    pub purge_interval: u64,  // seconds
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct DuplicateConfig {
+    pub window_days: i64,       // Max days between a bill and its duplicate
+    pub note_similarity: f64,   // 0..1, share of note words the two bills must have in common
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct NetWorthConfig {
+    pub snapshot_interval: u64,  // seconds between checks for month-end snapshots
+}
+
 impl Settings {
     pub fn new() -> Result<Self, config::ConfigError> {
         let run_mode = std::env::var("RUN_MODE").unwrap_or_else(|_| "development".into());
@@ -58,6 +98,35 @@ impl Default for Settings {
                 access_token_expires: 3600,
                 refresh_token_expires: 604800,
             },
+            storage: StorageConfig {
+                driver: "local".to_string(),
+                local_path: "data/attachments".to_string(),
+                max_file_size: 10 * 1024 * 1024,
+                allowed_types: vec![
+                    "image/jpeg".to_string(),
+                    "image/png".to_string(),
+                    "application/pdf".to_string(),
+                ],
+                thumbnail_size: 256,
+                s3: S3Config {
+                    endpoint: "http://localhost:9000".to_string(),
+                    bucket: "money-notes".to_string(),
+                    region: "us-east-1".to_string(),
+                    access_key: "minioadmin".to_string(),
+                    secret_key: "minioadmin".to_string(),
+                },
+            },
+            trash: TrashConfig {
+                retention_days: 30,
+                purge_interval: 3600,
+            },
+            duplicates: DuplicateConfig {
+                window_days: 1,
+                note_similarity: 0.5,
+            },
+            net_worth: NetWorthConfig {
+                snapshot_interval: 3600,
+            },
         }
     }
 }
@@ -1,6 +1,17 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer};
 use validator::Validate;
 
+use crate::models::{RuleConditions, ViewFilter};
+
+/// Tells a field left out (None) from one set to null (Some(None)), for fields a request can clear
+fn nullable<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
+
 // Auth DTOs
 #[derive(Debug, Deserialize, Validate)]
 pub struct RegisterRequest {
@@ -31,6 +42,10 @@ pub struct UpdateUserRequest {
     pub nickname: Option<String>,
     #[validate(url(message = "Invalid avatar URL"))]
     pub avatar: Option<String>,
+    pub locale: Option<String>,  // "zh-CN", "en" or "ja"
+    pub timezone: Option<String>,  // UTC offset such as "+08:00"
+    pub week_start: Option<u32>,  // 1 = Monday ... 7 = Sunday
+    pub month_start_day: Option<u32>,
 }
 
 #[derive(Debug, Deserialize, Validate)]
@@ -53,23 +68,39 @@ pub struct CreateLedgerRequest {
 #[derive(Debug, Deserialize, Validate)]
 pub struct CreateBillRequest {
     pub ledger_id: String,
-    pub category_id: String,
+    pub category_id: Option<String>,  // Falls back to the ledger's auto-categorization rules
     #[validate(range(min = 0.01, message = "Amount must be positive"))]
     pub amount: f64,
     #[serde(rename = "type")]
     pub bill_type: String,  // "income" or "expense"
     pub note: Option<String>,
     pub bill_date: String,  // YYYY-MM-DD
+    pub bill_time: Option<String>,  // HH:MM, the user's local time
+    pub force: Option<bool>,  // Save even if it looks like a duplicate
+}
+
+/// Free text such as "昨天 午饭 32.5 微信", previewed unless `commit` is set
+#[derive(Debug, Deserialize, Validate)]
+pub struct QuickBillRequest {
+    pub ledger_id: String,
+    #[validate(length(min = 1, max = 200, message = "Text must be 1-200 characters"))]
+    pub text: String,
+    pub commit: Option<bool>,
+    pub force: Option<bool>,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct UpdateBillRequest {
+    pub ledger_id: Option<String>,  // Moves the bill to another ledger
     pub category_id: Option<String>,
     pub amount: Option<f64>,
     #[serde(rename = "type")]
     pub bill_type: Option<String>,
     pub note: Option<String>,
     pub bill_date: Option<String>,
+    #[serde(default, deserialize_with = "nullable")]
+    pub bill_time: Option<Option<String>>,  // null clears the time, leaving it out keeps it
+    pub exchange_rate: Option<f64>,  // Destination units per source unit, needed when the ledgers' currencies differ
 }
 
 #[derive(Debug, Deserialize)]
@@ -79,11 +110,66 @@ pub struct BillQueryParams {
     pub end_date: Option<String>,
     #[serde(rename = "type")]
     pub bill_type: Option<String>,
-    pub category_id: Option<String>,
+    pub category_id: Option<String>,  // Comma-separated, subcategories are included
+    pub user_id: Option<String>,  // Comma-separated creator IDs, `me` for the current user
+    pub has_note: Option<bool>,
+    pub sort: Option<String>,  // date_desc (default), date_asc, amount_desc, amount_asc, created_desc, created_asc
+    pub period: Option<String>,  // Relative range such as "last 3 months", instead of the dates
+    pub q: Option<String>,  // Keywords matched against notes and category names
+    pub min_amount: Option<f64>,
+    pub max_amount: Option<f64>,
     pub page: Option<u32>,
     pub page_size: Option<u32>,
 }
 
+/// One kind of change applied to many bills, either all of them or none
+#[derive(Debug, Deserialize)]
+#[serde(tag = "action", rename_all = "lowercase")]
+pub enum BatchBillRequest {
+    Create {
+        items: Vec<CreateBillRequest>,
+    },
+    Update {
+        ids: Option<Vec<String>>,
+        filter: Option<BatchBillFilter>,
+        changes: BatchBillChanges,
+    },
+    Delete {
+        ids: Option<Vec<String>>,
+        filter: Option<BatchBillFilter>,
+    },
+}
+
+/// Selects bills by the same criteria as `GET /bills`, instead of listing their IDs
+#[derive(Debug, Deserialize)]
+pub struct BatchBillFilter {
+    pub ledger_id: String,
+    pub start_date: Option<String>,
+    pub end_date: Option<String>,
+    #[serde(rename = "type")]
+    pub bill_type: Option<String>,
+    pub category_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BatchBillChanges {
+    pub ledger_id: Option<String>,
+    pub category_id: Option<String>,
+    #[serde(rename = "type")]
+    pub bill_type: Option<String>,
+    pub note: Option<String>,
+    pub bill_date: Option<String>,
+    pub exchange_rate: Option<f64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DuplicateQueryParams {
+    pub ledger_id: String,
+    pub start_date: Option<String>,
+    pub end_date: Option<String>,
+    pub window_days: Option<i64>,  // Defaults to `duplicates.window_days` from the config
+}
+
 // Group DTOs
 #[derive(Debug, Deserialize, Validate)]
 pub struct CreateGroupRequest {
@@ -102,6 +188,12 @@ pub struct UpdateMemberRoleRequest {
     pub role: String,  // "admin" or "member"
 }
 
+#[derive(Debug, Deserialize)]
+pub struct UpdateGroupPolicyRequest {
+    pub admin: Vec<String>,  // e.g. ["create", "edit_own", "edit_any"]
+    pub member: Vec<String>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct TransferGroupRequest {
     pub new_owner_id: String,
@@ -116,4 +208,101 @@ pub struct CreateCategoryRequest {
     #[serde(rename = "type")]
     pub category_type: String,  // "income" or "expense"
     pub parent_id: Option<String>,
+    pub ledger_id: String,  // Custom categories always belong to a ledger
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MergeCategoryRequest {
+    pub target_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MoveCategoryRequest {
+    pub parent_id: Option<String>,  // None moves the category to the top level
+}
+
+// Rule DTOs
+#[derive(Debug, Deserialize)]
+pub struct RuleActionsRequest {
+    pub category_id: Option<String>,
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct CreateRuleRequest {
+    pub ledger_id: String,
+    #[validate(length(min = 1, max = 50, message = "Name must be 1-50 characters"))]
+    pub name: String,
+    pub priority: Option<i32>,
+    pub enabled: Option<bool>,
+    pub conditions: RuleConditions,
+    pub actions: RuleActionsRequest,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct UpdateRuleRequest {
+    #[validate(length(min = 1, max = 50, message = "Name must be 1-50 characters"))]
+    pub name: Option<String>,
+    pub priority: Option<i32>,
+    pub enabled: Option<bool>,
+    pub conditions: Option<RuleConditions>,
+    pub actions: Option<RuleActionsRequest>,
+}
+
+/// An unsaved rule to run against the ledger's existing bills
+#[derive(Debug, Deserialize)]
+pub struct TestRuleRequest {
+    pub ledger_id: String,
+    pub conditions: RuleConditions,
+    pub actions: RuleActionsRequest,
+    pub start_date: Option<String>,
+    pub end_date: Option<String>,
+    pub limit: Option<u32>,  // Matches returned, default 50, max 200
+}
+
+// Saved view DTOs
+#[derive(Debug, Deserialize, Validate)]
+pub struct CreateViewRequest {
+    pub ledger_id: String,
+    #[validate(length(min = 1, max = 50, message = "Name must be 1-50 characters"))]
+    pub name: String,
+    pub filter: ViewFilter,
+    pub shared: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct UpdateViewRequest {
+    #[validate(length(min = 1, max = 50, message = "Name must be 1-50 characters"))]
+    pub name: Option<String>,
+    pub filter: Option<ViewFilter>,
+    pub shared: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ViewBillsParams {
+    pub page: Option<u32>,
+    pub page_size: Option<u32>,
+}
+
+// Asset DTOs
+#[derive(Debug, Deserialize, Validate)]
+pub struct CreateAssetRequest {
+    #[validate(length(min = 1, max = 100, message = "Name must be 1-100 characters"))]
+    pub name: String,
+    pub kind: String,  // "asset" or "liability"
+    #[validate(length(min = 1, max = 10, message = "Invalid currency"))]
+    pub currency: Option<String>,
+    #[validate(range(min = 0.0, message = "Value cannot be negative"))]
+    pub value: f64,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct UpdateAssetRequest {
+    #[validate(length(min = 1, max = 100, message = "Name must be 1-100 characters"))]
+    pub name: Option<String>,
+    pub kind: Option<String>,
+    #[validate(length(min = 1, max = 10, message = "Invalid currency"))]
+    pub currency: Option<String>,
+    #[validate(range(min = 0.0, message = "Value cannot be negative"))]
+    pub value: Option<f64>,
 }
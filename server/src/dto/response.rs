@@ -1,5 +1,7 @@
 use serde::Serialize;
 
+use crate::models::{RuleConditions, ViewFilter};
+
 // Generic response wrapper
 #[derive(Debug, Serialize)]
 pub struct ApiResponse<T: Serialize> {
@@ -49,6 +51,10 @@ pub struct UserResponse {
     pub email: String,
     pub nickname: Option<String>,
     pub avatar: Option<String>,
+    pub locale: Option<String>,
+    pub timezone: String,  // UTC offset such as "+08:00"
+    pub week_start: u32,
+    pub month_start_day: u32,
     pub created_at: String,
 }
 
@@ -79,10 +85,65 @@ pub struct BillResponse {
     pub category: CategoryBriefResponse,
     pub note: Option<String>,
     pub bill_date: String,
+    pub bill_time: Option<String>,  // HH:MM
     pub user: UserBriefResponse,
     pub created_at: String,
 }
 
+#[derive(Debug, Serialize)]
+pub struct QuickBillPreviewResponse {
+    pub amount: Option<f64>,
+    #[serde(rename = "type")]
+    pub bill_type: String,
+    pub bill_date: String,
+    pub category: Option<CategoryBriefResponse>,
+    pub category_source: Option<String>,  // "rule", "name", "keyword" or "history"
+    pub note: Option<String>,
+    pub account: Option<String>,  // Recognized payment method, kept in the note
+    pub confidence: f64,
+    pub missing: Vec<String>,  // Fields that must be filled before committing
+}
+
+#[derive(Debug, Serialize)]
+pub struct QuickBillResponse {
+    pub preview: QuickBillPreviewResponse,
+    pub bill: Option<BillResponse>,  // Set when the request asked to commit
+}
+
+#[derive(Debug, Serialize)]
+pub struct BatchItemErrorResponse {
+    pub code: i32,
+    pub message: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BatchItemResultResponse {
+    pub index: usize,  // Position in `items` or `ids`, or in the filter's matches
+    pub id: Option<String>,
+    pub status: String,  // "ok", "failed", or "skipped" when another item failed
+    pub bill: Option<BillResponse>,
+    pub error: Option<BatchItemErrorResponse>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BatchBillResponse {
+    pub applied: bool,
+    pub total: usize,
+    pub failed: usize,
+    pub results: Vec<BatchItemResultResponse>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DuplicateClusterResponse {
+    pub bills: Vec<BillResponse>,  // Newest first
+}
+
+#[derive(Debug, Serialize)]
+pub struct DuplicateClusterListResponse {
+    pub items: Vec<DuplicateClusterResponse>,
+    pub window_days: i64,
+}
+
 #[derive(Debug, Serialize)]
 pub struct BillListResponse {
     pub items: Vec<BillResponse>,
@@ -117,6 +178,22 @@ pub struct CategoryStatisticsResponse {
     pub percentage: f64,
 }
 
+/// A category with its descendants' bills rolled into `amount` and `count`
+#[derive(Debug, Serialize)]
+pub struct CategoryStatisticsNodeResponse {
+    pub category_id: String,
+    pub category_name: String,
+    pub category_icon: Option<String>,
+    #[serde(rename = "type")]
+    pub bill_type: String,
+    pub amount: f64,
+    pub count: u32,
+    pub own_amount: f64,  // Bills filed directly under this category
+    pub own_count: u32,
+    pub percentage: f64,
+    pub children: Vec<CategoryStatisticsNodeResponse>,
+}
+
 #[derive(Debug, Serialize)]
 pub struct DailyStatisticsResponse {
     pub date: String,
@@ -139,6 +216,281 @@ pub struct FullStatisticsResponse {
     pub trend: Vec<TrendStatisticsResponse>,
 }
 
+#[derive(Debug, Serialize)]
+pub struct MemberCategoryStatisticsResponse {
+    pub category_id: String,
+    pub category_name: String,
+    pub category_icon: Option<String>,
+    #[serde(rename = "type")]
+    pub bill_type: String,
+    pub amount: f64,
+    pub count: u32,
+    pub percentage: f64,  // Of the member's total for this type
+}
+
+#[derive(Debug, Serialize)]
+pub struct MemberStatisticsResponse {
+    pub user: UserBriefResponse,
+    pub total_income: f64,
+    pub total_expense: f64,
+    pub balance: f64,
+    pub count: u32,
+    pub income_share: f64,  // Percentage of the ledger's income
+    pub expense_share: f64,
+    pub categories: Vec<MemberCategoryStatisticsResponse>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MemberStatisticsListResponse {
+    pub total_income: f64,
+    pub total_expense: f64,
+    pub items: Vec<MemberStatisticsResponse>,  // Biggest spender first
+}
+
+/// One period of a comparison
+#[derive(Debug, Serialize)]
+pub struct PeriodStatisticsResponse {
+    pub start_date: String,
+    pub end_date: String,
+    pub summary: BillStatisticsResponse,
+    pub trend: Vec<TrendStatisticsResponse>,
+}
+
+/// Change from a base period, `percentage` is None when the base is zero
+#[derive(Debug, Serialize)]
+pub struct DeltaResponse {
+    pub change: f64,
+    pub percentage: Option<f64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SummaryDeltaResponse {
+    pub income: DeltaResponse,
+    pub expense: DeltaResponse,
+    pub balance: DeltaResponse,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CategoryComparisonResponse {
+    pub category_id: String,
+    pub category_name: String,
+    pub category_icon: Option<String>,
+    #[serde(rename = "type")]
+    pub bill_type: String,
+    pub current: f64,
+    pub previous: f64,
+    pub last_year: f64,
+    pub vs_previous: DeltaResponse,
+    pub vs_last_year: DeltaResponse,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ComparisonStatisticsResponse {
+    pub current: PeriodStatisticsResponse,
+    pub previous: PeriodStatisticsResponse,
+    pub last_year: PeriodStatisticsResponse,  // The same period a year earlier
+    pub vs_previous: SummaryDeltaResponse,
+    pub vs_last_year: SummaryDeltaResponse,
+    pub categories: Vec<CategoryComparisonResponse>,  // Largest current amount first
+}
+
+#[derive(Debug, Serialize)]
+pub struct ForecastDayResponse {
+    pub date: String,
+    pub income: f64,
+    pub expense: f64,
+    pub balance: f64,
+    pub lower: f64,  // 80% confidence band of the balance
+    pub upper: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RecurringItemResponse {
+    #[serde(rename = "type")]
+    pub bill_type: String,
+    pub category: Option<CategoryBriefResponse>,
+    pub amount: f64,
+    pub note: Option<String>,
+    pub day_of_month: u32,
+    pub occurrences: usize,
+    pub last_date: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ForecastCategoryResponse {
+    pub category_id: String,
+    pub category_name: String,
+    pub category_icon: Option<String>,
+    #[serde(rename = "type")]
+    pub bill_type: String,
+    pub daily_average: f64,
+    pub projected: f64,  // Over the whole forecast
+}
+
+#[derive(Debug, Serialize)]
+pub struct ForecastResponse {
+    pub start_date: String,
+    pub end_date: String,
+    pub opening_balance: f64,
+    pub closing_balance: f64,
+    pub lower: f64,
+    pub upper: f64,
+    pub history_start: String,
+    pub history_end: String,
+    pub days: Vec<ForecastDayResponse>,
+    pub recurring: Vec<RecurringItemResponse>,
+    pub categories: Vec<ForecastCategoryResponse>,  // Recurring bills not included
+}
+
+/// One ledger's share of consolidated statistics, amounts already in the reporting currency
+#[derive(Debug, Serialize)]
+pub struct ConsolidatedLedgerResponse {
+    pub id: String,
+    pub name: String,
+    pub currency: String,
+    pub exchange_rate: f64,  // Reporting currency units per unit of the ledger's currency
+    pub total_income: f64,
+    pub total_expense: f64,
+    pub balance: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ConsolidatedStatisticsResponse {
+    pub currency: String,
+    pub start_date: String,
+    pub end_date: String,
+    pub ledgers: Vec<ConsolidatedLedgerResponse>,
+    pub summary: BillStatisticsResponse,
+    pub trend: Vec<TrendStatisticsResponse>,
+}
+
+/// One weekday's row of the weekday x hour matrices, indexed by hour 0-23
+#[derive(Debug, Serialize)]
+pub struct WeekdayHourResponse {
+    pub weekday: u32,  // 1 (Monday) to 7 (Sunday)
+    pub amounts: Vec<f64>,
+    pub counts: Vec<u32>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct HeatmapDayResponse {
+    pub date: String,
+    pub amount: f64,
+    pub count: u32,
+    pub level: u8,  // 0 for no bills, else 1-4 by quartile of the days with bills
+}
+
+#[derive(Debug, Serialize)]
+pub struct HeatmapResponse {
+    pub thresholds: Vec<f64>,  // Upper bounds of levels 1-3
+    pub days: Vec<HeatmapDayResponse>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct WeekdayAverageResponse {
+    pub weekday: u32,
+    pub total: f64,
+    pub count: u32,
+    pub days: u32,  // How many of this weekday the range has
+    pub average: f64,  // Per day, days without bills included
+}
+
+#[derive(Debug, Serialize)]
+pub struct PatternStatisticsResponse {
+    pub start_date: String,
+    pub end_date: String,
+    #[serde(rename = "type")]
+    pub bill_type: String,
+    pub weekday_hour: Vec<WeekdayHourResponse>,  // From the user's first day of the week
+    pub untimed_amount: f64,  // Bills without a time, left out of `weekday_hour`
+    pub untimed_count: u32,
+    pub heatmap: HeatmapResponse,
+    pub weekdays: Vec<WeekdayAverageResponse>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AnomalyResponse {
+    pub kind: String,  // "large_amount", "category_spike" or "new_merchant"
+    pub bill: Option<BillResponse>,  // None for category spikes
+    pub category_id: String,
+    pub category_name: String,
+    pub category_icon: Option<String>,
+    pub date: String,
+    pub amount: f64,
+    pub expected: f64,  // The category's median bill, or its average over the preceding periods
+    pub score: f64,  // How many times the expected amount
+    pub merchant: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AnomalyListResponse {
+    pub start_date: String,
+    pub end_date: String,
+    pub items: Vec<AnomalyResponse>,
+}
+
+// Attachment responses
+#[derive(Debug, Serialize)]
+pub struct AttachmentResponse {
+    pub id: String,
+    pub bill_id: String,
+    pub file_name: String,
+    pub content_type: String,
+    pub size: u64,
+    pub has_thumbnail: bool,
+    pub uploaded_by: String,
+    pub created_at: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AttachmentListResponse {
+    pub items: Vec<AttachmentResponse>,
+}
+
+// Audit responses
+#[derive(Debug, Serialize)]
+pub struct AuditEventResponse {
+    pub id: String,
+    pub entity_type: String,
+    pub entity_id: String,
+    pub action: String,
+    pub actor: UserBriefResponse,
+    pub changes: serde_json::Value,
+    pub created_at: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AuditEventListResponse {
+    pub items: Vec<AuditEventResponse>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GroupActivityResponse {
+    pub items: Vec<AuditEventResponse>,
+    pub pagination: PaginationResponse,
+}
+
+// Trash responses
+#[derive(Debug, Serialize)]
+pub struct TrashItemResponse {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub item_type: String,  // "bill", "ledger" or "category"
+    pub name: String,
+    pub ledger_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bill_date: Option<String>,
+    pub deleted_at: Option<String>,  // None once restored
+    pub purge_at: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TrashListResponse {
+    pub items: Vec<TrashItemResponse>,
+}
+
 // Category responses
 #[derive(Debug, Serialize)]
 pub struct CategoryResponse {
@@ -147,9 +499,17 @@ pub struct CategoryResponse {
     pub icon: Option<String>,
     #[serde(rename = "type")]
     pub category_type: String,
+    pub ledger_id: Option<String>,  // None for system defaults
+    pub archived: bool,
     pub children: Vec<CategoryResponse>,
 }
 
+#[derive(Debug, Serialize)]
+pub struct CategoryMergeResponse {
+    pub target: CategoryResponse,
+    pub moved_bills: u64,
+}
+
 #[derive(Debug, Serialize)]
 pub struct CategoryBriefResponse {
     pub id: String,
@@ -157,6 +517,18 @@ pub struct CategoryBriefResponse {
     pub icon: Option<String>,
 }
 
+#[derive(Debug, Serialize)]
+pub struct CategorySuggestionResponse {
+    pub category: CategoryBriefResponse,
+    pub confidence: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CategorySuggestionListResponse {
+    pub items: Vec<CategorySuggestionResponse>,
+    pub trained_on: u64,  // Bills in the ledger the suggestions were learned from
+}
+
 #[derive(Debug, Serialize)]
 pub struct CategoryListResponse {
     pub items: Vec<CategoryResponse>,
@@ -193,6 +565,14 @@ pub struct GroupMemberResponse {
     pub joined_at: String,
 }
 
+#[derive(Debug, Serialize)]
+pub struct GroupPolicyResponse {
+    pub owner: Vec<String>,  // Always every capability
+    pub admin: Vec<String>,
+    pub member: Vec<String>,
+    pub updated_at: String,
+}
+
 #[derive(Debug, Serialize)]
 pub struct GroupListResponse {
     pub items: Vec<GroupResponse>,
@@ -205,6 +585,148 @@ pub struct CreateGroupResponse {
     pub invite_code: String,
 }
 
+// Rule responses
+#[derive(Debug, Serialize)]
+pub struct RuleActionsResponse {
+    pub category_id: Option<String>,
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RuleResponse {
+    pub id: String,
+    pub ledger_id: String,
+    pub name: String,
+    pub priority: i32,
+    pub enabled: bool,
+    pub conditions: RuleConditions,
+    pub actions: RuleActionsResponse,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RuleListResponse {
+    pub items: Vec<RuleResponse>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RuleTestMatchResponse {
+    pub bill_id: String,
+    pub bill_date: String,
+    pub amount: f64,
+    pub note: Option<String>,
+    pub category_id: String,
+    pub new_category_id: Option<String>,  // None when the rule leaves the category alone
+    pub new_note: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RuleTestResponse {
+    pub scanned: u64,
+    pub matched: u64,
+    pub items: Vec<RuleTestMatchResponse>,
+}
+
+// Saved view responses
+#[derive(Debug, Serialize)]
+pub struct SavedViewResponse {
+    pub id: String,
+    pub ledger_id: String,
+    pub name: String,
+    pub filter: ViewFilter,
+    pub shared: bool,
+    pub owner: UserBriefResponse,
+    pub start_date: Option<String>,  // The filter's dates as they resolve today
+    pub end_date: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SavedViewListResponse {
+    pub items: Vec<SavedViewResponse>,
+}
+
+// Notification responses
+#[derive(Debug, Serialize)]
+pub struct NotificationResponse {
+    pub id: String,
+    pub kind: String,
+    pub ledger_id: Option<String>,
+    pub bill_id: Option<String>,
+    pub data: serde_json::Value,  // Depends on `kind`
+    pub read: bool,
+    pub created_at: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct NotificationListResponse {
+    pub items: Vec<NotificationResponse>,
+    pub unread: u64,
+    pub pagination: PaginationResponse,
+}
+
+// Net worth responses
+#[derive(Debug, Serialize)]
+pub struct AssetResponse {
+    pub id: String,
+    pub name: String,
+    pub kind: String,
+    pub currency: String,
+    pub value: f64,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AssetListResponse {
+    pub items: Vec<AssetResponse>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct NetWorthItemResponse {
+    pub source: String,  // "ledger" or "asset"
+    pub id: String,
+    pub name: String,
+    pub currency: String,
+    pub value: f64,  // Negative for overdrawn ledgers and liabilities
+}
+
+/// Net worth in one currency, currencies are never added up
+#[derive(Debug, Serialize)]
+pub struct NetWorthTotalResponse {
+    pub currency: String,
+    pub assets: f64,
+    pub liabilities: f64,
+    pub net_worth: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct NetWorthResponse {
+    pub date: String,
+    pub items: Vec<NetWorthItemResponse>,
+    pub totals: Vec<NetWorthTotalResponse>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct NetWorthSnapshotResponse {
+    pub snapshot_date: String,
+    pub totals: Vec<NetWorthTotalResponse>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct NetWorthMonthResponse {
+    pub month: String,  // e.g. "2024-01", following the user's month start day
+    pub snapshot_date: Option<String>,  // The month's latest snapshot, None when it has none
+    pub totals: Vec<NetWorthTotalResponse>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct NetWorthHistoryResponse {
+    pub months: Vec<NetWorthMonthResponse>,  // Oldest first
+}
+
 // Brief responses (for embedding)
 #[derive(Debug, Serialize)]
 pub struct UserBriefResponse {
@@ -29,6 +29,9 @@ pub enum AppError {
     #[error("Database error: {0}")]
     Database(String),
 
+    #[error("Storage error: {0}")]
+    Storage(String),
+
     #[error("JWT error: {0}")]
     Jwt(String),
 }
@@ -43,31 +46,55 @@ impl AppError {
             AppError::Internal(_) => 10005,
             AppError::Conflict(_) => 20001,
             AppError::Database(_) => 10005,
+            AppError::Storage(_) => 10005,
             AppError::Jwt(_) => 20003,
         }
     }
+
+    /// The message shown to clients, without the variant prefix used by `Display`
+    pub fn message(&self) -> String {
+        match self {
+            AppError::Unauthorized => "Unauthorized".to_string(),
+            AppError::Validation(msg)
+            | AppError::Forbidden(msg)
+            | AppError::NotFound(msg)
+            | AppError::Conflict(msg)
+            | AppError::Internal(msg)
+            | AppError::Database(msg)
+            | AppError::Storage(msg)
+            | AppError::Jwt(msg) => msg.clone(),
+        }
+    }
 }
 
 impl IntoResponse for AppError {
     fn into_response(self) -> Response {
-        let (status, code, message) = match &self {
-            AppError::Validation(msg) => (StatusCode::BAD_REQUEST, self.code(), msg.clone()),
-            AppError::Unauthorized => (StatusCode::UNAUTHORIZED, self.code(), "Unauthorized".to_string()),
-            AppError::Forbidden(msg) => (StatusCode::FORBIDDEN, self.code(), msg.clone()),
-            AppError::NotFound(msg) => (StatusCode::NOT_FOUND, self.code(), msg.clone()),
-            AppError::Conflict(msg) => (StatusCode::CONFLICT, self.code(), msg.clone()),
-            AppError::Internal(msg) => (StatusCode::INTERNAL_SERVER_ERROR, self.code(), msg.clone()),
-            AppError::Database(msg) => (StatusCode::INTERNAL_SERVER_ERROR, self.code(), msg.clone()),
-            AppError::Jwt(msg) => (StatusCode::UNAUTHORIZED, self.code(), msg.clone()),
+        let status = match &self {
+            AppError::Validation(_) => StatusCode::BAD_REQUEST,
+            AppError::Unauthorized | AppError::Jwt(_) => StatusCode::UNAUTHORIZED,
+            AppError::Forbidden(_) => StatusCode::FORBIDDEN,
+            AppError::NotFound(_) => StatusCode::NOT_FOUND,
+            AppError::Conflict(_) => StatusCode::CONFLICT,
+            AppError::Internal(_) | AppError::Database(_) | AppError::Storage(_) => StatusCode::INTERNAL_SERVER_ERROR,
         };
+        let (code, message) = (self.code(), self.message());
 
         let body = Json(json!({
             "code": code,
             "message": message
         }));
 
-        (status, body).into_response()
+        let mut response = (status, body).into_response();
+        response.extensions_mut().insert(ErrorDetails { code, message });
+        response
     }
 }
 
+/// Attached to error responses so outer layers (e.g. localization) can rebuild the body
+#[derive(Debug, Clone)]
+pub struct ErrorDetails {
+    pub code: i32,
+    pub message: String,
+}
+
 pub type AppResult<T> = Result<T, AppError>;
@@ -1,3 +1,3 @@
 mod app_error;
 
-pub use app_error::{AppError, AppResult};
+pub use app_error::{AppError, AppResult, ErrorDetails};
@@ -0,0 +1,37 @@
+use super::Locale;
+
+/// Names of the system default categories, keyed by their icon slug.
+/// Columns are zh-CN, en, ja.
+const DEFAULT_CATEGORY_NAMES: &[(&str, [&str; 3])] = &[
+    // Expense
+    ("food", ["餐饮", "Food & Dining", "食費"]),
+    ("transport", ["交通", "Transport", "交通費"]),
+    ("shopping", ["购物", "Shopping", "買い物"]),
+    ("entertainment", ["娱乐", "Entertainment", "娯楽"]),
+    ("housing", ["居住", "Housing", "住居"]),
+    ("medical", ["医疗", "Medical", "医療"]),
+    ("education", ["教育", "Education", "教育"]),
+    ("communication", ["通讯", "Phone & Internet", "通信費"]),
+    // Income
+    ("salary", ["工资", "Salary", "給与"]),
+    ("bonus", ["奖金", "Bonus", "ボーナス"]),
+    ("investment", ["投资", "Investment", "投資"]),
+    ("part-time", ["兼职", "Part-time", "副業"]),
+    ("parttime", ["兼职", "Part-time", "副業"]),  // Slug seeded by docs/sql/init.sql
+    ("red-packet", ["红包", "Gifts", "お年玉・ご祝儀"]),
+    // Shared by both types
+    ("other", ["其他", "Other", "その他"]),
+];
+
+pub fn default_category_name(icon: &str, locale: Locale) -> Option<&'static str> {
+    let column = match locale {
+        Locale::ZhCn => 0,
+        Locale::En => 1,
+        Locale::Ja => 2,
+    };
+
+    DEFAULT_CATEGORY_NAMES
+        .iter()
+        .find(|(slug, _)| *slug == icon)
+        .map(|(_, names)| names[column])
+}
@@ -0,0 +1,141 @@
+use super::Locale;
+
+/// Error messages as written in `AppError`, with their zh-CN and ja translations.
+/// Entries ending in ": " are prefixes, whatever follows is kept as-is.
+const MESSAGES: &[(&str, &str, &str)] = &[
+    // General
+    ("Unauthorized", "未登录或登录已过期", "認証されていません"),
+    ("Access denied", "无权访问", "アクセスが拒否されました"),
+    ("Invalid ID", "ID 无效", "ID が無効です"),
+    ("Invalid date format", "日期格式错误", "日付の形式が正しくありません"),
+    ("Invalid time format", "时间格式错误", "時刻の形式が正しくありません"),
+    ("Invalid start date", "开始日期无效", "開始日が無効です"),
+    ("Invalid end date", "结束日期无效", "終了日が無効です"),
+    ("Missing permission: ", "缺少权限：", "権限がありません: "),
+    // Users
+    ("User not found", "用户不存在", "ユーザーが見つかりません"),
+    ("Invalid user ID", "用户 ID 无效", "ユーザー ID が無効です"),
+    ("User already exists", "用户已存在", "ユーザーは既に存在します"),
+    ("Unsupported locale", "不支持的语言", "サポートされていない言語です"),
+    ("Invalid timezone", "时区无效", "タイムゾーンが無効です"),
+    ("Week start must be between 1 and 7", "每周起始日必须在 1 到 7 之间", "週の開始曜日は 1〜7 で指定してください"),
+    ("Month start day must be between 1 and 28", "每月起始日必须在 1 到 28 之间", "月の開始日は 1〜28 で指定してください"),
+    ("Invalid old password", "原密码错误", "現在のパスワードが正しくありません"),
+    // Ledgers
+    ("Ledger not found", "账本不存在", "帳簿が見つかりません"),
+    ("Invalid ledger ID", "账本 ID 无效", "帳簿 ID が無効です"),
+    ("Ledger not found in trash", "回收站中没有该账本", "ゴミ箱に帳簿が見つかりません"),
+    ("Restore the ledger first", "请先恢复账本", "先に帳簿を復元してください"),
+    // Bills
+    ("Bill not found", "账单不存在", "明細が見つかりません"),
+    ("Invalid bill ID", "账单 ID 无效", "明細 ID が無効です"),
+    ("Invalid bill type", "账单类型无效", "明細の種類が無効です"),
+    ("Bill not found in trash", "回收站中没有该账单", "ゴミ箱に明細が見つかりません"),
+    ("Could not find an amount in the text", "未能从文本中识别出金额", "テキストから金額を読み取れませんでした"),
+    ("Possible duplicate of bills: ", "疑似重复账单: ", "重複の可能性がある明細: "),
+    ("Batch is empty", "批量操作没有任何账单", "一括操作の対象がありません"),
+    ("Too many bills in the batch, the limit is: ", "批量操作的账单过多，上限为：", "一括操作の明細が多すぎます。上限: "),
+    ("Bill is listed more than once", "账单重复出现", "同じ明細が複数回指定されています"),
+    ("Provide either ids or filter", "请提供 ids 或 filter 其中之一", "ids か filter のどちらかを指定してください"),
+    ("Exchange rate is required between ledgers with different currencies", "账本币种不同，需要提供汇率", "帳簿の通貨が異なるため為替レートを指定してください"),
+    ("No matching category in the destination ledger", "目标账本中没有对应的分类", "移動先の帳簿に対応するカテゴリがありません"),
+    ("Invalid sort", "排序方式无效", "並び順が無効です"),
+    ("Currency is required for ledgers with different currencies", "账本币种不同，需要指定统计币种", "帳簿の通貨が異なるため集計通貨を指定してください"),
+    ("Missing exchange rate: ", "缺少汇率：", "為替レートがありません: "),
+    ("Invalid exchange rates", "汇率格式无效", "為替レートの形式が無効です"),
+    ("Date range cannot exceed 366 days", "日期范围不能超过 366 天", "日付の範囲は 366 日以内にしてください"),
+    ("Days must be between 1 and 365", "天数必须在 1 到 365 之间", "日数は 1〜365 で指定してください"),
+    ("Window must be 0-31 days", "时间窗口必须为 0-31 天", "期間は 0〜31 日で指定してください"),
+    // Categories
+    ("Category not found", "分类不存在", "カテゴリが見つかりません"),
+    ("Invalid category ID", "分类 ID 无效", "カテゴリ ID が無効です"),
+    ("Invalid category type", "分类类型无效", "カテゴリの種類が無効です"),
+    ("Invalid parent ID", "父分类 ID 无效", "親カテゴリ ID が無効です"),
+    ("Category not found in this ledger", "该账本中没有这个分类", "この帳簿にカテゴリが見つかりません"),
+    ("Category not found in trash", "回收站中没有该分类", "ゴミ箱にカテゴリが見つかりません"),
+    ("System categories cannot be modified", "系统分类不可修改", "システムカテゴリは変更できません"),
+    ("Parent category not found", "父分类不存在", "親カテゴリが見つかりません"),
+    ("Parent category has a different type", "父分类类型不同", "親カテゴリの種類が異なります"),
+    ("Parent category is archived", "父分类已归档", "親カテゴリはアーカイブされています"),
+    ("Categories have different types", "分类类型不同", "カテゴリの種類が異なります"),
+    ("Target is inside this category", "目标分类是该分类的子分类", "対象はこのカテゴリの下位にあります"),
+    ("Category hierarchy cannot contain cycles", "分类层级不能形成环", "カテゴリ階層を循環させることはできません"),
+    // Groups
+    ("Group not found", "群组不存在", "グループが見つかりません"),
+    ("Invalid group ID", "群组 ID 无效", "グループ ID が無効です"),
+    ("Invalid invite code", "邀请码无效", "招待コードが無効です"),
+    ("Invalid role", "角色无效", "ロールが無効です"),
+    ("Already a member", "已经是成员", "既にメンバーです"),
+    ("Not a member", "不是群组成员", "メンバーではありません"),
+    ("Not a member of this group", "不是该群组成员", "このグループのメンバーではありません"),
+    ("Member not found", "成员不存在", "メンバーが見つかりません"),
+    ("Cannot remove owner", "不能移除群主", "オーナーは削除できません"),
+    ("New owner must be a member", "新群主必须是群组成员", "新しいオーナーはメンバーである必要があります"),
+    ("Owner cannot leave. Transfer ownership first.", "群主不能退出，请先转让群组", "オーナーは退出できません。先に所有権を譲渡してください。"),
+    ("Only owner can transfer", "只有群主可以转让群组", "譲渡できるのはオーナーのみです"),
+    ("Only owner can delete group", "只有群主可以删除群组", "グループを削除できるのはオーナーのみです"),
+    ("Only owner can change roles", "只有群主可以修改角色", "ロールを変更できるのはオーナーのみです"),
+    ("Only owner can change permissions", "只有群主可以修改权限", "権限を変更できるのはオーナーのみです"),
+    ("Only owner or admin can update group", "只有群主或管理员可以修改群组", "グループを更新できるのはオーナーまたは管理者のみです"),
+    ("Only owner or admin can reset invite code", "只有群主或管理员可以重置邀请码", "招待コードをリセットできるのはオーナーまたは管理者のみです"),
+    ("Only owner or admin can remove members", "只有群主或管理员可以移除成员", "メンバーを削除できるのはオーナーまたは管理者のみです"),
+    // Rules
+    ("Rule not found", "规则不存在", "ルールが見つかりません"),
+    ("Invalid rule ID", "规则 ID 无效", "ルール ID が無効です"),
+    ("Rule needs at least one condition", "规则至少需要一个条件", "ルールには条件が 1 つ以上必要です"),
+    ("Rule needs at least one action", "规则至少需要一个动作", "ルールにはアクションが 1 つ以上必要です"),
+    ("Invalid note regex", "备注正则表达式无效", "メモの正規表現が無効です"),
+    ("min_amount cannot exceed max_amount", "min_amount 不能大于 max_amount", "min_amount は max_amount 以下にしてください"),
+    ("Weekdays must be between 1 and 7", "星期必须在 1 到 7 之间", "曜日は 1〜7 で指定してください"),
+    ("Category is required", "请选择分类", "カテゴリを指定してください"),
+    // Saved views
+    ("View not found", "视图不存在", "ビューが見つかりません"),
+    ("Invalid view ID", "视图 ID 无效", "ビュー ID が無効です"),
+    ("Only the owner can change a view", "只有创建者可以修改视图", "ビューを変更できるのは作成者のみです"),
+    ("Only views of group ledgers can be shared", "只有群组账本的视图可以共享", "共有できるのはグループ帳簿のビューのみです"),
+    ("Invalid period", "时间范围无效", "期間が無効です"),
+    ("Use either a period or fixed dates", "相对时间范围与固定日期只能选其一", "相対期間と固定日付のどちらかを指定してください"),
+    ("Invalid date range", "日期范围无效", "日付の範囲が無効です"),
+    // Attachments and trash
+    ("Attachment not found", "附件不存在", "添付ファイルが見つかりません"),
+    ("Invalid attachment ID", "附件 ID 无效", "添付ファイル ID が無効です"),
+    ("Missing file field", "缺少 file 字段", "file フィールドがありません"),
+    ("File is empty", "文件为空", "ファイルが空です"),
+    ("File is too large, the limit in bytes is: ", "文件过大，字节数上限为：", "ファイルが大きすぎます。上限 (バイト): "),
+    ("Invalid multipart body: ", "multipart 请求体无效：", "multipart 本文が無効です: "),
+    ("Failed to read upload: ", "读取上传文件失败：", "アップロードを読み込めませんでした: "),
+    ("File not found", "文件不存在", "ファイルが見つかりません"),
+    ("Thumbnail not found", "缩略图不存在", "サムネイルが見つかりません"),
+    ("Unsupported file type", "不支持的文件类型", "サポートされていないファイル形式です"),
+    ("Invalid item type", "类型无效", "種類が無効です"),
+    // Notifications
+    ("Notification not found", "通知不存在", "通知が見つかりません"),
+    ("Invalid notification ID", "通知 ID 无效", "通知 ID が無効です"),
+    // Net worth
+    ("Asset not found", "资产不存在", "資産が見つかりません"),
+    ("Invalid asset ID", "资产 ID 无效", "資産 ID が無効です"),
+    ("Invalid asset kind", "资产类型无效", "資産の種類が無効です"),
+    ("Value cannot be negative", "价值不能为负数", "評価額は負の値にできません"),
+    ("Invalid currency", "币种无效", "通貨が無効です"),
+    ("Months must be between 1 and 120", "月数必须在 1 到 120 之间", "月数は 1 から 120 の間で指定してください"),
+];
+
+/// Translates an error message, `None` when it is already in `locale` or has no entry yet
+pub fn translate_message(message: &str, locale: Locale) -> Option<String> {
+    let pick = |entry: &(&str, &'static str, &'static str)| match locale {
+        Locale::ZhCn => Some(entry.1),
+        Locale::Ja => Some(entry.2),
+        Locale::En => None,
+    };
+
+    MESSAGES.iter().find_map(|entry| {
+        if entry.0.ends_with(": ") {
+            let detail = message.strip_prefix(entry.0)?;
+            pick(entry).map(|prefix| format!("{}{}", prefix, detail))
+        } else if entry.0 == message {
+            pick(entry).map(str::to_string)
+        } else {
+            None
+        }
+    })
+}
@@ -0,0 +1,145 @@
+mod catalog;
+mod messages;
+
+use serde::{Deserialize, Serialize};
+
+pub use catalog::default_category_name;
+pub use messages::translate_message;
+
+/// Languages the server can answer in.
+/// Stored names of system categories are zh-CN and error messages are written in en.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Locale {
+    #[serde(rename = "zh-CN")]
+    ZhCn,
+    #[serde(rename = "en")]
+    En,
+    #[serde(rename = "ja")]
+    Ja,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 3] = [Locale::ZhCn, Locale::En, Locale::Ja];
+
+    /// Matches a BCP 47 tag, falling back to its primary language ("en-US" -> en, "zh-TW" -> zh-CN)
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        let tag = tag.trim().to_ascii_lowercase();
+        let language = tag.split(['-', '_']).next().unwrap_or_default();
+        match language {
+            "zh" => Some(Locale::ZhCn),
+            "en" => Some(Locale::En),
+            "ja" => Some(Locale::Ja),
+            _ => None,
+        }
+    }
+
+    /// Picks the supported language with the highest `q` from an `Accept-Language` header
+    pub fn from_accept_language(header: &str) -> Option<Self> {
+        let mut candidates: Vec<(&str, f32)> = header
+            .split(',')
+            .filter_map(|part| {
+                let mut pieces = part.split(';');
+                let tag = pieces.next()?.trim();
+                let quality = pieces
+                    .find_map(|p| p.trim().strip_prefix("q="))
+                    .map(|q| q.trim().parse::<f32>().unwrap_or(0.0))
+                    .unwrap_or(1.0);
+                Some((tag, quality))
+            })
+            .filter(|(_, quality)| *quality > 0.0)
+            .collect();
+
+        // Stable sort keeps header order between equal weights
+        candidates.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        candidates.into_iter().find_map(|(tag, _)| Self::from_tag(tag))
+    }
+}
+
+impl std::fmt::Display for Locale {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Locale::ZhCn => write!(f, "zh-CN"),
+            Locale::En => write!(f, "en"),
+            Locale::Ja => write!(f, "ja"),
+        }
+    }
+}
+
+impl std::str::FromStr for Locale {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_tag(s).ok_or_else(|| format!("Unsupported locale: {}", s))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tag_falls_back_to_primary_language() {
+        assert_eq!(Locale::from_tag("en-US"), Some(Locale::En));
+        assert_eq!(Locale::from_tag("zh-TW"), Some(Locale::ZhCn));
+        assert_eq!(Locale::from_tag("ja_JP"), Some(Locale::Ja));
+        assert_eq!(Locale::from_tag("fr-FR"), None);
+        assert_eq!(Locale::from_tag(""), None);
+    }
+
+    #[test]
+    fn accept_language_prefers_highest_supported_quality() {
+        assert_eq!(Locale::from_accept_language("fr-FR, ja;q=0.5, en;q=0.8"), Some(Locale::En));
+        assert_eq!(Locale::from_accept_language("de, fr;q=0.9, *;q=0.1"), None);
+        assert_eq!(Locale::from_accept_language("en;q=0, ja;q=0.2"), Some(Locale::Ja));
+        assert_eq!(Locale::from_accept_language("zh-CN,zh;q=0.9,en;q=0.8"), Some(Locale::ZhCn));
+        assert_eq!(Locale::from_accept_language(""), None);
+    }
+
+    #[test]
+    fn category_names_fall_back_to_none_for_unknown_icons() {
+        assert_eq!(default_category_name("food", Locale::En), Some("Food & Dining"));
+        assert_eq!(default_category_name("food", Locale::ZhCn), Some("餐饮"));
+        assert_eq!(default_category_name("red-packet", Locale::Ja), Some("お年玉・ご祝儀"));
+        assert_eq!(default_category_name("coffee", Locale::En), None);
+    }
+
+    #[test]
+    fn only_system_categories_are_localized() {
+        use crate::models::{BillType, Category};
+        use uuid::Uuid;
+
+        let system = Category::default_expense_categories().remove(0);
+        assert_eq!(system.name, "餐饮");
+        assert_eq!(system.localized_name(Some(Locale::Ja)), "食費");
+        assert_eq!(system.localized_name(None), "餐饮");
+
+        let custom = Category::new(
+            "咖啡".to_string(),
+            Some("food".to_string()),
+            BillType::Expense,
+            None,
+            Some(Uuid::new_v4()),
+            None,
+        );
+        assert_eq!(custom.localized_name(Some(Locale::En)), "咖啡");
+    }
+
+    #[test]
+    fn untranslated_messages_fall_back_to_none() {
+        assert_eq!(translate_message("Bill not found", Locale::ZhCn).as_deref(), Some("账单不存在"));
+        assert_eq!(translate_message("Bill not found", Locale::En), None);
+        assert_eq!(translate_message("Something unexpected", Locale::Ja), None);
+    }
+
+    #[test]
+    fn prefixed_messages_keep_their_detail() {
+        assert_eq!(
+            translate_message("Missing permission: edit_any", Locale::ZhCn).as_deref(),
+            Some("缺少权限：edit_any")
+        );
+        assert_eq!(
+            translate_message("Too many bills in the batch, the limit is: 500", Locale::Ja).as_deref(),
+            Some("一括操作の明細が多すぎます。上限: 500")
+        );
+    }
+}
@@ -2,8 +2,10 @@ pub mod api;
 pub mod config;
 pub mod dto;
 pub mod error;
+pub mod i18n;
 pub mod middleware;
 pub mod models;
 pub mod repositories;
 pub mod services;
+pub mod storage;
 pub mod utils;
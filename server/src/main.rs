@@ -6,15 +6,23 @@ use tower_http::trace::TraceLayer;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
 use money_notes_server::api::{
-    create_routes, routes::AppState, AuthApi, BillApi, CategoryApi, GroupApi, LedgerApi, UserApi,
+    create_routes, routes::AppState, AttachmentApi, AuditApi, AuthApi, BillApi, CategoryApi,
+    GroupApi, LedgerApi, NetWorthApi, NotificationApi, RuleApi, TrashApi, UserApi, ViewApi,
 };
 use money_notes_server::config::Settings;
-use money_notes_server::middleware::AuthState;
+use money_notes_server::middleware::{AuthState, LocaleState};
 use money_notes_server::repositories::mysql::{
-    MySqlBillRepository, MySqlCategoryRepository, MySqlGroupRepository, MySqlLedgerRepository,
+    MySqlAssetRepository, MySqlAttachmentRepository, MySqlAuditRepository, MySqlBillRepository,
+    MySqlCategoryRepository, MySqlGroupRepository, MySqlLedgerRepository, MySqlNetWorthRepository,
+    MySqlNotificationRepository, MySqlRuleRepository, MySqlSavedViewRepository,
     MySqlUserRepository,
 };
-use money_notes_server::services::AuthService;
+use money_notes_server::services::{
+    AnomalyService, AttachmentService, AuditService, AuthService, DuplicateService,
+    ForecastService, NetWorthService, PermissionService, QuickEntryService, RuleService,
+    SuggestionService, TrashService,
+};
+use money_notes_server::storage::{BlobStore, LocalBlobStore, S3BlobStore};
 use money_notes_server::utils::JwtUtil;
 
 #[tokio::main]
@@ -53,6 +61,26 @@ async fn main() {
         Arc::new(MySqlCategoryRepository::new(pool.clone()));
     let group_repo: Arc<dyn money_notes_server::repositories::GroupRepository> =
         Arc::new(MySqlGroupRepository::new(pool.clone()));
+    let attachment_repo: Arc<dyn money_notes_server::repositories::AttachmentRepository> =
+        Arc::new(MySqlAttachmentRepository::new(pool.clone()));
+    let audit_repo: Arc<dyn money_notes_server::repositories::AuditRepository> =
+        Arc::new(MySqlAuditRepository::new(pool.clone()));
+    let rule_repo: Arc<dyn money_notes_server::repositories::RuleRepository> =
+        Arc::new(MySqlRuleRepository::new(pool.clone()));
+    let view_repo: Arc<dyn money_notes_server::repositories::SavedViewRepository> =
+        Arc::new(MySqlSavedViewRepository::new(pool.clone()));
+    let notification_repo: Arc<dyn money_notes_server::repositories::NotificationRepository> =
+        Arc::new(MySqlNotificationRepository::new(pool.clone()));
+    let asset_repo: Arc<dyn money_notes_server::repositories::AssetRepository> =
+        Arc::new(MySqlAssetRepository::new(pool.clone()));
+    let net_worth_repo: Arc<dyn money_notes_server::repositories::NetWorthRepository> =
+        Arc::new(MySqlNetWorthRepository::new(pool.clone()));
+
+    // Initialize blob storage for attachments
+    let blob_store: Arc<dyn BlobStore> = match settings.storage.driver.as_str() {
+        "s3" => Arc::new(S3BlobStore::new(&settings.storage.s3).expect("Invalid S3 configuration")),
+        _ => Arc::new(LocalBlobStore::new(&settings.storage.local_path)),
+    };
 
     // Initialize default categories
     if let Err(e) = category_repo.init_default_categories().await {
@@ -68,20 +96,129 @@ async fn main() {
 
     // Initialize services
     let auth_service = Arc::new(AuthService::new(user_repo.clone(), jwt_util.clone()));
+    let audit_service = Arc::new(AuditService::new(audit_repo.clone()));
+    let permission_service = Arc::new(PermissionService::new(ledger_repo.clone(), group_repo.clone()));
+    let rule_service = Arc::new(RuleService::new(rule_repo.clone(), category_repo.clone()));
+    let suggestion_service = Arc::new(SuggestionService::new(bill_repo.clone(), category_repo.clone()));
+    let quick_entry_service = Arc::new(QuickEntryService::new(
+        category_repo.clone(),
+        rule_service.clone(),
+        suggestion_service.clone(),
+    ));
+    let attachment_service = Arc::new(AttachmentService::new(
+        attachment_repo.clone(),
+        blob_store,
+        &settings.storage,
+    ));
+
+    let trash_settings = settings.trash;
+    let trash_service = Arc::new(TrashService::new(
+        bill_repo.clone(),
+        ledger_repo.clone(),
+        category_repo.clone(),
+        attachment_service.clone(),
+        trash_settings.retention_days,
+    ));
+    trash_service.clone().spawn_purge_job(trash_settings.purge_interval);
+
+    let net_worth_service = Arc::new(NetWorthService::new(
+        user_repo.clone(),
+        ledger_repo.clone(),
+        bill_repo.clone(),
+        asset_repo.clone(),
+        net_worth_repo.clone(),
+    ));
+    net_worth_service.clone().spawn_snapshot_job(settings.net_worth.snapshot_interval);
 
     // Initialize API handlers
+    let duplicate_settings = settings.duplicates;
+    let duplicate_service = Arc::new(DuplicateService::new(
+        bill_repo.clone(),
+        duplicate_settings.window_days,
+        duplicate_settings.note_similarity,
+    ));
+    let forecast_service = Arc::new(ForecastService::new(bill_repo.clone()));
+    let anomaly_service = Arc::new(AnomalyService::new(
+        bill_repo.clone(),
+        group_repo.clone(),
+        notification_repo.clone(),
+    ));
+
     let auth_api = Arc::new(AuthApi::new(auth_service, settings.jwt.access_token_expires));
     let user_api = Arc::new(UserApi::new(user_repo.clone()));
-    let ledger_api = Arc::new(LedgerApi::new(ledger_repo.clone()));
+    let ledger_api = Arc::new(LedgerApi::new(ledger_repo.clone(), audit_service.clone()));
     let bill_api = Arc::new(BillApi::new(
+        bill_repo.clone(),
+        category_repo.clone(),
+        user_repo.clone(),
+        audit_service.clone(),
+        permission_service.clone(),
+        rule_service.clone(),
+        suggestion_service,
+        quick_entry_service,
+        duplicate_service,
+        forecast_service,
+        anomaly_service,
+        view_repo.clone(),
+    ));
+    let category_api = Arc::new(CategoryApi::new(
+        category_repo.clone(),
+        bill_repo.clone(),
+        audit_service.clone(),
+        permission_service.clone(),
+    ));
+    let group_api = Arc::new(GroupApi::new(
+        group_repo.clone(),
+        user_repo.clone(),
+        ledger_repo.clone(),
+        audit_service.clone(),
+        permission_service.clone(),
+    ));
+    let attachment_api = Arc::new(AttachmentApi::new(
+        attachment_service.clone(),
+        bill_repo.clone(),
+        permission_service.clone(),
+    ));
+
+    let trash_api = Arc::new(TrashApi::new(
         bill_repo.clone(),
         ledger_repo.clone(),
         category_repo.clone(),
+        trash_service,
+        audit_service.clone(),
+        permission_service.clone(),
+    ));
+    let audit_api = Arc::new(AuditApi::new(
+        audit_service,
+        bill_repo.clone(),
+        ledger_repo.clone(),
+        group_repo.clone(),
         user_repo.clone(),
     ));
-    let category_api = Arc::new(CategoryApi::new(category_repo.clone()));
-    let group_api = Arc::new(GroupApi::new(group_repo.clone(), user_repo.clone(), ledger_repo.clone()));
+    let rule_api = Arc::new(RuleApi::new(
+        rule_repo.clone(),
+        bill_repo.clone(),
+        rule_service,
+        permission_service.clone(),
+    ));
+    let view_api = Arc::new(ViewApi::new(
+        view_repo,
+        category_repo.clone(),
+        user_repo.clone(),
+        permission_service.clone(),
+    ));
+    let notification_api = Arc::new(NotificationApi::new(notification_repo));
+    let net_worth_api = Arc::new(NetWorthApi::new(
+        asset_repo,
+        net_worth_repo,
+        user_repo.clone(),
+        net_worth_service,
+    ));
 
+    let locale_state = LocaleState {
+        jwt_util: jwt_util.clone(),
+        user_repo: user_repo.clone(),
+    };
     let auth_state = AuthState { jwt_util };
 
     // Build router
@@ -92,7 +229,15 @@ async fn main() {
         bill_api,
         category_api,
         group_api,
+        attachment_api,
+        trash_api,
+        audit_api,
+        rule_api,
+        view_api,
+        notification_api,
+        net_worth_api,
         auth_state,
+        locale_state,
     };
 
     let app = create_routes(app_state)
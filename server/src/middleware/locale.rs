@@ -0,0 +1,87 @@
+use async_trait::async_trait;
+use axum::{
+    body::Body,
+    extract::{FromRequestParts, State},
+    http::{header, request::Parts, Request},
+    middleware::Next,
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde_json::json;
+use std::convert::Infallible;
+use std::sync::Arc;
+
+use crate::error::ErrorDetails;
+use crate::i18n::{translate_message, Locale};
+use crate::repositories::UserRepository;
+use crate::utils::JwtUtil;
+
+#[derive(Clone)]
+pub struct LocaleState {
+    pub jwt_util: JwtUtil,
+    pub user_repo: Arc<dyn UserRepository>,
+}
+
+/// The caller's language, `None` when neither a preference nor a supported `Accept-Language` was given.
+/// Responses are left untouched in that case.
+#[derive(Debug, Clone, Copy)]
+pub struct RequestLocale(pub Option<Locale>);
+
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for RequestLocale {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        Ok(RequestLocale(parts.extensions.get::<Locale>().copied()))
+    }
+}
+
+/// Resolves the request locale (user preference first, then `Accept-Language`)
+/// and translates error messages on the way out.
+pub async fn locale_middleware(
+    State(state): State<LocaleState>,
+    mut request: Request<Body>,
+    next: Next,
+) -> Response {
+    let token = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|h| h.strip_prefix("Bearer "))
+        .map(str::to_string);
+
+    let locale = match user_locale(&state, token.as_deref()).await {
+        Some(locale) => Some(locale),
+        None => request
+            .headers()
+            .get(header::ACCEPT_LANGUAGE)
+            .and_then(|value| value.to_str().ok())
+            .and_then(Locale::from_accept_language),
+    };
+
+    let Some(locale) = locale else {
+        return next.run(request).await;
+    };
+
+    request.extensions_mut().insert(locale);
+    let response = next.run(request).await;
+
+    let Some(details) = response.extensions().get::<ErrorDetails>().cloned() else {
+        return response;
+    };
+    match translate_message(&details.message, locale) {
+        Some(message) => {
+            let body = Json(json!({
+                "code": details.code,
+                "message": message
+            }));
+            (response.status(), body).into_response()
+        }
+        None => response,
+    }
+}
+
+async fn user_locale(state: &LocaleState, token: Option<&str>) -> Option<Locale> {
+    let user_id = state.jwt_util.extract_user_id(token?).ok()?;
+    state.user_repo.find_by_id(user_id).await.ok()??.locale
+}
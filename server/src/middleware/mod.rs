@@ -1,3 +1,5 @@
 pub mod auth;
+pub mod locale;
 
 pub use auth::*;
+pub use locale::*;
@@ -0,0 +1,77 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AssetKind {
+    Asset,  // Property, a car, investments...
+    Liability,  // A loan or mortgage
+}
+
+impl std::fmt::Display for AssetKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AssetKind::Asset => write!(f, "asset"),
+            AssetKind::Liability => write!(f, "liability"),
+        }
+    }
+}
+
+impl std::str::FromStr for AssetKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "asset" => Ok(AssetKind::Asset),
+            "liability" => Ok(AssetKind::Liability),
+            _ => Err(format!("Unknown asset kind: {}", s)),
+        }
+    }
+}
+
+/// Something a user owns or owes outside their ledgers, valued by hand
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Asset {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    pub kind: AssetKind,
+    pub currency: String,
+    pub value: f64,  // Always positive, `kind` says which side of the net worth it is on
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,  // When the value was last changed
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateAsset {
+    pub user_id: Uuid,
+    pub name: String,
+    pub kind: AssetKind,
+    pub currency: Option<String>,
+    pub value: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateAsset {
+    pub name: Option<String>,
+    pub kind: Option<AssetKind>,
+    pub currency: Option<String>,
+    pub value: Option<f64>,
+}
+
+impl Asset {
+    pub fn new(asset: CreateAsset) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            user_id: asset.user_id,
+            name: asset.name,
+            kind: asset.kind,
+            currency: asset.currency.unwrap_or_else(|| "CNY".to_string()),
+            value: asset.value,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
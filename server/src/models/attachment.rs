@@ -0,0 +1,54 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Attachment {
+    pub id: Uuid,
+    pub bill_id: Uuid,
+    pub user_id: Uuid,  // Who uploaded this attachment
+    pub file_name: String,
+    pub content_type: String,
+    pub size: u64,
+    pub storage_key: String,
+    pub thumbnail_key: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateAttachment {
+    pub id: Uuid,
+    pub bill_id: Uuid,
+    pub user_id: Uuid,
+    pub file_name: String,
+    pub content_type: String,
+    pub size: u64,
+    pub storage_key: String,
+    pub thumbnail_key: Option<String>,
+}
+
+impl Attachment {
+    pub fn new(attachment: CreateAttachment) -> Self {
+        Self {
+            id: attachment.id,
+            bill_id: attachment.bill_id,
+            user_id: attachment.user_id,
+            file_name: attachment.file_name,
+            content_type: attachment.content_type,
+            size: attachment.size,
+            storage_key: attachment.storage_key,
+            thumbnail_key: attachment.thumbnail_key,
+            created_at: Utc::now(),
+        }
+    }
+
+    /// Storage key for the original file
+    pub fn storage_key_for(bill_id: Uuid, id: Uuid) -> String {
+        format!("bills/{}/{}", bill_id, id)
+    }
+
+    /// Storage key for the generated thumbnail
+    pub fn thumbnail_key_for(bill_id: Uuid, id: Uuid) -> String {
+        format!("bills/{}/{}_thumb", bill_id, id)
+    }
+}
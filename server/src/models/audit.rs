@@ -0,0 +1,109 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditEntityType {
+    Bill,
+    Ledger,
+    Category,
+    GroupMember,
+}
+
+impl std::fmt::Display for AuditEntityType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AuditEntityType::Bill => write!(f, "bill"),
+            AuditEntityType::Ledger => write!(f, "ledger"),
+            AuditEntityType::Category => write!(f, "category"),
+            AuditEntityType::GroupMember => write!(f, "group_member"),
+        }
+    }
+}
+
+impl std::str::FromStr for AuditEntityType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "bill" => Ok(AuditEntityType::Bill),
+            "ledger" => Ok(AuditEntityType::Ledger),
+            "category" => Ok(AuditEntityType::Category),
+            "group_member" => Ok(AuditEntityType::GroupMember),
+            _ => Err(format!("Unknown audit entity type: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AuditAction {
+    Create,
+    Update,
+    Delete,
+    Restore,
+}
+
+impl std::fmt::Display for AuditAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AuditAction::Create => write!(f, "create"),
+            AuditAction::Update => write!(f, "update"),
+            AuditAction::Delete => write!(f, "delete"),
+            AuditAction::Restore => write!(f, "restore"),
+        }
+    }
+}
+
+impl std::str::FromStr for AuditAction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "create" => Ok(AuditAction::Create),
+            "update" => Ok(AuditAction::Update),
+            "delete" => Ok(AuditAction::Delete),
+            "restore" => Ok(AuditAction::Restore),
+            _ => Err(format!("Unknown audit action: {}", s)),
+        }
+    }
+}
+
+/// An append-only record of a single change
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditEvent {
+    pub id: Uuid,
+    pub entity_type: AuditEntityType,
+    pub entity_id: Uuid,
+    pub action: AuditAction,
+    pub actor_id: Uuid,
+    pub group_id: Option<Uuid>,  // Set when the change happened inside a group
+    pub changes: serde_json::Value,  // { field: { "before": .., "after": .. } }
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateAuditEvent {
+    pub entity_type: AuditEntityType,
+    pub entity_id: Uuid,
+    pub action: AuditAction,
+    pub actor_id: Uuid,
+    pub group_id: Option<Uuid>,
+    pub changes: serde_json::Value,
+}
+
+impl AuditEvent {
+    pub fn new(event: CreateAuditEvent) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            entity_type: event.entity_type,
+            entity_id: event.entity_id,
+            action: event.action,
+            actor_id: event.actor_id,
+            group_id: event.group_id,
+            changes: event.changes,
+            created_at: Utc::now(),
+        }
+    }
+}
@@ -1,8 +1,8 @@
-use chrono::{DateTime, NaiveDate, Utc};
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum BillType {
     Income,
@@ -18,6 +18,33 @@ impl std::fmt::Display for BillType {
     }
 }
 
+/// Orders a bill listing can be sorted in, ties go to the most recent bill
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BillSort {
+    #[default]
+    DateDesc,
+    DateAsc,
+    AmountDesc,
+    AmountAsc,
+    CreatedDesc,
+    CreatedAsc,
+}
+
+impl BillSort {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "date_desc" => Some(Self::DateDesc),
+            "date_asc" => Some(Self::DateAsc),
+            "amount_desc" => Some(Self::AmountDesc),
+            "amount_asc" => Some(Self::AmountAsc),
+            "created_desc" => Some(Self::CreatedDesc),
+            "created_asc" => Some(Self::CreatedAsc),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Bill {
     pub id: Uuid,
@@ -28,8 +55,10 @@ pub struct Bill {
     pub amount: f64,
     pub note: Option<String>,
     pub bill_date: NaiveDate,
+    pub bill_time: Option<NaiveTime>,  // Time of day it happened, in the local time of whoever entered it
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,  // Set while the bill is in the trash
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -41,18 +70,22 @@ pub struct CreateBill {
     pub amount: f64,
     pub note: Option<String>,
     pub bill_date: NaiveDate,
+    pub bill_time: Option<NaiveTime>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UpdateBill {
+    pub ledger_id: Option<Uuid>,
     pub category_id: Option<Uuid>,
     pub bill_type: Option<BillType>,
     pub amount: Option<f64>,
     pub note: Option<String>,
     pub bill_date: Option<NaiveDate>,
+    pub bill_time: Option<Option<NaiveTime>>,  // Some(None) clears the time
 }
 
 impl Bill {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         ledger_id: Uuid,
         category_id: Uuid,
@@ -61,6 +94,7 @@ impl Bill {
         amount: f64,
         note: Option<String>,
         bill_date: NaiveDate,
+        bill_time: Option<NaiveTime>,
     ) -> Self {
         let now = Utc::now();
         Self {
@@ -72,8 +106,10 @@ impl Bill {
             amount,
             note,
             bill_date,
+            bill_time,
             created_at: now,
             updated_at: now,
+            deleted_at: None,
         }
     }
 }
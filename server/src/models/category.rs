@@ -3,6 +3,7 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use super::bill::BillType;
+use crate::i18n::{default_category_name, Locale};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Category {
@@ -14,6 +15,8 @@ pub struct Category {
     pub ledger_id: Option<Uuid>,  // None = system default
     pub sort_order: i32,
     pub created_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,  // Set while the category is in the trash
+    pub archived_at: Option<DateTime<Utc>>,  // Hidden from pickers but kept on existing bills
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -44,33 +47,61 @@ impl Category {
             ledger_id,
             sort_order: sort_order.unwrap_or(0),
             created_at: Utc::now(),
+            deleted_at: None,
+            archived_at: None,
         }
     }
 
+    /// System defaults are shared by every ledger and can't be changed by users
+    pub fn is_system(&self) -> bool {
+        self.ledger_id.is_none()
+    }
+
+    /// Whether new bills and child categories in `ledger_id` may use this category
+    pub fn is_usable_in(&self, ledger_id: Uuid) -> bool {
+        self.deleted_at.is_none()
+            && self.archived_at.is_none()
+            && (self.is_system() || self.ledger_id == Some(ledger_id))
+    }
+
+    /// Name shown to the caller, system defaults are translated by their icon slug
+    pub fn localized_name(&self, locale: Option<Locale>) -> String {
+        let translated = match (locale, &self.icon) {
+            (Some(locale), Some(icon)) if self.is_system() => default_category_name(icon, locale),
+            _ => None,
+        };
+        translated.map(str::to_string).unwrap_or_else(|| self.name.clone())
+    }
+
+    fn system_default(icon: &str, category_type: BillType, sort_order: i32) -> Self {
+        let name = default_category_name(icon, Locale::ZhCn).unwrap_or(icon);
+        Self::new(name.to_string(), Some(icon.to_string()), category_type, None, None, Some(sort_order))
+    }
+
     /// Create default expense categories
     pub fn default_expense_categories() -> Vec<Self> {
         vec![
-            Self::new("餐饮".to_string(), Some("food".to_string()), BillType::Expense, None, None, Some(1)),
-            Self::new("交通".to_string(), Some("transport".to_string()), BillType::Expense, None, None, Some(2)),
-            Self::new("购物".to_string(), Some("shopping".to_string()), BillType::Expense, None, None, Some(3)),
-            Self::new("娱乐".to_string(), Some("entertainment".to_string()), BillType::Expense, None, None, Some(4)),
-            Self::new("居住".to_string(), Some("housing".to_string()), BillType::Expense, None, None, Some(5)),
-            Self::new("医疗".to_string(), Some("medical".to_string()), BillType::Expense, None, None, Some(6)),
-            Self::new("教育".to_string(), Some("education".to_string()), BillType::Expense, None, None, Some(7)),
-            Self::new("通讯".to_string(), Some("communication".to_string()), BillType::Expense, None, None, Some(8)),
-            Self::new("其他".to_string(), Some("other".to_string()), BillType::Expense, None, None, Some(99)),
+            Self::system_default("food", BillType::Expense, 1),
+            Self::system_default("transport", BillType::Expense, 2),
+            Self::system_default("shopping", BillType::Expense, 3),
+            Self::system_default("entertainment", BillType::Expense, 4),
+            Self::system_default("housing", BillType::Expense, 5),
+            Self::system_default("medical", BillType::Expense, 6),
+            Self::system_default("education", BillType::Expense, 7),
+            Self::system_default("communication", BillType::Expense, 8),
+            Self::system_default("other", BillType::Expense, 99),
         ]
     }
 
     /// Create default income categories
     pub fn default_income_categories() -> Vec<Self> {
         vec![
-            Self::new("工资".to_string(), Some("salary".to_string()), BillType::Income, None, None, Some(1)),
-            Self::new("奖金".to_string(), Some("bonus".to_string()), BillType::Income, None, None, Some(2)),
-            Self::new("投资".to_string(), Some("investment".to_string()), BillType::Income, None, None, Some(3)),
-            Self::new("兼职".to_string(), Some("part-time".to_string()), BillType::Income, None, None, Some(4)),
-            Self::new("红包".to_string(), Some("red-packet".to_string()), BillType::Income, None, None, Some(5)),
-            Self::new("其他".to_string(), Some("other".to_string()), BillType::Income, None, None, Some(99)),
+            Self::system_default("salary", BillType::Income, 1),
+            Self::system_default("bonus", BillType::Income, 2),
+            Self::system_default("investment", BillType::Income, 3),
+            Self::system_default("part-time", BillType::Income, 4),
+            Self::system_default("red-packet", BillType::Income, 5),
+            Self::system_default("other", BillType::Income, 99),
         ]
     }
 }
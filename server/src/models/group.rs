@@ -20,6 +20,57 @@ impl std::fmt::Display for GroupRole {
     }
 }
 
+/// Something a group member may do in the group's ledgers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GroupCapability {
+    Create,
+    EditOwn,  // Edit and delete bills the member created
+    EditAny,
+    DeleteAny,
+    ManageCategories,
+    ViewOnly,  // Read access only, cannot be combined with the others
+}
+
+impl GroupCapability {
+    pub const ALL: [GroupCapability; 5] = [
+        GroupCapability::Create,
+        GroupCapability::EditOwn,
+        GroupCapability::EditAny,
+        GroupCapability::DeleteAny,
+        GroupCapability::ManageCategories,
+    ];
+}
+
+impl std::fmt::Display for GroupCapability {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GroupCapability::Create => write!(f, "create"),
+            GroupCapability::EditOwn => write!(f, "edit_own"),
+            GroupCapability::EditAny => write!(f, "edit_any"),
+            GroupCapability::DeleteAny => write!(f, "delete_any"),
+            GroupCapability::ManageCategories => write!(f, "manage_categories"),
+            GroupCapability::ViewOnly => write!(f, "view_only"),
+        }
+    }
+}
+
+impl std::str::FromStr for GroupCapability {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "create" => Ok(GroupCapability::Create),
+            "edit_own" => Ok(GroupCapability::EditOwn),
+            "edit_any" => Ok(GroupCapability::EditAny),
+            "delete_any" => Ok(GroupCapability::DeleteAny),
+            "manage_categories" => Ok(GroupCapability::ManageCategories),
+            "view_only" => Ok(GroupCapability::ViewOnly),
+            _ => Err(format!("Unknown capability: {}", s)),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Group {
     pub id: Uuid,
@@ -40,6 +91,36 @@ pub struct GroupMember {
     pub joined_at: DateTime<Utc>,
 }
 
+/// Capabilities granted to admins and members of a group.
+/// The owner always has every capability so a group can't lock itself out.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroupPolicy {
+    pub group_id: Uuid,
+    pub admin: Vec<GroupCapability>,
+    pub member: Vec<GroupCapability>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl GroupPolicy {
+    /// Policy used until the owner configures one
+    pub fn default_for(group_id: Uuid) -> Self {
+        Self {
+            group_id,
+            admin: GroupCapability::ALL.to_vec(),
+            member: vec![GroupCapability::Create, GroupCapability::EditOwn],
+            updated_at: Utc::now(),
+        }
+    }
+
+    pub fn capabilities(&self, role: GroupRole) -> Vec<GroupCapability> {
+        match role {
+            GroupRole::Owner => GroupCapability::ALL.to_vec(),
+            GroupRole::Admin => self.admin.clone(),
+            GroupRole::Member => self.member.clone(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateGroup {
     pub name: String,
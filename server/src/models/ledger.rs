@@ -29,6 +29,7 @@ pub struct Ledger {
     pub currency: String,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,  // Set while the ledger is in the trash
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -54,6 +55,7 @@ impl Ledger {
             currency: currency.unwrap_or_else(|| "CNY".to_string()),
             created_at: now,
             updated_at: now,
+            deleted_at: None,
         }
     }
 
@@ -69,6 +71,7 @@ impl Ledger {
             currency: currency.unwrap_or_else(|| "CNY".to_string()),
             created_at: now,
             updated_at: now,
+            deleted_at: None,
         }
     }
 }
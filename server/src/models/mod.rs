@@ -3,9 +3,23 @@ mod group;
 mod ledger;
 mod bill;
 mod category;
+mod attachment;
+mod audit;
+mod rule;
+mod saved_view;
+mod notification;
+mod asset;
+mod net_worth;
 
 pub use user::*;
 pub use group::*;
 pub use ledger::*;
 pub use bill::*;
 pub use category::*;
+pub use attachment::*;
+pub use audit::*;
+pub use rule::*;
+pub use saved_view::*;
+pub use notification::*;
+pub use asset::*;
+pub use net_worth::*;
@@ -0,0 +1,43 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A user's net worth in one currency on one day. Amounts in different currencies are never added up,
+/// so a day has one snapshot per currency.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NetWorthSnapshot {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub snapshot_date: NaiveDate,  // In the user's calendar
+    pub currency: String,
+    pub assets: f64,  // Positive ledger balances plus assets
+    pub liabilities: f64,  // Negative ledger balances plus liabilities, as a positive amount
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateNetWorthSnapshot {
+    pub user_id: Uuid,
+    pub snapshot_date: NaiveDate,
+    pub currency: String,
+    pub assets: f64,
+    pub liabilities: f64,
+}
+
+impl NetWorthSnapshot {
+    pub fn new(snapshot: CreateNetWorthSnapshot) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            user_id: snapshot.user_id,
+            snapshot_date: snapshot.snapshot_date,
+            currency: snapshot.currency,
+            assets: snapshot.assets,
+            liabilities: snapshot.liabilities,
+            created_at: Utc::now(),
+        }
+    }
+
+    pub fn net_worth(&self) -> f64 {
+        self.assets - self.liabilities
+    }
+}
@@ -0,0 +1,65 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationKind {
+    Anomaly,  // A new bill looks unusual for its ledger
+}
+
+impl std::fmt::Display for NotificationKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NotificationKind::Anomaly => write!(f, "anomaly"),
+        }
+    }
+}
+
+impl std::str::FromStr for NotificationKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "anomaly" => Ok(NotificationKind::Anomaly),
+            _ => Err(format!("Unknown notification kind: {}", s)),
+        }
+    }
+}
+
+/// Something a user should look at, stays unread until they open it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Notification {
+    pub id: Uuid,
+    pub user_id: Uuid,  // Recipient
+    pub kind: NotificationKind,
+    pub ledger_id: Option<Uuid>,
+    pub bill_id: Option<Uuid>,
+    pub data: serde_json::Value,  // Details for the client to render, depends on `kind`
+    pub read_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateNotification {
+    pub user_id: Uuid,
+    pub kind: NotificationKind,
+    pub ledger_id: Option<Uuid>,
+    pub bill_id: Option<Uuid>,
+    pub data: serde_json::Value,
+}
+
+impl Notification {
+    pub fn new(notification: CreateNotification) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            user_id: notification.user_id,
+            kind: notification.kind,
+            ledger_id: notification.ledger_id,
+            bill_id: notification.bill_id,
+            data: notification.data,
+            read_at: None,
+            created_at: Utc::now(),
+        }
+    }
+}
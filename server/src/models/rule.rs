@@ -0,0 +1,203 @@
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+use regex::{Regex, RegexBuilder};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::bill::BillType;
+
+/// Conditions of an auto-categorization rule, every condition that is set must hold
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RuleConditions {
+    #[serde(default)]
+    pub note_contains: Option<String>,  // Case-insensitive substring
+    #[serde(default)]
+    pub note_regex: Option<String>,
+    #[serde(default)]
+    pub min_amount: Option<f64>,
+    #[serde(default)]
+    pub max_amount: Option<f64>,
+    #[serde(default)]
+    pub weekdays: Vec<u32>,  // ISO numbering, 1 = Monday ... 7 = Sunday
+    #[serde(default)]
+    pub bill_type: Option<BillType>,
+}
+
+/// What a matching rule does to the bill
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RuleActions {
+    #[serde(default)]
+    pub category_id: Option<Uuid>,
+    #[serde(default)]
+    pub note: Option<String>,  // New note, `$1`/`$name` are expanded from `note_regex` captures
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CategoryRule {
+    pub id: Uuid,
+    pub ledger_id: Uuid,
+    pub name: String,
+    pub priority: i32,  // Lower runs first
+    pub enabled: bool,
+    pub conditions: RuleConditions,
+    pub actions: RuleActions,
+    pub created_by: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateCategoryRule {
+    pub ledger_id: Uuid,
+    pub name: String,
+    pub priority: i32,
+    pub enabled: bool,
+    pub conditions: RuleConditions,
+    pub actions: RuleActions,
+    pub created_by: Uuid,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateCategoryRule {
+    pub name: Option<String>,
+    pub priority: Option<i32>,
+    pub enabled: Option<bool>,
+    pub conditions: Option<RuleConditions>,
+    pub actions: Option<RuleActions>,
+}
+
+/// The parts of a bill rules look at
+#[derive(Debug, Clone, Copy)]
+pub struct RuleInput<'a> {
+    pub note: Option<&'a str>,
+    pub amount: f64,
+    pub bill_type: BillType,
+    pub bill_date: NaiveDate,
+}
+
+/// Changes a matching rule makes, `None` fields are left alone
+#[derive(Debug, Clone)]
+pub struct RuleOutcome {
+    pub rule_id: Uuid,
+    pub category_id: Option<Uuid>,
+    pub note: Option<String>,
+}
+
+impl RuleConditions {
+    pub fn validate(&self) -> Result<(), String> {
+        let has_condition = self.note_contains.is_some()
+            || self.note_regex.is_some()
+            || self.min_amount.is_some()
+            || self.max_amount.is_some()
+            || !self.weekdays.is_empty()
+            || self.bill_type.is_some();
+        if !has_condition {
+            return Err("Rule needs at least one condition".to_string());
+        }
+        if let Some(ref pattern) = self.note_regex {
+            compile(pattern).map_err(|_| "Invalid note regex".to_string())?;
+        }
+        if let (Some(min), Some(max)) = (self.min_amount, self.max_amount) {
+            if min > max {
+                return Err("min_amount cannot exceed max_amount".to_string());
+            }
+        }
+        if self.weekdays.iter().any(|d| !(1..=7).contains(d)) {
+            return Err("Weekdays must be between 1 and 7".to_string());
+        }
+        Ok(())
+    }
+
+    /// `note_regex` compiled, to build once and pass to `evaluate` for every bill.
+    /// None when there is no pattern or it doesn't compile.
+    pub fn compile_note_regex(&self) -> Option<Regex> {
+        self.note_regex.as_deref().and_then(|pattern| compile(pattern).ok())
+    }
+}
+
+impl RuleActions {
+    pub fn is_empty(&self) -> bool {
+        self.category_id.is_none() && self.note.is_none()
+    }
+}
+
+impl CategoryRule {
+    pub fn new(rule: CreateCategoryRule) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            ledger_id: rule.ledger_id,
+            name: rule.name,
+            priority: rule.priority,
+            enabled: rule.enabled,
+            conditions: rule.conditions,
+            actions: rule.actions,
+            created_by: rule.created_by,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Runs the rule against a bill, `None` when a condition doesn't hold.
+    /// `note_regex` is the rule's compiled `note_regex` condition.
+    pub fn apply(&self, note_regex: Option<&Regex>, input: &RuleInput<'_>) -> Option<RuleOutcome> {
+        evaluate(self.id, &self.conditions, &self.actions, note_regex, input)
+    }
+}
+
+/// Shared by saved rules and dry runs of unsaved ones.
+/// `note_regex` comes from `RuleConditions::compile_note_regex`, so a rule run against many bills compiles it once.
+pub fn evaluate(
+    rule_id: Uuid,
+    conditions: &RuleConditions,
+    actions: &RuleActions,
+    note_regex: Option<&Regex>,
+    input: &RuleInput<'_>,
+) -> Option<RuleOutcome> {
+    let note = input.note.unwrap_or_default();
+
+    if conditions.bill_type.is_some_and(|t| t != input.bill_type) {
+        return None;
+    }
+    if conditions.min_amount.is_some_and(|min| input.amount < min) {
+        return None;
+    }
+    if conditions.max_amount.is_some_and(|max| input.amount > max) {
+        return None;
+    }
+    if !conditions.weekdays.is_empty()
+        && !conditions.weekdays.contains(&input.bill_date.weekday().number_from_monday())
+    {
+        return None;
+    }
+    if let Some(ref needle) = conditions.note_contains {
+        if !note.to_lowercase().contains(&needle.to_lowercase()) {
+            return None;
+        }
+    }
+
+    let new_note = match conditions.note_regex {
+        Some(_) => {
+            // A pattern that doesn't compile matches nothing
+            let captures = note_regex?.captures(note)?;
+            actions.note.as_ref().map(|template| {
+                let mut expanded = String::new();
+                captures.expand(template, &mut expanded);
+                expanded
+            })
+        }
+        None => actions.note.clone(),
+    };
+
+    Some(RuleOutcome {
+        rule_id,
+        category_id: actions.category_id,
+        note: new_note,
+    })
+}
+
+fn compile(pattern: &str) -> Result<Regex, regex::Error> {
+    RegexBuilder::new(pattern)
+        .case_insensitive(true)
+        .size_limit(1 << 20)
+        .build()
+}
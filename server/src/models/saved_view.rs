@@ -0,0 +1,117 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::bill::{BillSort, BillType};
+use crate::utils::Calendar;
+
+/// The bill listing criteria a view saves, every criterion that is set must hold
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ViewFilter {
+    #[serde(default)]
+    pub period: Option<String>,  // Relative range such as "last 3 months", resolved each time the view runs
+    #[serde(default)]
+    pub start_date: Option<NaiveDate>,
+    #[serde(default)]
+    pub end_date: Option<NaiveDate>,
+    #[serde(default)]
+    pub bill_type: Option<BillType>,
+    #[serde(default)]
+    pub category_ids: Vec<Uuid>,  // Subcategories are included
+    #[serde(default)]
+    pub user_ids: Vec<Uuid>,  // Creators
+    #[serde(default)]
+    pub mine: bool,  // Created by whoever runs the view, on top of `user_ids`
+    #[serde(default)]
+    pub q: Option<String>,
+    #[serde(default)]
+    pub min_amount: Option<f64>,
+    #[serde(default)]
+    pub max_amount: Option<f64>,
+    #[serde(default)]
+    pub has_note: Option<bool>,
+    #[serde(default)]
+    pub sort: BillSort,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedView {
+    pub id: Uuid,
+    pub user_id: Uuid,  // Owner, the only one who can change the view
+    pub ledger_id: Uuid,
+    pub name: String,
+    pub filter: ViewFilter,
+    pub shared: bool,  // Visible to everyone who can view the ledger
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateSavedView {
+    pub user_id: Uuid,
+    pub ledger_id: Uuid,
+    pub name: String,
+    pub filter: ViewFilter,
+    pub shared: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateSavedView {
+    pub name: Option<String>,
+    pub filter: Option<ViewFilter>,
+    pub shared: Option<bool>,
+}
+
+impl ViewFilter {
+    pub fn validate(&self) -> Result<(), String> {
+        if let Some(ref period) = self.period {
+            let calendar = Calendar::default();
+            if calendar.resolve(period, calendar.today()).is_none() {
+                return Err("Invalid period".to_string());
+            }
+            if self.start_date.is_some() || self.end_date.is_some() {
+                return Err("Use either a period or fixed dates".to_string());
+            }
+        }
+        if let (Some(start), Some(end)) = (self.start_date, self.end_date) {
+            if start > end {
+                return Err("Invalid date range".to_string());
+            }
+        }
+        if let (Some(min), Some(max)) = (self.min_amount, self.max_amount) {
+            if min > max {
+                return Err("min_amount cannot exceed max_amount".to_string());
+            }
+        }
+        Ok(())
+    }
+
+    /// Fixed dates as saved, or the period resolved in the calendar of whoever runs the view
+    pub fn date_range(&self, calendar: &Calendar) -> (Option<NaiveDate>, Option<NaiveDate>) {
+        match self.period.as_deref().and_then(|p| calendar.resolve(p, calendar.today())) {
+            Some((start, end)) => (Some(start), Some(end)),
+            None => (self.start_date, self.end_date),
+        }
+    }
+}
+
+impl SavedView {
+    pub fn new(view: CreateSavedView) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            user_id: view.user_id,
+            ledger_id: view.ledger_id,
+            name: view.name,
+            filter: view.filter,
+            shared: view.shared,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Owners always see their views, everyone else only shared ones
+    pub fn is_visible_to(&self, user_id: Uuid) -> bool {
+        self.shared || self.user_id == user_id
+    }
+}
@@ -1,7 +1,10 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, FixedOffset, Utc, Weekday};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::i18n::Locale;
+use crate::utils::Calendar;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct User {
     pub id: Uuid,
@@ -10,6 +13,10 @@ pub struct User {
     pub password_hash: String,
     pub nickname: Option<String>,
     pub avatar: Option<String>,
+    pub locale: Option<Locale>,  // Overrides Accept-Language when set
+    pub utc_offset: Option<i32>,  // Minutes east of UTC, UTC when unset
+    pub week_start: Option<u32>,  // 1 = Monday ... 7 = Sunday, Monday when unset
+    pub month_start_day: Option<u32>,  // 1-28, the 1st when unset
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -25,6 +32,10 @@ pub struct CreateUser {
 pub struct UpdateUser {
     pub nickname: Option<String>,
     pub avatar: Option<String>,
+    pub locale: Option<Locale>,
+    pub utc_offset: Option<i32>,
+    pub week_start: Option<u32>,
+    pub month_start_day: Option<u32>,
 }
 
 impl User {
@@ -36,8 +47,27 @@ impl User {
             password_hash,
             nickname,
             avatar: None,
+            locale: None,
+            utc_offset: None,
+            week_start: None,
+            month_start_day: None,
             created_at: now,
             updated_at: now,
         }
     }
+
+    /// The user's calendar preferences, defaults filled in
+    pub fn calendar(&self) -> Calendar {
+        let mut calendar = Calendar::default();
+        if let Some(offset) = self.utc_offset.and_then(|minutes| FixedOffset::east_opt(minutes * 60)) {
+            calendar.utc_offset = offset;
+        }
+        if let Some(week_start) = self.week_start.filter(|day| (1..=7).contains(day)).and_then(|day| Weekday::try_from(day as u8 - 1).ok()) {
+            calendar.week_start = week_start;
+        }
+        if let Some(day) = self.month_start_day.filter(|day| (1..=28).contains(day)) {
+            calendar.month_start_day = day;
+        }
+        calendar
+    }
 }
@@ -0,0 +1,81 @@
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::RwLock;
+use uuid::Uuid;
+
+use crate::error::{AppError, AppResult};
+use crate::models::{Asset, CreateAsset, UpdateAsset};
+use crate::repositories::traits::AssetRepository;
+
+pub struct MemoryAssetRepository {
+    assets: RwLock<HashMap<Uuid, Asset>>,
+}
+
+impl MemoryAssetRepository {
+    pub fn new() -> Self {
+        Self {
+            assets: RwLock::new(HashMap::new()),
+        }
+    }
+}
+
+impl Default for MemoryAssetRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl AssetRepository for MemoryAssetRepository {
+    async fn create(&self, asset: CreateAsset) -> AppResult<Asset> {
+        let new_asset = Asset::new(asset);
+        let mut assets = self.assets.write().unwrap();
+        assets.insert(new_asset.id, new_asset.clone());
+        Ok(new_asset)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> AppResult<Option<Asset>> {
+        let assets = self.assets.read().unwrap();
+        Ok(assets.get(&id).cloned())
+    }
+
+    async fn find_by_user_id(&self, user_id: Uuid) -> AppResult<Vec<Asset>> {
+        let assets = self.assets.read().unwrap();
+        let mut result: Vec<Asset> = assets
+            .values()
+            .filter(|a| a.user_id == user_id)
+            .cloned()
+            .collect();
+        result.sort_by(|a, b| a.name.cmp(&b.name).then(a.created_at.cmp(&b.created_at)));
+        Ok(result)
+    }
+
+    async fn update(&self, id: Uuid, update: UpdateAsset) -> AppResult<Asset> {
+        let mut assets = self.assets.write().unwrap();
+        let asset = assets
+            .get_mut(&id)
+            .ok_or_else(|| AppError::NotFound("Asset not found".to_string()))?;
+
+        if let Some(name) = update.name {
+            asset.name = name;
+        }
+        if let Some(kind) = update.kind {
+            asset.kind = kind;
+        }
+        if let Some(currency) = update.currency {
+            asset.currency = currency;
+        }
+        if let Some(value) = update.value {
+            asset.value = value;
+        }
+        asset.updated_at = chrono::Utc::now();
+
+        Ok(asset.clone())
+    }
+
+    async fn delete(&self, id: Uuid) -> AppResult<()> {
+        let mut assets = self.assets.write().unwrap();
+        assets.remove(&id);
+        Ok(())
+    }
+}
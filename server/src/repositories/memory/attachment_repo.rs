@@ -0,0 +1,64 @@
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::RwLock;
+use uuid::Uuid;
+
+use crate::error::AppResult;
+use crate::models::{Attachment, CreateAttachment};
+use crate::repositories::traits::AttachmentRepository;
+
+pub struct MemoryAttachmentRepository {
+    attachments: RwLock<HashMap<Uuid, Attachment>>,
+}
+
+impl MemoryAttachmentRepository {
+    pub fn new() -> Self {
+        Self {
+            attachments: RwLock::new(HashMap::new()),
+        }
+    }
+}
+
+impl Default for MemoryAttachmentRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl AttachmentRepository for MemoryAttachmentRepository {
+    async fn create(&self, attachment: CreateAttachment) -> AppResult<Attachment> {
+        let new_attachment = Attachment::new(attachment);
+        let mut attachments = self.attachments.write().unwrap();
+        attachments.insert(new_attachment.id, new_attachment.clone());
+        Ok(new_attachment)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> AppResult<Option<Attachment>> {
+        let attachments = self.attachments.read().unwrap();
+        Ok(attachments.get(&id).cloned())
+    }
+
+    async fn find_by_bill_id(&self, bill_id: Uuid) -> AppResult<Vec<Attachment>> {
+        let attachments = self.attachments.read().unwrap();
+        let mut result: Vec<Attachment> = attachments
+            .values()
+            .filter(|a| a.bill_id == bill_id)
+            .cloned()
+            .collect();
+        result.sort_by_key(|a| a.created_at);
+        Ok(result)
+    }
+
+    async fn delete(&self, id: Uuid) -> AppResult<()> {
+        let mut attachments = self.attachments.write().unwrap();
+        attachments.remove(&id);
+        Ok(())
+    }
+
+    async fn delete_by_bill_id(&self, bill_id: Uuid) -> AppResult<()> {
+        let mut attachments = self.attachments.write().unwrap();
+        attachments.retain(|_, a| a.bill_id != bill_id);
+        Ok(())
+    }
+}
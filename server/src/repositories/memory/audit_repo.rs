@@ -0,0 +1,65 @@
+use async_trait::async_trait;
+use std::sync::RwLock;
+use uuid::Uuid;
+
+use crate::error::AppResult;
+use crate::models::{AuditEntityType, AuditEvent, CreateAuditEvent};
+use crate::repositories::traits::AuditRepository;
+
+pub struct MemoryAuditRepository {
+    events: RwLock<Vec<AuditEvent>>,
+}
+
+impl MemoryAuditRepository {
+    pub fn new() -> Self {
+        Self {
+            events: RwLock::new(Vec::new()),
+        }
+    }
+}
+
+impl Default for MemoryAuditRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl AuditRepository for MemoryAuditRepository {
+    async fn create(&self, event: CreateAuditEvent) -> AppResult<AuditEvent> {
+        let new_event = AuditEvent::new(event);
+        let mut events = self.events.write().unwrap();
+        events.push(new_event.clone());
+        Ok(new_event)
+    }
+
+    async fn find_by_entity(&self, entity_type: AuditEntityType, entity_id: Uuid) -> AppResult<Vec<AuditEvent>> {
+        let events = self.events.read().unwrap();
+        // Events are appended in order, so they are already oldest first
+        Ok(events
+            .iter()
+            .filter(|e| e.entity_type == entity_type && e.entity_id == entity_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn find_by_group_id(&self, group_id: Uuid, page: u32, page_size: u32) -> AppResult<(Vec<AuditEvent>, u64)> {
+        let events = self.events.read().unwrap();
+        let matching: Vec<&AuditEvent> = events
+            .iter()
+            .rev()
+            .filter(|e| e.group_id == Some(group_id))
+            .collect();
+
+        let total = matching.len() as u64;
+        let offset = ((page.max(1) - 1) * page_size) as usize;
+        let items = matching
+            .into_iter()
+            .skip(offset)
+            .take(page_size as usize)
+            .cloned()
+            .collect();
+
+        Ok((items, total))
+    }
+}
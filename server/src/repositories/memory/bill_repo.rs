@@ -1,23 +1,111 @@
 use async_trait::async_trait;
-use chrono::{Datelike, NaiveDate, Weekday};
-use std::collections::HashMap;
+use chrono::{DateTime, NaiveDate, Timelike, Utc};
+use std::collections::{HashMap, HashSet};
 use std::sync::RwLock;
 use uuid::Uuid;
 
 use crate::error::{AppError, AppResult};
-use crate::models::{Bill, BillType, CreateBill, UpdateBill};
-use crate::repositories::traits::{BillFilter, BillRepository, BillStatistics, CategoryStatistics, DailyStatistics, TrendStatistics};
+use crate::models::{Bill, BillSort, BillType, CreateBill, UpdateBill};
+use crate::repositories::traits::{
+    BillFilter, BillRepository, BillStatistics, CategoryStatistics, DailyStatistics,
+    HourlyStatistics, MemberCategoryStatistics, MemberStatistics, TrendStatistics,
+};
+use crate::utils::Calendar;
 
 pub struct MemoryBillRepository {
     bills: RwLock<HashMap<Uuid, Bill>>,
+    /// Inverted index of the notes' lowercase characters and character pairs for keyword search
+    note_index: RwLock<HashMap<String, HashSet<Uuid>>>,
 }
 
 impl MemoryBillRepository {
     pub fn new() -> Self {
         Self {
             bills: RwLock::new(HashMap::new()),
+            note_index: RwLock::new(HashMap::new()),
         }
     }
+
+    /// Always taken after the `bills` lock
+    fn reindex(&self, id: Uuid, old_note: Option<&str>, new_note: Option<&str>) {
+        let mut index = self.note_index.write().unwrap();
+        for gram in old_note.map(note_grams).unwrap_or_default() {
+            if let Some(ids) = index.get_mut(&gram) {
+                ids.remove(&id);
+                if ids.is_empty() {
+                    index.remove(&gram);
+                }
+            }
+        }
+        for gram in new_note.map(note_grams).unwrap_or_default() {
+            index.entry(gram).or_default().insert(id);
+        }
+    }
+
+    /// Bills whose note contains `word` anywhere, like the MySQL ngram index.
+    /// The index narrows the bills down to those with all of the word's pairs, the note itself settles it.
+    fn search_notes(&self, bills: &HashMap<Uuid, Bill>, word: &str) -> HashSet<Uuid> {
+        let index = self.note_index.read().unwrap();
+        let mut grams = word_grams(word).into_iter();
+        let Some(first) = grams.next() else {
+            return HashSet::new();
+        };
+        let mut ids = index.get(&first).cloned().unwrap_or_default();
+        for gram in grams {
+            let Some(found) = index.get(&gram) else {
+                return HashSet::new();
+            };
+            ids.retain(|id| found.contains(id));
+        }
+        ids.retain(|id| {
+            bills
+                .get(id)
+                .and_then(|b| b.note.as_deref())
+                .is_some_and(|n| n.to_lowercase().contains(word))
+        });
+        ids
+    }
+}
+
+/// Every character and pair of neighbouring characters of a note, lowercased
+fn note_grams(note: &str) -> HashSet<String> {
+    let chars: Vec<char> = note.to_lowercase().chars().collect();
+    let pairs = chars.windows(2).map(|pair| pair.iter().collect());
+    chars.iter().map(|c| c.to_string()).chain(pairs).collect()
+}
+
+/// The grams a note needs to contain `word`: its character pairs, or the character of a one-character word
+fn word_grams(word: &str) -> HashSet<String> {
+    let chars: Vec<char> = word.chars().collect();
+    match chars.len() {
+        1 => chars.iter().map(|c| c.to_string()).collect(),
+        _ => chars.windows(2).map(|pair| pair.iter().collect()).collect(),
+    }
+}
+
+fn apply_update(bill: &mut Bill, update: UpdateBill) {
+    if let Some(ledger_id) = update.ledger_id {
+        bill.ledger_id = ledger_id;
+    }
+    if let Some(cat_id) = update.category_id {
+        bill.category_id = cat_id;
+    }
+    if let Some(t) = update.bill_type {
+        bill.bill_type = t;
+    }
+    if let Some(amount) = update.amount {
+        bill.amount = amount;
+    }
+    if update.note.is_some() {
+        bill.note = update.note;
+    }
+    if let Some(date) = update.bill_date {
+        bill.bill_date = date;
+    }
+    if let Some(time) = update.bill_time {
+        bill.bill_time = time;
+    }
+    bill.updated_at = chrono::Utc::now();
 }
 
 impl Default for MemoryBillRepository {
@@ -37,23 +125,26 @@ impl BillRepository for MemoryBillRepository {
             bill.amount,
             bill.note,
             bill.bill_date,
+            bill.bill_time,
         );
         let mut bills = self.bills.write().unwrap();
         bills.insert(new_bill.id, new_bill.clone());
+        self.reindex(new_bill.id, None, new_bill.note.as_deref());
         Ok(new_bill)
     }
 
     async fn find_by_id(&self, id: Uuid) -> AppResult<Option<Bill>> {
         let bills = self.bills.read().unwrap();
-        Ok(bills.get(&id).cloned())
+        Ok(bills.get(&id).filter(|b| b.deleted_at.is_none()).cloned())
     }
 
     async fn find_by_filter(&self, filter: BillFilter) -> AppResult<(Vec<Bill>, u64)> {
         let bills = self.bills.read().unwrap();
+        let note_matches: Vec<HashSet<Uuid>> = filter.search.iter().map(|term| self.search_notes(&bills, &term.word)).collect();
         let mut filtered: Vec<Bill> = bills
             .values()
             .filter(|b| {
-                if b.ledger_id != filter.ledger_id {
+                if b.ledger_id != filter.ledger_id || b.deleted_at.is_some() {
                     return false;
                 }
                 if let Some(start) = filter.start_date {
@@ -71,17 +162,40 @@ impl BillRepository for MemoryBillRepository {
                         return false;
                     }
                 }
-                if let Some(cat_id) = filter.category_id {
-                    if b.category_id != cat_id {
+                if !filter.category_ids.is_empty() && !filter.category_ids.contains(&b.category_id) {
+                    return false;
+                }
+                if !filter.user_ids.is_empty() && !filter.user_ids.contains(&b.user_id) {
+                    return false;
+                }
+                if filter.min_amount.is_some_and(|min| b.amount < min) || filter.max_amount.is_some_and(|max| b.amount > max) {
+                    return false;
+                }
+                if let Some(has_note) = filter.has_note {
+                    if b.note.as_deref().is_some_and(|n| !n.trim().is_empty()) != has_note {
                         return false;
                     }
                 }
-                true
+                filter
+                    .search
+                    .iter()
+                    .zip(&note_matches)
+                    .all(|(term, ids)| term.category_ids.contains(&b.category_id) || ids.contains(&b.id))
             })
             .cloned()
             .collect();
 
-        filtered.sort_by(|a, b| b.bill_date.cmp(&a.bill_date));
+        filtered.sort_by(|a, b| {
+            let newest = b.bill_date.cmp(&a.bill_date).then(b.created_at.cmp(&a.created_at));
+            match filter.sort {
+                BillSort::DateDesc => newest,
+                BillSort::DateAsc => newest.reverse(),
+                BillSort::AmountDesc => b.amount.total_cmp(&a.amount).then(newest),
+                BillSort::AmountAsc => a.amount.total_cmp(&b.amount).then(newest),
+                BillSort::CreatedDesc => b.created_at.cmp(&a.created_at),
+                BillSort::CreatedAsc => a.created_at.cmp(&b.created_at),
+            }
+        });
 
         let total = filtered.len() as u64;
         let start = ((filter.page - 1) * filter.page_size) as usize;
@@ -96,40 +210,149 @@ impl BillRepository for MemoryBillRepository {
 
     async fn update(&self, id: Uuid, update: UpdateBill) -> AppResult<Bill> {
         let mut bills = self.bills.write().unwrap();
-        let bill = bills.get_mut(&id).ok_or_else(|| AppError::NotFound("Bill not found".to_string()))?;
+        let bill = bills
+            .get_mut(&id)
+            .filter(|b| b.deleted_at.is_none())
+            .ok_or_else(|| AppError::NotFound("Bill not found".to_string()))?;
+
+        let old_note = bill.note.clone();
+        apply_update(bill, update);
+        self.reindex(id, old_note.as_deref(), bill.note.as_deref());
+        Ok(bill.clone())
+    }
 
-        if let Some(cat_id) = update.category_id {
-            bill.category_id = cat_id;
+    async fn delete(&self, id: Uuid) -> AppResult<()> {
+        let mut bills = self.bills.write().unwrap();
+        if let Some(bill) = bills.get_mut(&id) {
+            bill.deleted_at.get_or_insert_with(Utc::now);
         }
-        if let Some(t) = update.bill_type {
-            bill.bill_type = t;
+        Ok(())
+    }
+
+    async fn create_many(&self, new_bills: Vec<CreateBill>) -> AppResult<Vec<Bill>> {
+        let mut bills = self.bills.write().unwrap();
+        let created: Vec<Bill> = new_bills
+            .into_iter()
+            .map(|bill| {
+                Bill::new(
+                    bill.ledger_id,
+                    bill.category_id,
+                    bill.user_id,
+                    bill.bill_type,
+                    bill.amount,
+                    bill.note,
+                    bill.bill_date,
+                    bill.bill_time,
+                )
+            })
+            .collect();
+        for bill in &created {
+            bills.insert(bill.id, bill.clone());
+            self.reindex(bill.id, None, bill.note.as_deref());
         }
-        if let Some(amount) = update.amount {
-            bill.amount = amount;
+        Ok(created)
+    }
+
+    async fn update_many(&self, updates: Vec<(Uuid, UpdateBill)>) -> AppResult<Vec<Bill>> {
+        let mut bills = self.bills.write().unwrap();
+        // Check every bill before touching any of them
+        if updates.iter().any(|(id, _)| bills.get(id).is_none_or(|b| b.deleted_at.is_some())) {
+            return Err(AppError::NotFound("Bill not found".to_string()));
         }
-        if update.note.is_some() {
-            bill.note = update.note;
+
+        let mut updated = Vec::new();
+        for (id, update) in updates {
+            let bill = bills.get_mut(&id).unwrap();
+            let old_note = bill.note.clone();
+            apply_update(bill, update);
+            self.reindex(id, old_note.as_deref(), bill.note.as_deref());
+            updated.push(bill.clone());
         }
-        if let Some(date) = update.bill_date {
-            bill.bill_date = date;
+        Ok(updated)
+    }
+
+    async fn delete_many(&self, ids: &[Uuid]) -> AppResult<()> {
+        let mut bills = self.bills.write().unwrap();
+        let now = Utc::now();
+        for id in ids {
+            if let Some(bill) = bills.get_mut(id) {
+                bill.deleted_at.get_or_insert(now);
+            }
         }
-        bill.updated_at = chrono::Utc::now();
+        Ok(())
+    }
 
+    async fn find_trashed_by_id(&self, id: Uuid) -> AppResult<Option<Bill>> {
+        let bills = self.bills.read().unwrap();
+        Ok(bills.get(&id).filter(|b| b.deleted_at.is_some()).cloned())
+    }
+
+    async fn find_trashed_by_user_id(&self, user_id: Uuid) -> AppResult<Vec<Bill>> {
+        let bills = self.bills.read().unwrap();
+        let mut result: Vec<Bill> = bills
+            .values()
+            .filter(|b| b.user_id == user_id && b.deleted_at.is_some())
+            .cloned()
+            .collect();
+        result.sort_by_key(|b| std::cmp::Reverse(b.deleted_at));
+        Ok(result)
+    }
+
+    async fn restore(&self, id: Uuid) -> AppResult<Bill> {
+        let mut bills = self.bills.write().unwrap();
+        let bill = bills
+            .get_mut(&id)
+            .filter(|b| b.deleted_at.is_some())
+            .ok_or_else(|| AppError::NotFound("Bill not found in trash".to_string()))?;
+        bill.deleted_at = None;
+        bill.updated_at = Utc::now();
         Ok(bill.clone())
     }
 
-    async fn delete(&self, id: Uuid) -> AppResult<()> {
+    async fn purge(&self, id: Uuid) -> AppResult<()> {
         let mut bills = self.bills.write().unwrap();
-        bills.remove(&id);
+        if let Some(bill) = bills.remove(&id) {
+            self.reindex(id, bill.note.as_deref(), None);
+        }
         Ok(())
     }
 
+    async fn find_purgeable(&self, deleted_before: DateTime<Utc>) -> AppResult<Vec<Bill>> {
+        let bills = self.bills.read().unwrap();
+        Ok(bills
+            .values()
+            .filter(|b| b.deleted_at.is_some_and(|d| d < deleted_before))
+            .cloned()
+            .collect())
+    }
+
+    async fn find_all_ids_by_ledger_id(&self, ledger_id: Uuid) -> AppResult<Vec<Uuid>> {
+        let bills = self.bills.read().unwrap();
+        Ok(bills.values().filter(|b| b.ledger_id == ledger_id).map(|b| b.id).collect())
+    }
+
+    async fn count_by_category_id(&self, category_id: Uuid) -> AppResult<u64> {
+        let bills = self.bills.read().unwrap();
+        Ok(bills.values().filter(|b| b.category_id == category_id).count() as u64)
+    }
+
+    async fn reassign_category(&self, from_category_id: Uuid, to_category_id: Uuid) -> AppResult<u64> {
+        let mut bills = self.bills.write().unwrap();
+        let mut moved = 0;
+        for bill in bills.values_mut().filter(|b| b.category_id == from_category_id) {
+            bill.category_id = to_category_id;
+            bill.updated_at = Utc::now();
+            moved += 1;
+        }
+        Ok(moved)
+    }
+
     async fn get_statistics(&self, ledger_id: Uuid, start_date: Option<NaiveDate>, end_date: Option<NaiveDate>) -> AppResult<BillStatistics> {
         let bills = self.bills.read().unwrap();
         let filtered: Vec<&Bill> = bills
             .values()
             .filter(|b| {
-                if b.ledger_id != ledger_id {
+                if b.ledger_id != ledger_id || b.deleted_at.is_some() {
                     return false;
                 }
                 if let Some(start) = start_date {
@@ -163,7 +386,7 @@ impl BillRepository for MemoryBillRepository {
         let filtered: Vec<&Bill> = bills
             .values()
             .filter(|b| {
-                if b.ledger_id != ledger_id || b.bill_type != target_type {
+                if b.ledger_id != ledger_id || b.bill_type != target_type || b.deleted_at.is_some() {
                     return false;
                 }
                 if let Some(start) = start_date {
@@ -195,7 +418,7 @@ impl BillRepository for MemoryBillRepository {
                 category_id: cat_id,
                 category_name: "Category".to_string(),
                 category_icon: None,
-                bill_type: target_type.clone(),
+                bill_type: target_type,
                 amount,
                 count,
                 percentage: if total > 0.0 { (amount / total) * 100.0 } else { 0.0 },
@@ -206,6 +429,32 @@ impl BillRepository for MemoryBillRepository {
         Ok(result)
     }
 
+    async fn get_member_statistics(&self, ledger_id: Uuid, start_date: Option<NaiveDate>, end_date: Option<NaiveDate>) -> AppResult<Vec<MemberStatistics>> {
+        let bills = self.bills.read().unwrap();
+
+        let mut sums: HashMap<(Uuid, Uuid, BillType), (f64, u32)> = HashMap::new();
+        for bill in bills.values() {
+            if bill.ledger_id != ledger_id
+                || bill.deleted_at.is_some()
+                || start_date.is_some_and(|start| bill.bill_date < start)
+                || end_date.is_some_and(|end| bill.bill_date > end)
+            {
+                continue;
+            }
+            let entry = sums.entry((bill.user_id, bill.category_id, bill.bill_type)).or_insert((0.0, 0));
+            entry.0 += bill.amount;
+            entry.1 += 1;
+        }
+
+        let rows = sums
+            .into_iter()
+            .map(|((user_id, category_id, bill_type), (amount, count))| {
+                (user_id, MemberCategoryStatistics { category_id, bill_type, amount, count })
+            })
+            .collect();
+        Ok(MemberStatistics::from_categories(rows))
+    }
+
     async fn get_daily_statistics(&self, ledger_id: Uuid, start_date: NaiveDate, end_date: NaiveDate) -> AppResult<Vec<DailyStatistics>> {
         let bills = self.bills.read().unwrap();
 
@@ -220,7 +469,7 @@ impl BillRepository for MemoryBillRepository {
 
         // Aggregate bills
         for bill in bills.values() {
-            if bill.ledger_id != ledger_id {
+            if bill.ledger_id != ledger_id || bill.deleted_at.is_some() {
                 continue;
             }
             if bill.bill_date < start_date || bill.bill_date > end_date {
@@ -239,31 +488,22 @@ impl BillRepository for MemoryBillRepository {
             .map(|(date, (income, expense))| DailyStatistics { date, income, expense })
             .collect();
 
-        result.sort_by(|a, b| a.date.cmp(&b.date));
+        result.sort_by_key(|d| d.date);
         Ok(result)
     }
 
-    async fn get_trend_statistics(&self, ledger_id: Uuid, start_date: NaiveDate, end_date: NaiveDate, group_by: &str) -> AppResult<Vec<TrendStatistics>> {
+    async fn get_trend_statistics(&self, ledger_id: Uuid, start_date: NaiveDate, end_date: NaiveDate, group_by: &str, calendar: &Calendar) -> AppResult<Vec<TrendStatistics>> {
         let bills = self.bills.read().unwrap();
 
         let filtered: Vec<&Bill> = bills
             .values()
-            .filter(|b| b.ledger_id == ledger_id && b.bill_date >= start_date && b.bill_date <= end_date)
+            .filter(|b| b.ledger_id == ledger_id && b.deleted_at.is_none() && b.bill_date >= start_date && b.bill_date <= end_date)
             .collect();
 
         let mut trend_data: HashMap<String, (f64, f64)> = HashMap::new();
 
         for bill in filtered {
-            let period = match group_by {
-                "day" => bill.bill_date.format("%Y-%m-%d").to_string(),
-                "week" => {
-                    let iso_week = bill.bill_date.iso_week();
-                    format!("{}-W{:02}", iso_week.year(), iso_week.week())
-                }
-                "month" => bill.bill_date.format("%Y-%m").to_string(),
-                "year" => bill.bill_date.format("%Y").to_string(),
-                _ => bill.bill_date.format("%Y-%m").to_string(),
-            };
+            let period = calendar.bucket(group_by, bill.bill_date);
 
             let entry = trend_data.entry(period).or_insert((0.0, 0.0));
             match bill.bill_type {
@@ -285,4 +525,105 @@ impl BillRepository for MemoryBillRepository {
         result.sort_by(|a, b| a.period.cmp(&b.period));
         Ok(result)
     }
+
+    async fn get_hourly_statistics(&self, ledger_id: Uuid, start_date: NaiveDate, end_date: NaiveDate, bill_type: BillType) -> AppResult<Vec<HourlyStatistics>> {
+        let bills = self.bills.read().unwrap();
+
+        let mut hourly_data: HashMap<(NaiveDate, Option<u32>), (f64, u32)> = HashMap::new();
+        for bill in bills.values().filter(|b| {
+            b.ledger_id == ledger_id && b.deleted_at.is_none() && b.bill_type == bill_type && b.bill_date >= start_date && b.bill_date <= end_date
+        }) {
+            let entry = hourly_data.entry((bill.bill_date, bill.bill_time.map(|t| t.hour()))).or_default();
+            entry.0 += bill.amount;
+            entry.1 += 1;
+        }
+
+        let mut result: Vec<HourlyStatistics> = hourly_data
+            .into_iter()
+            .map(|((date, hour), (amount, count))| HourlyStatistics { date, hour, amount, count })
+            .collect();
+
+        result.sort_by_key(|s| (s.date, s.hour));
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::SearchTerm;
+
+    fn term(word: &str, category_ids: &[Uuid]) -> SearchTerm {
+        SearchTerm {
+            word: word.to_string(),
+            category_ids: category_ids.to_vec(),
+        }
+    }
+
+    async fn search(repo: &MemoryBillRepository, ledger_id: Uuid, search: Vec<SearchTerm>) -> Vec<String> {
+        let filter = BillFilter {
+            ledger_id,
+            search,
+            ..Default::default()
+        };
+        let mut notes: Vec<String> = repo.find_by_filter(filter).await.unwrap().0.into_iter().filter_map(|b| b.note).collect();
+        notes.sort();
+        notes
+    }
+
+    #[tokio::test]
+    async fn search_matches_words_inside_notes_or_by_category() {
+        let repo = MemoryBillRepository::new();
+        let ledger_id = Uuid::new_v4();
+        let food = Uuid::new_v4();
+        let transport = Uuid::new_v4();
+        let mut ids = Vec::new();
+        for (category_id, note) in [(food, "Starbucks Coffee"), (food, "周末聚餐火锅"), (transport, "Taxi")] {
+            let bill = repo
+                .create(CreateBill {
+                    ledger_id,
+                    category_id,
+                    user_id: Uuid::nil(),
+                    bill_type: BillType::Expense,
+                    amount: 10.0,
+                    note: Some(note.to_string()),
+                    bill_date: NaiveDate::from_ymd_opt(2026, 10, 18).unwrap(),
+                    bill_time: None,
+                })
+                .await
+                .unwrap();
+            ids.push(bill.id);
+        }
+
+        assert_eq!(search(&repo, ledger_id, vec![term("coffee", &[])]).await, ["Starbucks Coffee"]);
+        assert_eq!(search(&repo, ledger_id, vec![term("star", &[])]).await, ["Starbucks Coffee"]);
+        assert_eq!(search(&repo, ledger_id, vec![term("聚餐", &[]), term("火锅", &[])]).await, ["周末聚餐火锅"]);
+        assert_eq!(search(&repo, ledger_id, vec![term("x", &[])]).await, ["Taxi"]);
+        // All the pairs are there but not in this order
+        assert!(search(&repo, ledger_id, vec![term("coffeee", &[])]).await.is_empty());
+        assert!(search(&repo, ledger_id, vec![term("聚餐", &[]), term("taxi", &[])]).await.is_empty());
+
+        // A category whose name matched finds its bills without the word in the note
+        assert_eq!(
+            search(&repo, ledger_id, vec![term("food", &[food])]).await,
+            ["Starbucks Coffee", "周末聚餐火锅"]
+        );
+        assert_eq!(search(&repo, ledger_id, vec![term("food", &[food]), term("火锅", &[])]).await, ["周末聚餐火锅"]);
+
+        // The index follows changed and purged notes
+        let update = UpdateBill {
+            ledger_id: None,
+            category_id: None,
+            bill_type: None,
+            amount: None,
+            note: Some("Bus".to_string()),
+            bill_date: None,
+            bill_time: None,
+        };
+        repo.update(ids[2], update).await.unwrap();
+        assert!(search(&repo, ledger_id, vec![term("taxi", &[])]).await.is_empty());
+        assert_eq!(search(&repo, ledger_id, vec![term("bus", &[])]).await, ["Bus"]);
+        repo.purge(ids[0]).await.unwrap();
+        assert!(search(&repo, ledger_id, vec![term("coffee", &[])]).await.is_empty());
+    }
 }
@@ -1,4 +1,5 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use std::collections::HashMap;
 use std::sync::RwLock;
 use uuid::Uuid;
@@ -46,7 +47,12 @@ impl CategoryRepository for MemoryCategoryRepository {
         Ok(categories.get(&id).cloned())
     }
 
-    async fn find_by_ledger_id(&self, ledger_id: Option<Uuid>, category_type: Option<BillType>) -> AppResult<Vec<Category>> {
+    async fn find_by_ledger_id(
+        &self,
+        ledger_id: Option<Uuid>,
+        category_type: Option<BillType>,
+        include_archived: bool,
+    ) -> AppResult<Vec<Category>> {
         let categories = self.categories.read().unwrap();
         let mut result: Vec<Category> = categories
             .values()
@@ -54,17 +60,21 @@ impl CategoryRepository for MemoryCategoryRepository {
                 // Show system categories (ledger_id is None) or custom categories for specific ledger
                 let ledger_match = c.ledger_id.is_none() || c.ledger_id == ledger_id;
                 let type_match = category_type.as_ref().map(|t| &c.category_type == t).unwrap_or(true);
-                ledger_match && type_match
+                let archive_match = include_archived || c.archived_at.is_none();
+                ledger_match && type_match && archive_match && c.deleted_at.is_none()
             })
             .cloned()
             .collect();
-        result.sort_by(|a, b| a.sort_order.cmp(&b.sort_order));
+        result.sort_by_key(|c| c.sort_order);
         Ok(result)
     }
 
     async fn update(&self, id: Uuid, name: Option<String>, icon: Option<String>, sort_order: Option<i32>) -> AppResult<Category> {
         let mut categories = self.categories.write().unwrap();
-        let category = categories.get_mut(&id).ok_or_else(|| AppError::NotFound("Category not found".to_string()))?;
+        let category = categories
+            .get_mut(&id)
+            .filter(|c| c.deleted_at.is_none())
+            .ok_or_else(|| AppError::NotFound("Category not found".to_string()))?;
 
         if let Some(n) = name {
             category.name = n;
@@ -81,7 +91,9 @@ impl CategoryRepository for MemoryCategoryRepository {
 
     async fn delete(&self, id: Uuid) -> AppResult<()> {
         let mut categories = self.categories.write().unwrap();
-        categories.remove(&id);
+        if let Some(category) = categories.get_mut(&id) {
+            category.deleted_at.get_or_insert_with(Utc::now);
+        }
         Ok(())
     }
 
@@ -124,4 +136,75 @@ impl CategoryRepository for MemoryCategoryRepository {
 
         Ok(())
     }
+
+    async fn set_parent(&self, id: Uuid, parent_id: Option<Uuid>) -> AppResult<Category> {
+        let mut categories = self.categories.write().unwrap();
+
+        // Walk up from the new parent, reaching `id` would close a loop
+        let mut ancestor = parent_id;
+        while let Some(ancestor_id) = ancestor {
+            if ancestor_id == id {
+                return Err(AppError::Validation("Category hierarchy cannot contain cycles".to_string()));
+            }
+            ancestor = categories.get(&ancestor_id).and_then(|c| c.parent_id);
+        }
+
+        let category = categories
+            .get_mut(&id)
+            .filter(|c| c.deleted_at.is_none())
+            .ok_or_else(|| AppError::NotFound("Category not found".to_string()))?;
+        category.parent_id = parent_id;
+        Ok(category.clone())
+    }
+
+    async fn set_archived(&self, id: Uuid, archived: bool) -> AppResult<Category> {
+        let mut categories = self.categories.write().unwrap();
+        let category = categories
+            .get_mut(&id)
+            .filter(|c| c.deleted_at.is_none())
+            .ok_or_else(|| AppError::NotFound("Category not found".to_string()))?;
+        if archived {
+            category.archived_at.get_or_insert_with(Utc::now);
+        } else {
+            category.archived_at = None;
+        }
+        Ok(category.clone())
+    }
+
+    async fn find_trashed(&self, ledger_ids: &[Uuid]) -> AppResult<Vec<Category>> {
+        let categories = self.categories.read().unwrap();
+        Ok(categories
+            .values()
+            .filter(|c| {
+                c.deleted_at.is_some()
+                    && c.ledger_id.is_some_and(|id| ledger_ids.contains(&id))
+            })
+            .cloned()
+            .collect())
+    }
+
+    async fn restore(&self, id: Uuid) -> AppResult<Category> {
+        let mut categories = self.categories.write().unwrap();
+        let category = categories
+            .get_mut(&id)
+            .filter(|c| c.deleted_at.is_some())
+            .ok_or_else(|| AppError::NotFound("Category not found in trash".to_string()))?;
+        category.deleted_at = None;
+        Ok(category.clone())
+    }
+
+    async fn purge(&self, id: Uuid) -> AppResult<()> {
+        let mut categories = self.categories.write().unwrap();
+        categories.remove(&id);
+        Ok(())
+    }
+
+    async fn find_purgeable(&self, deleted_before: DateTime<Utc>) -> AppResult<Vec<Category>> {
+        let categories = self.categories.read().unwrap();
+        Ok(categories
+            .values()
+            .filter(|c| c.deleted_at.is_some_and(|d| d < deleted_before))
+            .cloned()
+            .collect())
+    }
 }
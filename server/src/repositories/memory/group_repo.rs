@@ -5,12 +5,13 @@ use std::sync::RwLock;
 use uuid::Uuid;
 
 use crate::error::{AppError, AppResult};
-use crate::models::{CreateGroup, Group, GroupMember, GroupRole};
+use crate::models::{CreateGroup, Group, GroupMember, GroupPolicy, GroupRole};
 use crate::repositories::traits::GroupRepository;
 
 pub struct MemoryGroupRepository {
     groups: RwLock<HashMap<Uuid, Group>>,
     members: RwLock<HashMap<Uuid, GroupMember>>,
+    policies: RwLock<HashMap<Uuid, GroupPolicy>>,
 }
 
 impl MemoryGroupRepository {
@@ -18,6 +19,7 @@ impl MemoryGroupRepository {
         Self {
             groups: RwLock::new(HashMap::new()),
             members: RwLock::new(HashMap::new()),
+            policies: RwLock::new(HashMap::new()),
         }
     }
 }
@@ -91,6 +93,7 @@ impl GroupRepository for MemoryGroupRepository {
             let mut members = self.members.write().unwrap();
             members.retain(|_, m| m.group_id != id);
         }
+        self.policies.write().unwrap().remove(&id);
         let mut groups = self.groups.write().unwrap();
         groups.remove(&id);
         Ok(())
@@ -150,4 +153,15 @@ impl GroupRepository for MemoryGroupRepository {
             .find(|m| m.group_id == group_id && m.user_id == user_id)
             .cloned())
     }
+
+    async fn get_policy(&self, group_id: Uuid) -> AppResult<Option<GroupPolicy>> {
+        let policies = self.policies.read().unwrap();
+        Ok(policies.get(&group_id).cloned())
+    }
+
+    async fn save_policy(&self, policy: GroupPolicy) -> AppResult<GroupPolicy> {
+        let mut policies = self.policies.write().unwrap();
+        policies.insert(policy.group_id, policy.clone());
+        Ok(policy)
+    }
 }
@@ -1,4 +1,5 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use std::collections::HashMap;
 use std::sync::RwLock;
 use uuid::Uuid;
@@ -41,14 +42,14 @@ impl LedgerRepository for MemoryLedgerRepository {
 
     async fn find_by_id(&self, id: Uuid) -> AppResult<Option<Ledger>> {
         let ledgers = self.ledgers.read().unwrap();
-        Ok(ledgers.get(&id).cloned())
+        Ok(ledgers.get(&id).filter(|l| l.deleted_at.is_none()).cloned())
     }
 
     async fn find_by_user_id(&self, user_id: Uuid) -> AppResult<Vec<Ledger>> {
         let ledgers = self.ledgers.read().unwrap();
         Ok(ledgers
             .values()
-            .filter(|l| l.user_id == Some(user_id))
+            .filter(|l| l.user_id == Some(user_id) && l.deleted_at.is_none())
             .cloned()
             .collect())
     }
@@ -57,14 +58,17 @@ impl LedgerRepository for MemoryLedgerRepository {
         let ledgers = self.ledgers.read().unwrap();
         Ok(ledgers
             .values()
-            .filter(|l| l.group_id == Some(group_id))
+            .filter(|l| l.group_id == Some(group_id) && l.deleted_at.is_none())
             .cloned()
             .collect())
     }
 
     async fn update(&self, id: Uuid, name: Option<String>, description: Option<String>) -> AppResult<Ledger> {
         let mut ledgers = self.ledgers.write().unwrap();
-        let ledger = ledgers.get_mut(&id).ok_or_else(|| AppError::NotFound("Ledger not found".to_string()))?;
+        let ledger = ledgers
+            .get_mut(&id)
+            .filter(|l| l.deleted_at.is_none())
+            .ok_or_else(|| AppError::NotFound("Ledger not found".to_string()))?;
 
         if let Some(n) = name {
             ledger.name = n;
@@ -72,14 +76,56 @@ impl LedgerRepository for MemoryLedgerRepository {
         if let Some(d) = description {
             ledger.description = Some(d);
         }
-        ledger.updated_at = chrono::Utc::now();
+        ledger.updated_at = Utc::now();
 
         Ok(ledger.clone())
     }
 
     async fn delete(&self, id: Uuid) -> AppResult<()> {
+        let mut ledgers = self.ledgers.write().unwrap();
+        if let Some(ledger) = ledgers.get_mut(&id) {
+            ledger.deleted_at.get_or_insert_with(Utc::now);
+        }
+        Ok(())
+    }
+
+    async fn find_trashed_by_id(&self, id: Uuid) -> AppResult<Option<Ledger>> {
+        let ledgers = self.ledgers.read().unwrap();
+        Ok(ledgers.get(&id).filter(|l| l.deleted_at.is_some()).cloned())
+    }
+
+    async fn find_trashed_by_user_id(&self, user_id: Uuid) -> AppResult<Vec<Ledger>> {
+        let ledgers = self.ledgers.read().unwrap();
+        Ok(ledgers
+            .values()
+            .filter(|l| l.user_id == Some(user_id) && l.deleted_at.is_some())
+            .cloned()
+            .collect())
+    }
+
+    async fn restore(&self, id: Uuid) -> AppResult<Ledger> {
+        let mut ledgers = self.ledgers.write().unwrap();
+        let ledger = ledgers
+            .get_mut(&id)
+            .filter(|l| l.deleted_at.is_some())
+            .ok_or_else(|| AppError::NotFound("Ledger not found in trash".to_string()))?;
+        ledger.deleted_at = None;
+        ledger.updated_at = Utc::now();
+        Ok(ledger.clone())
+    }
+
+    async fn purge(&self, id: Uuid) -> AppResult<()> {
         let mut ledgers = self.ledgers.write().unwrap();
         ledgers.remove(&id);
         Ok(())
     }
+
+    async fn find_purgeable(&self, deleted_before: DateTime<Utc>) -> AppResult<Vec<Ledger>> {
+        let ledgers = self.ledgers.read().unwrap();
+        Ok(ledgers
+            .values()
+            .filter(|l| l.deleted_at.is_some_and(|d| d < deleted_before))
+            .cloned()
+            .collect())
+    }
 }
@@ -3,9 +3,23 @@ pub mod ledger_repo;
 pub mod bill_repo;
 pub mod category_repo;
 pub mod group_repo;
+pub mod attachment_repo;
+pub mod audit_repo;
+pub mod rule_repo;
+pub mod saved_view_repo;
+pub mod notification_repo;
+pub mod asset_repo;
+pub mod net_worth_repo;
 
 pub use user_repo::MemoryUserRepository;
 pub use ledger_repo::MemoryLedgerRepository;
 pub use bill_repo::MemoryBillRepository;
 pub use category_repo::MemoryCategoryRepository;
 pub use group_repo::MemoryGroupRepository;
+pub use attachment_repo::MemoryAttachmentRepository;
+pub use audit_repo::MemoryAuditRepository;
+pub use rule_repo::MemoryRuleRepository;
+pub use saved_view_repo::MemorySavedViewRepository;
+pub use notification_repo::MemoryNotificationRepository;
+pub use asset_repo::MemoryAssetRepository;
+pub use net_worth_repo::MemoryNetWorthRepository;
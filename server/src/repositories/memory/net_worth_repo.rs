@@ -0,0 +1,49 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use std::sync::RwLock;
+use uuid::Uuid;
+
+use crate::error::AppResult;
+use crate::models::{CreateNetWorthSnapshot, NetWorthSnapshot};
+use crate::repositories::traits::NetWorthRepository;
+
+pub struct MemoryNetWorthRepository {
+    snapshots: RwLock<Vec<NetWorthSnapshot>>,
+}
+
+impl MemoryNetWorthRepository {
+    pub fn new() -> Self {
+        Self {
+            snapshots: RwLock::new(Vec::new()),
+        }
+    }
+}
+
+impl Default for MemoryNetWorthRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl NetWorthRepository for MemoryNetWorthRepository {
+    async fn save_day(&self, user_id: Uuid, snapshot_date: NaiveDate, snapshots: Vec<CreateNetWorthSnapshot>) -> AppResult<Vec<NetWorthSnapshot>> {
+        let mut all = self.snapshots.write().unwrap();
+        all.retain(|s| s.user_id != user_id || s.snapshot_date != snapshot_date);
+
+        let saved: Vec<NetWorthSnapshot> = snapshots.into_iter().map(NetWorthSnapshot::new).collect();
+        all.extend(saved.iter().cloned());
+        Ok(saved)
+    }
+
+    async fn find_by_user_id(&self, user_id: Uuid, start_date: NaiveDate) -> AppResult<Vec<NetWorthSnapshot>> {
+        let all = self.snapshots.read().unwrap();
+        let mut result: Vec<NetWorthSnapshot> = all
+            .iter()
+            .filter(|s| s.user_id == user_id && s.snapshot_date >= start_date)
+            .cloned()
+            .collect();
+        result.sort_by(|a, b| a.snapshot_date.cmp(&b.snapshot_date).then(a.currency.cmp(&b.currency)));
+        Ok(result)
+    }
+}
@@ -0,0 +1,86 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use std::sync::RwLock;
+use uuid::Uuid;
+
+use crate::error::AppResult;
+use crate::models::{CreateNotification, Notification};
+use crate::repositories::traits::NotificationRepository;
+
+pub struct MemoryNotificationRepository {
+    notifications: RwLock<Vec<Notification>>,
+}
+
+impl MemoryNotificationRepository {
+    pub fn new() -> Self {
+        Self {
+            notifications: RwLock::new(Vec::new()),
+        }
+    }
+}
+
+impl Default for MemoryNotificationRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl NotificationRepository for MemoryNotificationRepository {
+    async fn create(&self, notification: CreateNotification) -> AppResult<Notification> {
+        let new_notification = Notification::new(notification);
+        let mut notifications = self.notifications.write().unwrap();
+        notifications.push(new_notification.clone());
+        Ok(new_notification)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> AppResult<Option<Notification>> {
+        let notifications = self.notifications.read().unwrap();
+        Ok(notifications.iter().find(|n| n.id == id).cloned())
+    }
+
+    async fn find_by_user_id(&self, user_id: Uuid, unread_only: bool, page: u32, page_size: u32) -> AppResult<(Vec<Notification>, u64)> {
+        let notifications = self.notifications.read().unwrap();
+        let matching: Vec<&Notification> = notifications
+            .iter()
+            .rev()
+            .filter(|n| n.user_id == user_id && (!unread_only || n.read_at.is_none()))
+            .collect();
+
+        let total = matching.len() as u64;
+        let offset = ((page.max(1) - 1) * page_size) as usize;
+        let items = matching
+            .into_iter()
+            .skip(offset)
+            .take(page_size as usize)
+            .cloned()
+            .collect();
+
+        Ok((items, total))
+    }
+
+    async fn count_unread(&self, user_id: Uuid) -> AppResult<u64> {
+        let notifications = self.notifications.read().unwrap();
+        Ok(notifications
+            .iter()
+            .filter(|n| n.user_id == user_id && n.read_at.is_none())
+            .count() as u64)
+    }
+
+    async fn mark_read(&self, id: Uuid) -> AppResult<()> {
+        let mut notifications = self.notifications.write().unwrap();
+        if let Some(notification) = notifications.iter_mut().find(|n| n.id == id && n.read_at.is_none()) {
+            notification.read_at = Some(Utc::now());
+        }
+        Ok(())
+    }
+
+    async fn mark_all_read(&self, user_id: Uuid) -> AppResult<()> {
+        let mut notifications = self.notifications.write().unwrap();
+        let now = Utc::now();
+        for notification in notifications.iter_mut().filter(|n| n.user_id == user_id && n.read_at.is_none()) {
+            notification.read_at = Some(now);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,84 @@
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::RwLock;
+use uuid::Uuid;
+
+use crate::error::{AppError, AppResult};
+use crate::models::{CategoryRule, CreateCategoryRule, UpdateCategoryRule};
+use crate::repositories::traits::RuleRepository;
+
+pub struct MemoryRuleRepository {
+    rules: RwLock<HashMap<Uuid, CategoryRule>>,
+}
+
+impl MemoryRuleRepository {
+    pub fn new() -> Self {
+        Self {
+            rules: RwLock::new(HashMap::new()),
+        }
+    }
+}
+
+impl Default for MemoryRuleRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl RuleRepository for MemoryRuleRepository {
+    async fn create(&self, rule: CreateCategoryRule) -> AppResult<CategoryRule> {
+        let new_rule = CategoryRule::new(rule);
+        let mut rules = self.rules.write().unwrap();
+        rules.insert(new_rule.id, new_rule.clone());
+        Ok(new_rule)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> AppResult<Option<CategoryRule>> {
+        let rules = self.rules.read().unwrap();
+        Ok(rules.get(&id).cloned())
+    }
+
+    async fn find_by_ledger_id(&self, ledger_id: Uuid) -> AppResult<Vec<CategoryRule>> {
+        let rules = self.rules.read().unwrap();
+        let mut result: Vec<CategoryRule> = rules
+            .values()
+            .filter(|r| r.ledger_id == ledger_id)
+            .cloned()
+            .collect();
+        result.sort_by(|a, b| a.priority.cmp(&b.priority).then(a.created_at.cmp(&b.created_at)));
+        Ok(result)
+    }
+
+    async fn update(&self, id: Uuid, update: UpdateCategoryRule) -> AppResult<CategoryRule> {
+        let mut rules = self.rules.write().unwrap();
+        let rule = rules
+            .get_mut(&id)
+            .ok_or_else(|| AppError::NotFound("Rule not found".to_string()))?;
+
+        if let Some(name) = update.name {
+            rule.name = name;
+        }
+        if let Some(priority) = update.priority {
+            rule.priority = priority;
+        }
+        if let Some(enabled) = update.enabled {
+            rule.enabled = enabled;
+        }
+        if let Some(conditions) = update.conditions {
+            rule.conditions = conditions;
+        }
+        if let Some(actions) = update.actions {
+            rule.actions = actions;
+        }
+        rule.updated_at = chrono::Utc::now();
+
+        Ok(rule.clone())
+    }
+
+    async fn delete(&self, id: Uuid) -> AppResult<()> {
+        let mut rules = self.rules.write().unwrap();
+        rules.remove(&id);
+        Ok(())
+    }
+}
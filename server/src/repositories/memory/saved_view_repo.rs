@@ -0,0 +1,78 @@
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::RwLock;
+use uuid::Uuid;
+
+use crate::error::{AppError, AppResult};
+use crate::models::{CreateSavedView, SavedView, UpdateSavedView};
+use crate::repositories::traits::SavedViewRepository;
+
+pub struct MemorySavedViewRepository {
+    views: RwLock<HashMap<Uuid, SavedView>>,
+}
+
+impl MemorySavedViewRepository {
+    pub fn new() -> Self {
+        Self {
+            views: RwLock::new(HashMap::new()),
+        }
+    }
+}
+
+impl Default for MemorySavedViewRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl SavedViewRepository for MemorySavedViewRepository {
+    async fn create(&self, view: CreateSavedView) -> AppResult<SavedView> {
+        let new_view = SavedView::new(view);
+        let mut views = self.views.write().unwrap();
+        views.insert(new_view.id, new_view.clone());
+        Ok(new_view)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> AppResult<Option<SavedView>> {
+        let views = self.views.read().unwrap();
+        Ok(views.get(&id).cloned())
+    }
+
+    async fn find_by_ledger_id(&self, ledger_id: Uuid) -> AppResult<Vec<SavedView>> {
+        let views = self.views.read().unwrap();
+        let mut result: Vec<SavedView> = views
+            .values()
+            .filter(|v| v.ledger_id == ledger_id)
+            .cloned()
+            .collect();
+        result.sort_by(|a, b| a.name.cmp(&b.name).then(a.created_at.cmp(&b.created_at)));
+        Ok(result)
+    }
+
+    async fn update(&self, id: Uuid, update: UpdateSavedView) -> AppResult<SavedView> {
+        let mut views = self.views.write().unwrap();
+        let view = views
+            .get_mut(&id)
+            .ok_or_else(|| AppError::NotFound("View not found".to_string()))?;
+
+        if let Some(name) = update.name {
+            view.name = name;
+        }
+        if let Some(filter) = update.filter {
+            view.filter = filter;
+        }
+        if let Some(shared) = update.shared {
+            view.shared = shared;
+        }
+        view.updated_at = chrono::Utc::now();
+
+        Ok(view.clone())
+    }
+
+    async fn delete(&self, id: Uuid) -> AppResult<()> {
+        let mut views = self.views.write().unwrap();
+        views.remove(&id);
+        Ok(())
+    }
+}
@@ -44,6 +44,11 @@ impl UserRepository for MemoryUserRepository {
         Ok(users.values().find(|u| u.email == email).cloned())
     }
 
+    async fn find_all(&self) -> AppResult<Vec<User>> {
+        let users = self.users.read().unwrap();
+        Ok(users.values().cloned().collect())
+    }
+
     async fn update(&self, id: Uuid, update: UpdateUser) -> AppResult<User> {
         let mut users = self.users.write().unwrap();
         let user = users.get_mut(&id).ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
@@ -54,6 +59,18 @@ impl UserRepository for MemoryUserRepository {
         if let Some(avatar) = update.avatar {
             user.avatar = Some(avatar);
         }
+        if let Some(locale) = update.locale {
+            user.locale = Some(locale);
+        }
+        if let Some(utc_offset) = update.utc_offset {
+            user.utc_offset = Some(utc_offset);
+        }
+        if let Some(week_start) = update.week_start {
+            user.week_start = Some(week_start);
+        }
+        if let Some(month_start_day) = update.month_start_day {
+            user.month_start_day = Some(month_start_day);
+        }
         user.updated_at = chrono::Utc::now();
 
         Ok(user.clone())
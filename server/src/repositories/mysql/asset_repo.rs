@@ -0,0 +1,137 @@
+use async_trait::async_trait;
+use sqlx::MySqlPool;
+use uuid::Uuid;
+
+use crate::error::{AppError, AppResult};
+use crate::models::{Asset, CreateAsset, UpdateAsset};
+use crate::repositories::traits::AssetRepository;
+
+pub struct MySqlAssetRepository {
+    pool: MySqlPool,
+}
+
+impl MySqlAssetRepository {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl AssetRepository for MySqlAssetRepository {
+    async fn create(&self, asset: CreateAsset) -> AppResult<Asset> {
+        let new_asset = Asset::new(asset);
+
+        sqlx::query(
+            r#"
+            INSERT INTO assets (id, user_id, name, kind, currency, value, created_at, updated_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(new_asset.id.to_string())
+        .bind(new_asset.user_id.to_string())
+        .bind(&new_asset.name)
+        .bind(new_asset.kind.to_string())
+        .bind(&new_asset.currency)
+        .bind(new_asset.value)
+        .bind(new_asset.created_at)
+        .bind(new_asset.updated_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(new_asset)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> AppResult<Option<Asset>> {
+        let row = sqlx::query_as::<_, AssetRow>(
+            r#"
+            SELECT id, user_id, name, kind, currency, value, created_at, updated_at
+            FROM assets WHERE id = ?
+            "#,
+        )
+        .bind(id.to_string())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(row.map(|r| r.into()))
+    }
+
+    async fn find_by_user_id(&self, user_id: Uuid) -> AppResult<Vec<Asset>> {
+        let rows = sqlx::query_as::<_, AssetRow>(
+            r#"
+            SELECT id, user_id, name, kind, currency, value, created_at, updated_at
+            FROM assets WHERE user_id = ? ORDER BY name ASC, created_at ASC
+            "#,
+        )
+        .bind(user_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+
+    async fn update(&self, id: Uuid, asset: UpdateAsset) -> AppResult<Asset> {
+        sqlx::query(
+            r#"
+            UPDATE assets SET
+                name = COALESCE(?, name),
+                kind = COALESCE(?, kind),
+                currency = COALESCE(?, currency),
+                value = COALESCE(?, value),
+                updated_at = NOW()
+            WHERE id = ?
+            "#,
+        )
+        .bind(&asset.name)
+        .bind(asset.kind.map(|k| k.to_string()))
+        .bind(&asset.currency)
+        .bind(asset.value)
+        .bind(id.to_string())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(e.to_string()))?;
+
+        self.find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Asset not found".to_string()))
+    }
+
+    async fn delete(&self, id: Uuid) -> AppResult<()> {
+        sqlx::query("DELETE FROM assets WHERE id = ?")
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct AssetRow {
+    id: String,
+    user_id: String,
+    name: String,
+    kind: String,
+    currency: String,
+    value: f64,
+    created_at: chrono::DateTime<chrono::Utc>,
+    updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<AssetRow> for Asset {
+    fn from(row: AssetRow) -> Self {
+        Asset {
+            id: Uuid::parse_str(&row.id).unwrap(),
+            user_id: Uuid::parse_str(&row.user_id).unwrap(),
+            name: row.name,
+            kind: row.kind.parse().unwrap(),
+            currency: row.currency,
+            value: row.value,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use async_trait::async_trait;
+use sqlx::MySqlPool;
+use uuid::Uuid;
+
+use crate::error::{AppError, AppResult};
+use crate::models::{Attachment, CreateAttachment};
+use crate::repositories::traits::AttachmentRepository;
+
+pub struct MySqlAttachmentRepository {
+    pool: MySqlPool,
+}
+
+impl MySqlAttachmentRepository {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl AttachmentRepository for MySqlAttachmentRepository {
+    async fn create(&self, attachment: CreateAttachment) -> AppResult<Attachment> {
+        let new_attachment = Attachment::new(attachment);
+
+        sqlx::query(
+            r#"
+            INSERT INTO bill_attachments (id, bill_id, user_id, file_name, content_type, size, storage_key, thumbnail_key, created_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(new_attachment.id.to_string())
+        .bind(new_attachment.bill_id.to_string())
+        .bind(new_attachment.user_id.to_string())
+        .bind(&new_attachment.file_name)
+        .bind(&new_attachment.content_type)
+        .bind(new_attachment.size)
+        .bind(&new_attachment.storage_key)
+        .bind(&new_attachment.thumbnail_key)
+        .bind(new_attachment.created_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(new_attachment)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> AppResult<Option<Attachment>> {
+        let result = sqlx::query_as::<_, AttachmentRow>(
+            r#"
+            SELECT id, bill_id, user_id, file_name, content_type, size, storage_key, thumbnail_key, created_at
+            FROM bill_attachments WHERE id = ?
+            "#,
+        )
+        .bind(id.to_string())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(result.map(|r| r.into()))
+    }
+
+    async fn find_by_bill_id(&self, bill_id: Uuid) -> AppResult<Vec<Attachment>> {
+        let rows = sqlx::query_as::<_, AttachmentRow>(
+            r#"
+            SELECT id, bill_id, user_id, file_name, content_type, size, storage_key, thumbnail_key, created_at
+            FROM bill_attachments WHERE bill_id = ? ORDER BY created_at ASC
+            "#,
+        )
+        .bind(bill_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+
+    async fn delete(&self, id: Uuid) -> AppResult<()> {
+        sqlx::query("DELETE FROM bill_attachments WHERE id = ?")
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn delete_by_bill_id(&self, bill_id: Uuid) -> AppResult<()> {
+        sqlx::query("DELETE FROM bill_attachments WHERE bill_id = ?")
+            .bind(bill_id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct AttachmentRow {
+    id: String,
+    bill_id: String,
+    user_id: String,
+    file_name: String,
+    content_type: String,
+    size: u64,
+    storage_key: String,
+    thumbnail_key: Option<String>,
+    created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<AttachmentRow> for Attachment {
+    fn from(row: AttachmentRow) -> Self {
+        Attachment {
+            id: Uuid::parse_str(&row.id).unwrap(),
+            bill_id: Uuid::parse_str(&row.bill_id).unwrap(),
+            user_id: Uuid::parse_str(&row.user_id).unwrap(),
+            file_name: row.file_name,
+            content_type: row.content_type,
+            size: row.size,
+            storage_key: row.storage_key,
+            thumbnail_key: row.thumbnail_key,
+            created_at: row.created_at,
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use async_trait::async_trait;
+use sqlx::MySqlPool;
+use uuid::Uuid;
+
+use crate::error::{AppError, AppResult};
+use crate::models::{AuditEntityType, AuditEvent, CreateAuditEvent};
+use crate::repositories::traits::AuditRepository;
+
+pub struct MySqlAuditRepository {
+    pool: MySqlPool,
+}
+
+impl MySqlAuditRepository {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl AuditRepository for MySqlAuditRepository {
+    async fn create(&self, event: CreateAuditEvent) -> AppResult<AuditEvent> {
+        let new_event = AuditEvent::new(event);
+
+        sqlx::query(
+            r#"
+            INSERT INTO audit_events (id, entity_type, entity_id, action, actor_id, group_id, changes, created_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(new_event.id.to_string())
+        .bind(new_event.entity_type.to_string())
+        .bind(new_event.entity_id.to_string())
+        .bind(new_event.action.to_string())
+        .bind(new_event.actor_id.to_string())
+        .bind(new_event.group_id.map(|id| id.to_string()))
+        .bind(new_event.changes.to_string())
+        .bind(new_event.created_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(new_event)
+    }
+
+    async fn find_by_entity(&self, entity_type: AuditEntityType, entity_id: Uuid) -> AppResult<Vec<AuditEvent>> {
+        let rows = sqlx::query_as::<_, AuditEventRow>(
+            r#"
+            SELECT id, entity_type, entity_id, action, actor_id, group_id, CAST(changes AS CHAR) AS changes, created_at
+            FROM audit_events WHERE entity_type = ? AND entity_id = ? ORDER BY created_at ASC
+            "#,
+        )
+        .bind(entity_type.to_string())
+        .bind(entity_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+
+    async fn find_by_group_id(&self, group_id: Uuid, page: u32, page_size: u32) -> AppResult<(Vec<AuditEvent>, u64)> {
+        let offset = (page.max(1) - 1) * page_size;
+
+        let rows = sqlx::query_as::<_, AuditEventRow>(
+            r#"
+            SELECT id, entity_type, entity_id, action, actor_id, group_id, CAST(changes AS CHAR) AS changes, created_at
+            FROM audit_events WHERE group_id = ? ORDER BY created_at DESC LIMIT ? OFFSET ?
+            "#,
+        )
+        .bind(group_id.to_string())
+        .bind(page_size as i64)
+        .bind(offset as i64)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(e.to_string()))?;
+
+        let total: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM audit_events WHERE group_id = ?")
+            .bind(group_id.to_string())
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok((rows.into_iter().map(|r| r.into()).collect(), total as u64))
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct AuditEventRow {
+    id: String,
+    entity_type: String,
+    entity_id: String,
+    action: String,
+    actor_id: String,
+    group_id: Option<String>,
+    changes: String,
+    created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<AuditEventRow> for AuditEvent {
+    fn from(row: AuditEventRow) -> Self {
+        AuditEvent {
+            id: Uuid::parse_str(&row.id).unwrap(),
+            entity_type: row.entity_type.parse().unwrap(),
+            entity_id: Uuid::parse_str(&row.entity_id).unwrap(),
+            action: row.action.parse().unwrap(),
+            actor_id: Uuid::parse_str(&row.actor_id).unwrap(),
+            group_id: row.group_id.and_then(|id| Uuid::parse_str(&id).ok()),
+            changes: serde_json::from_str(&row.changes).unwrap_or(serde_json::Value::Null),
+            created_at: row.created_at,
+        }
+    }
+}
@@ -1,11 +1,16 @@
 use async_trait::async_trait;
-use chrono::NaiveDate;
+use chrono::{DateTime, NaiveDate, Utc};
 use sqlx::MySqlPool;
+use std::collections::BTreeMap;
 use uuid::Uuid;
 
 use crate::error::{AppError, AppResult};
-use crate::models::{Bill, BillType, CreateBill, UpdateBill};
-use crate::repositories::traits::{BillFilter, BillRepository, BillStatistics, CategoryStatistics, DailyStatistics, TrendStatistics};
+use crate::models::{Bill, BillSort, BillType, CreateBill, UpdateBill};
+use crate::repositories::traits::{
+    BillFilter, BillRepository, BillStatistics, CategoryStatistics, DailyStatistics,
+    HourlyStatistics, MemberCategoryStatistics, MemberStatistics, TrendStatistics,
+};
+use crate::utils::Calendar;
 
 pub struct MySqlBillRepository {
     pool: MySqlPool,
@@ -28,12 +33,13 @@ impl BillRepository for MySqlBillRepository {
             bill.amount,
             bill.note,
             bill.bill_date,
+            bill.bill_time,
         );
 
         sqlx::query(
             r#"
-            INSERT INTO bills (id, ledger_id, category_id, user_id, type, amount, note, bill_date, created_at, updated_at)
-            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            INSERT INTO bills (id, ledger_id, category_id, user_id, type, amount, note, bill_date, bill_time, created_at, updated_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
             "#,
         )
         .bind(new_bill.id.to_string())
@@ -44,6 +50,7 @@ impl BillRepository for MySqlBillRepository {
         .bind(new_bill.amount)
         .bind(&new_bill.note)
         .bind(new_bill.bill_date)
+        .bind(new_bill.bill_time)
         .bind(new_bill.created_at)
         .bind(new_bill.updated_at)
         .execute(&self.pool)
@@ -56,8 +63,8 @@ impl BillRepository for MySqlBillRepository {
     async fn find_by_id(&self, id: Uuid) -> AppResult<Option<Bill>> {
         let result = sqlx::query_as::<_, BillRow>(
             r#"
-            SELECT id, ledger_id, category_id, user_id, type, amount, note, bill_date, created_at, updated_at
-            FROM bills WHERE id = ?
+            SELECT id, ledger_id, category_id, user_id, type, amount, note, bill_date, bill_time, created_at, updated_at, deleted_at
+            FROM bills WHERE id = ? AND deleted_at IS NULL
             "#,
         )
         .bind(id.to_string())
@@ -69,8 +76,8 @@ impl BillRepository for MySqlBillRepository {
     }
 
     async fn find_by_filter(&self, filter: BillFilter) -> AppResult<(Vec<Bill>, u64)> {
-        let mut conditions = vec!["ledger_id = ?".to_string()];
-        let mut count_conditions = vec!["ledger_id = ?".to_string()];
+        let mut conditions = vec!["ledger_id = ?".to_string(), "deleted_at IS NULL".to_string()];
+        let mut count_conditions = vec!["ledger_id = ?".to_string(), "deleted_at IS NULL".to_string()];
 
         if filter.start_date.is_some() {
             conditions.push("bill_date >= ?".to_string());
@@ -84,9 +91,51 @@ impl BillRepository for MySqlBillRepository {
             conditions.push("type = ?".to_string());
             count_conditions.push("type = ?".to_string());
         }
-        if filter.category_id.is_some() {
-            conditions.push("category_id = ?".to_string());
-            count_conditions.push("category_id = ?".to_string());
+        if !filter.category_ids.is_empty() {
+            let condition = format!("category_id IN ({})", vec!["?"; filter.category_ids.len()].join(", "));
+            conditions.push(condition.clone());
+            count_conditions.push(condition);
+        }
+        if !filter.user_ids.is_empty() {
+            let condition = format!("user_id IN ({})", vec!["?"; filter.user_ids.len()].join(", "));
+            conditions.push(condition.clone());
+            count_conditions.push(condition);
+        }
+        if filter.min_amount.is_some() {
+            conditions.push("amount >= ?".to_string());
+            count_conditions.push("amount >= ?".to_string());
+        }
+        if filter.max_amount.is_some() {
+            conditions.push("amount <= ?".to_string());
+            count_conditions.push("amount <= ?".to_string());
+        }
+        if let Some(has_note) = filter.has_note {
+            let condition = if has_note {
+                "TRIM(COALESCE(note, '')) <> ''"
+            } else {
+                "TRIM(COALESCE(note, '')) = ''"
+            };
+            conditions.push(condition.to_string());
+            count_conditions.push(condition.to_string());
+        }
+
+        // The ngram FULLTEXT index needs at least two characters, shorter words fall back to LIKE
+        let mut search_binds = Vec::new();
+        for term in &filter.search {
+            let mut matches = if term.word.chars().count() >= 2 {
+                search_binds.push(format!("\"{}\"", term.word.replace('"', "")));
+                vec!["MATCH(note) AGAINST (? IN BOOLEAN MODE)".to_string()]
+            } else {
+                search_binds.push(format!("%{}%", term.word.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_")));
+                vec!["note LIKE ?".to_string()]
+            };
+            if !term.category_ids.is_empty() {
+                matches.push(format!("category_id IN ({})", vec!["?"; term.category_ids.len()].join(", ")));
+                search_binds.extend(term.category_ids.iter().map(|id| id.to_string()));
+            }
+            let condition = format!("({})", matches.join(" OR "));
+            conditions.push(condition.clone());
+            count_conditions.push(condition);
         }
 
         let where_clause = conditions.join(" AND ");
@@ -94,10 +143,11 @@ impl BillRepository for MySqlBillRepository {
 
         let query = format!(
             r#"
-            SELECT id, ledger_id, category_id, user_id, type, amount, note, bill_date, created_at, updated_at
-            FROM bills WHERE {} ORDER BY bill_date DESC, created_at DESC LIMIT ? OFFSET ?
+            SELECT id, ledger_id, category_id, user_id, type, amount, note, bill_date, bill_time, created_at, updated_at, deleted_at
+            FROM bills WHERE {} ORDER BY {} LIMIT ? OFFSET ?
             "#,
-            where_clause
+            where_clause,
+            order_clause(filter.sort)
         );
 
         let count_query = format!("SELECT COUNT(*) as count FROM bills WHERE {}", count_conditions.join(" AND "));
@@ -113,9 +163,18 @@ impl BillRepository for MySqlBillRepository {
         if let Some(ref t) = filter.bill_type {
             q = q.bind(t.to_string());
         }
-        if let Some(id) = filter.category_id {
+        for id in filter.category_ids.iter().chain(&filter.user_ids) {
             q = q.bind(id.to_string());
         }
+        if let Some(amount) = filter.min_amount {
+            q = q.bind(amount);
+        }
+        if let Some(amount) = filter.max_amount {
+            q = q.bind(amount);
+        }
+        for value in &search_binds {
+            q = q.bind(value);
+        }
 
         let bills: Vec<Bill> = q
             .bind(filter.page_size as i64)
@@ -138,9 +197,18 @@ impl BillRepository for MySqlBillRepository {
         if let Some(ref t) = filter.bill_type {
             cq = cq.bind(t.to_string());
         }
-        if let Some(id) = filter.category_id {
+        for id in filter.category_ids.iter().chain(&filter.user_ids) {
             cq = cq.bind(id.to_string());
         }
+        if let Some(amount) = filter.min_amount {
+            cq = cq.bind(amount);
+        }
+        if let Some(amount) = filter.max_amount {
+            cq = cq.bind(amount);
+        }
+        for value in &search_binds {
+            cq = cq.bind(value);
+        }
 
         let total = cq
             .fetch_one(&self.pool)
@@ -153,23 +221,27 @@ impl BillRepository for MySqlBillRepository {
     async fn update(&self, id: Uuid, bill: UpdateBill) -> AppResult<Bill> {
         let existing = self.find_by_id(id).await?.ok_or_else(|| AppError::NotFound("Bill not found".to_string()))?;
 
+        let ledger_id = bill.ledger_id.unwrap_or(existing.ledger_id);
         let category_id = bill.category_id.unwrap_or(existing.category_id);
         let bill_type = bill.bill_type.unwrap_or(existing.bill_type);
         let amount = bill.amount.unwrap_or(existing.amount);
         let note = bill.note.or(existing.note);
         let bill_date = bill.bill_date.unwrap_or(existing.bill_date);
+        let bill_time = bill.bill_time.unwrap_or(existing.bill_time);
 
         sqlx::query(
             r#"
-            UPDATE bills SET category_id = ?, type = ?, amount = ?, note = ?, bill_date = ?, updated_at = NOW()
-            WHERE id = ?
+            UPDATE bills SET ledger_id = ?, category_id = ?, type = ?, amount = ?, note = ?, bill_date = ?, bill_time = ?, updated_at = NOW()
+            WHERE id = ? AND deleted_at IS NULL
             "#,
         )
+        .bind(ledger_id.to_string())
         .bind(category_id.to_string())
         .bind(bill_type.to_string())
         .bind(amount)
         .bind(&note)
         .bind(bill_date)
+        .bind(bill_time)
         .bind(id.to_string())
         .execute(&self.pool)
         .await
@@ -179,6 +251,163 @@ impl BillRepository for MySqlBillRepository {
     }
 
     async fn delete(&self, id: Uuid) -> AppResult<()> {
+        sqlx::query("UPDATE bills SET deleted_at = NOW() WHERE id = ? AND deleted_at IS NULL")
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn create_many(&self, bills: Vec<CreateBill>) -> AppResult<Vec<Bill>> {
+        let mut tx = self.pool.begin().await.map_err(|e| AppError::Database(e.to_string()))?;
+
+        let mut created = Vec::new();
+        for bill in bills {
+            let new_bill = Bill::new(
+                bill.ledger_id,
+                bill.category_id,
+                bill.user_id,
+                bill.bill_type,
+                bill.amount,
+                bill.note,
+                bill.bill_date,
+                bill.bill_time,
+            );
+
+            sqlx::query(
+                r#"
+                INSERT INTO bills (id, ledger_id, category_id, user_id, type, amount, note, bill_date, bill_time, created_at, updated_at)
+                VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                "#,
+            )
+            .bind(new_bill.id.to_string())
+            .bind(new_bill.ledger_id.to_string())
+            .bind(new_bill.category_id.to_string())
+            .bind(new_bill.user_id.to_string())
+            .bind(new_bill.bill_type.to_string())
+            .bind(new_bill.amount)
+            .bind(&new_bill.note)
+            .bind(new_bill.bill_date)
+            .bind(new_bill.bill_time)
+            .bind(new_bill.created_at)
+            .bind(new_bill.updated_at)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| AppError::Database(e.to_string()))?;
+
+            created.push(new_bill);
+        }
+
+        tx.commit().await.map_err(|e| AppError::Database(e.to_string()))?;
+        Ok(created)
+    }
+
+    async fn update_many(&self, updates: Vec<(Uuid, UpdateBill)>) -> AppResult<Vec<Bill>> {
+        let mut tx = self.pool.begin().await.map_err(|e| AppError::Database(e.to_string()))?;
+
+        let ids: Vec<Uuid> = updates.iter().map(|(id, _)| *id).collect();
+        for (id, bill) in updates {
+            // Dropping the transaction rolls back the bills updated so far
+            let exists = sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM bills WHERE id = ? AND deleted_at IS NULL FOR UPDATE")
+                .bind(id.to_string())
+                .fetch_one(&mut *tx)
+                .await
+                .map_err(|e| AppError::Database(e.to_string()))?;
+            if exists == 0 {
+                return Err(AppError::NotFound("Bill not found".to_string()));
+            }
+
+            sqlx::query(
+                r#"
+                UPDATE bills SET ledger_id = COALESCE(?, ledger_id), category_id = COALESCE(?, category_id), type = COALESCE(?, type),
+                    amount = COALESCE(?, amount), note = COALESCE(?, note), bill_date = COALESCE(?, bill_date),
+                    bill_time = IF(?, ?, bill_time), updated_at = NOW()
+                WHERE id = ? AND deleted_at IS NULL
+                "#,
+            )
+            .bind(bill.ledger_id.map(|id| id.to_string()))
+            .bind(bill.category_id.map(|id| id.to_string()))
+            .bind(bill.bill_type.map(|t| t.to_string()))
+            .bind(bill.amount)
+            .bind(&bill.note)
+            .bind(bill.bill_date)
+            .bind(bill.bill_time.is_some())
+            .bind(bill.bill_time.flatten())
+            .bind(id.to_string())
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| AppError::Database(e.to_string()))?;
+        }
+
+        tx.commit().await.map_err(|e| AppError::Database(e.to_string()))?;
+
+        let mut updated = Vec::new();
+        for id in ids {
+            if let Some(bill) = self.find_by_id(id).await? {
+                updated.push(bill);
+            }
+        }
+        Ok(updated)
+    }
+
+    async fn delete_many(&self, ids: &[Uuid]) -> AppResult<()> {
+        let mut tx = self.pool.begin().await.map_err(|e| AppError::Database(e.to_string()))?;
+
+        for id in ids {
+            sqlx::query("UPDATE bills SET deleted_at = NOW() WHERE id = ? AND deleted_at IS NULL")
+                .bind(id.to_string())
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| AppError::Database(e.to_string()))?;
+        }
+
+        tx.commit().await.map_err(|e| AppError::Database(e.to_string()))?;
+        Ok(())
+    }
+
+    async fn find_trashed_by_id(&self, id: Uuid) -> AppResult<Option<Bill>> {
+        let result = sqlx::query_as::<_, BillRow>(
+            r#"
+            SELECT id, ledger_id, category_id, user_id, type, amount, note, bill_date, bill_time, created_at, updated_at, deleted_at
+            FROM bills WHERE id = ? AND deleted_at IS NOT NULL
+            "#,
+        )
+        .bind(id.to_string())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(result.map(|r| r.into()))
+    }
+
+    async fn find_trashed_by_user_id(&self, user_id: Uuid) -> AppResult<Vec<Bill>> {
+        let rows = sqlx::query_as::<_, BillRow>(
+            r#"
+            SELECT id, ledger_id, category_id, user_id, type, amount, note, bill_date, bill_time, created_at, updated_at, deleted_at
+            FROM bills WHERE user_id = ? AND deleted_at IS NOT NULL ORDER BY deleted_at DESC
+            "#,
+        )
+        .bind(user_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+
+    async fn restore(&self, id: Uuid) -> AppResult<Bill> {
+        sqlx::query("UPDATE bills SET deleted_at = NULL, updated_at = NOW() WHERE id = ? AND deleted_at IS NOT NULL")
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::Database(e.to_string()))?;
+
+        self.find_by_id(id).await?.ok_or_else(|| AppError::NotFound("Bill not found".to_string()))
+    }
+
+    async fn purge(&self, id: Uuid) -> AppResult<()> {
         sqlx::query("DELETE FROM bills WHERE id = ?")
             .bind(id.to_string())
             .execute(&self.pool)
@@ -188,13 +417,59 @@ impl BillRepository for MySqlBillRepository {
         Ok(())
     }
 
+    async fn find_purgeable(&self, deleted_before: DateTime<Utc>) -> AppResult<Vec<Bill>> {
+        let rows = sqlx::query_as::<_, BillRow>(
+            r#"
+            SELECT id, ledger_id, category_id, user_id, type, amount, note, bill_date, bill_time, created_at, updated_at, deleted_at
+            FROM bills WHERE deleted_at IS NOT NULL AND deleted_at < ?
+            "#,
+        )
+        .bind(deleted_before)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+
+    async fn find_all_ids_by_ledger_id(&self, ledger_id: Uuid) -> AppResult<Vec<Uuid>> {
+        let ids: Vec<String> = sqlx::query_scalar("SELECT id FROM bills WHERE ledger_id = ?")
+            .bind(ledger_id.to_string())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(ids.iter().filter_map(|id| Uuid::parse_str(id).ok()).collect())
+    }
+
+    async fn count_by_category_id(&self, category_id: Uuid) -> AppResult<u64> {
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM bills WHERE category_id = ?")
+            .bind(category_id.to_string())
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(count as u64)
+    }
+
+    async fn reassign_category(&self, from_category_id: Uuid, to_category_id: Uuid) -> AppResult<u64> {
+        let result = sqlx::query("UPDATE bills SET category_id = ?, updated_at = NOW() WHERE category_id = ?")
+            .bind(to_category_id.to_string())
+            .bind(from_category_id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(result.rows_affected())
+    }
+
     async fn get_statistics(&self, ledger_id: Uuid, start_date: Option<NaiveDate>, end_date: Option<NaiveDate>) -> AppResult<BillStatistics> {
         let mut query = String::from(
             r#"
             SELECT
                 COALESCE(SUM(CASE WHEN type = 'income' THEN amount ELSE 0 END), 0) as total_income,
                 COALESCE(SUM(CASE WHEN type = 'expense' THEN amount ELSE 0 END), 0) as total_expense
-            FROM bills WHERE ledger_id = ?
+            FROM bills WHERE ledger_id = ? AND deleted_at IS NULL
             "#
         );
 
@@ -238,7 +513,7 @@ impl BillRepository for MySqlBillRepository {
                 SUM(b.amount) as amount
             FROM bills b
             JOIN categories c ON b.category_id = c.id
-            WHERE b.ledger_id = ? AND b.type = ?
+            WHERE b.ledger_id = ? AND b.type = ? AND b.deleted_at IS NULL
             "#
         );
 
@@ -275,7 +550,7 @@ impl BillRepository for MySqlBillRepository {
                 category_id: Uuid::parse_str(&id).unwrap(),
                 category_name: name,
                 category_icon: icon,
-                bill_type: target_type.clone(),
+                bill_type: target_type,
                 amount,
                 count: count as u32,
                 percentage: if total > 0.0 { (amount / total) * 100.0 } else { 0.0 },
@@ -285,6 +560,57 @@ impl BillRepository for MySqlBillRepository {
         Ok(stats)
     }
 
+    async fn get_member_statistics(&self, ledger_id: Uuid, start_date: Option<NaiveDate>, end_date: Option<NaiveDate>) -> AppResult<Vec<MemberStatistics>> {
+        let mut query = String::from(
+            r#"
+            SELECT user_id, category_id, type, COUNT(*) as count, SUM(amount) as amount
+            FROM bills
+            WHERE ledger_id = ? AND deleted_at IS NULL
+            "#
+        );
+
+        if start_date.is_some() {
+            query.push_str(" AND bill_date >= ?");
+        }
+        if end_date.is_some() {
+            query.push_str(" AND bill_date <= ?");
+        }
+
+        query.push_str(" GROUP BY user_id, category_id, type");
+
+        let mut q = sqlx::query_as::<_, (String, String, String, i64, f64)>(&query).bind(ledger_id.to_string());
+
+        if let Some(date) = start_date {
+            q = q.bind(date);
+        }
+        if let Some(date) = end_date {
+            q = q.bind(date);
+        }
+
+        let rows = q
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| AppError::Database(e.to_string()))?
+            .into_iter()
+            .map(|(user_id, category_id, bill_type, count, amount)| {
+                (
+                    Uuid::parse_str(&user_id).unwrap(),
+                    MemberCategoryStatistics {
+                        category_id: Uuid::parse_str(&category_id).unwrap(),
+                        bill_type: match bill_type.as_str() {
+                            "income" => BillType::Income,
+                            _ => BillType::Expense,
+                        },
+                        amount,
+                        count: count as u32,
+                    },
+                )
+            })
+            .collect();
+
+        Ok(MemberStatistics::from_categories(rows))
+    }
+
     async fn get_daily_statistics(&self, ledger_id: Uuid, start_date: NaiveDate, end_date: NaiveDate) -> AppResult<Vec<DailyStatistics>> {
         let query = r#"
             SELECT
@@ -292,7 +618,7 @@ impl BillRepository for MySqlBillRepository {
                 COALESCE(SUM(CASE WHEN type = 'income' THEN amount ELSE 0 END), 0) as income,
                 COALESCE(SUM(CASE WHEN type = 'expense' THEN amount ELSE 0 END), 0) as expense
             FROM bills
-            WHERE ledger_id = ? AND bill_date >= ? AND bill_date <= ?
+            WHERE ledger_id = ? AND deleted_at IS NULL AND bill_date >= ? AND bill_date <= ?
             GROUP BY bill_date
             ORDER BY bill_date ASC
         "#;
@@ -313,44 +639,71 @@ impl BillRepository for MySqlBillRepository {
         Ok(stats)
     }
 
-    async fn get_trend_statistics(&self, ledger_id: Uuid, start_date: NaiveDate, end_date: NaiveDate, group_by: &str) -> AppResult<Vec<TrendStatistics>> {
-        let date_format = match group_by {
-            "day" => "%Y-%m-%d",
-            "week" => "%x-W%v",  // ISO week format
-            "month" => "%Y-%m",
-            "year" => "%Y",
-            _ => "%Y-%m",
-        };
-
-        let query = format!(
+    async fn get_trend_statistics(&self, ledger_id: Uuid, start_date: NaiveDate, end_date: NaiveDate, group_by: &str, calendar: &Calendar) -> AppResult<Vec<TrendStatistics>> {
+        // Weeks and months follow the user's calendar, so days are bucketed here rather than in SQL
+        let rows = sqlx::query_as::<_, (NaiveDate, f64, f64)>(
             r#"
             SELECT
-                DATE_FORMAT(bill_date, '{}') as period,
+                bill_date,
                 COALESCE(SUM(CASE WHEN type = 'income' THEN amount ELSE 0 END), 0) as income,
                 COALESCE(SUM(CASE WHEN type = 'expense' THEN amount ELSE 0 END), 0) as expense
             FROM bills
-            WHERE ledger_id = ? AND bill_date >= ? AND bill_date <= ?
-            GROUP BY period
-            ORDER BY period ASC
+            WHERE ledger_id = ? AND deleted_at IS NULL AND bill_date >= ? AND bill_date <= ?
+            GROUP BY bill_date
             "#,
-            date_format
-        );
+        )
+        .bind(ledger_id.to_string())
+        .bind(start_date)
+        .bind(end_date)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(e.to_string()))?;
+
+        let mut periods: BTreeMap<String, (f64, f64)> = BTreeMap::new();
+        for (date, income, expense) in rows {
+            let entry = periods.entry(calendar.bucket(group_by, date)).or_default();
+            entry.0 += income;
+            entry.1 += expense;
+        }
+
+        let stats: Vec<TrendStatistics> = periods
+            .into_iter()
+            .map(|(period, (income, expense))| TrendStatistics {
+                period,
+                income,
+                expense,
+                balance: income - expense,
+            })
+            .collect();
+
+        Ok(stats)
+    }
+
+    async fn get_hourly_statistics(&self, ledger_id: Uuid, start_date: NaiveDate, end_date: NaiveDate, bill_type: BillType) -> AppResult<Vec<HourlyStatistics>> {
+        let query = r#"
+            SELECT bill_date, CAST(HOUR(bill_time) AS SIGNED) as hour, SUM(amount) as amount, COUNT(*) as count
+            FROM bills
+            WHERE ledger_id = ? AND deleted_at IS NULL AND type = ? AND bill_date >= ? AND bill_date <= ?
+            GROUP BY bill_date, HOUR(bill_time)
+            ORDER BY bill_date ASC, hour ASC
+        "#;
 
-        let rows = sqlx::query_as::<_, (String, f64, f64)>(&query)
+        let rows = sqlx::query_as::<_, (NaiveDate, Option<i64>, f64, i64)>(query)
             .bind(ledger_id.to_string())
+            .bind(bill_type.to_string())
             .bind(start_date)
             .bind(end_date)
             .fetch_all(&self.pool)
             .await
             .map_err(|e| AppError::Database(e.to_string()))?;
 
-        let stats: Vec<TrendStatistics> = rows
+        let stats: Vec<HourlyStatistics> = rows
             .into_iter()
-            .map(|(period, income, expense)| TrendStatistics {
-                period,
-                income,
-                expense,
-                balance: income - expense,
+            .map(|(date, hour, amount, count)| HourlyStatistics {
+                date,
+                hour: hour.map(|h| h as u32),
+                amount,
+                count: count as u32,
             })
             .collect();
 
@@ -358,6 +711,17 @@ impl BillRepository for MySqlBillRepository {
     }
 }
 
+fn order_clause(sort: BillSort) -> &'static str {
+    match sort {
+        BillSort::DateDesc => "bill_date DESC, created_at DESC",
+        BillSort::DateAsc => "bill_date ASC, created_at ASC",
+        BillSort::AmountDesc => "amount DESC, bill_date DESC, created_at DESC",
+        BillSort::AmountAsc => "amount ASC, bill_date DESC, created_at DESC",
+        BillSort::CreatedDesc => "created_at DESC",
+        BillSort::CreatedAsc => "created_at ASC",
+    }
+}
+
 #[derive(sqlx::FromRow)]
 struct BillRow {
     id: String,
@@ -369,8 +733,10 @@ struct BillRow {
     amount: f64,
     note: Option<String>,
     bill_date: chrono::NaiveDate,
+    bill_time: Option<chrono::NaiveTime>,
     created_at: chrono::DateTime<chrono::Utc>,
     updated_at: chrono::DateTime<chrono::Utc>,
+    deleted_at: Option<chrono::DateTime<chrono::Utc>>,
 }
 
 impl From<BillRow> for Bill {
@@ -387,8 +753,10 @@ impl From<BillRow> for Bill {
             amount: row.amount,
             note: row.note,
             bill_date: row.bill_date,
+            bill_time: row.bill_time,
             created_at: row.created_at,
             updated_at: row.updated_at,
+            deleted_at: row.deleted_at,
         }
     }
 }
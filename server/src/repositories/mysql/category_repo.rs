@@ -1,10 +1,11 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use sqlx::MySqlPool;
 use uuid::Uuid;
 
 use crate::error::{AppError, AppResult};
 use crate::models::{BillType, Category, CreateCategory};
-use crate::repositories::traits::CategoryRepository;
+use crate::repositories::traits::{BillRepository, CategoryRepository};
 
 pub struct MySqlCategoryRepository {
     pool: MySqlPool,
@@ -52,7 +53,7 @@ impl CategoryRepository for MySqlCategoryRepository {
     async fn find_by_id(&self, id: Uuid) -> AppResult<Option<Category>> {
         let result = sqlx::query_as::<_, CategoryRow>(
             r#"
-            SELECT id, name, icon, type, parent_id, ledger_id, sort_order, created_at
+            SELECT id, name, icon, type, parent_id, ledger_id, sort_order, created_at, deleted_at, archived_at
             FROM categories WHERE id = ?
             "#,
         )
@@ -64,9 +65,14 @@ impl CategoryRepository for MySqlCategoryRepository {
         Ok(result.map(|r| r.into()))
     }
 
-    async fn find_by_ledger_id(&self, ledger_id: Option<Uuid>, category_type: Option<BillType>) -> AppResult<Vec<Category>> {
+    async fn find_by_ledger_id(
+        &self,
+        ledger_id: Option<Uuid>,
+        category_type: Option<BillType>,
+        include_archived: bool,
+    ) -> AppResult<Vec<Category>> {
         let mut query = String::from(
-            "SELECT id, name, icon, type, parent_id, ledger_id, sort_order, created_at FROM categories WHERE 1=1"
+            "SELECT id, name, icon, type, parent_id, ledger_id, sort_order, created_at, deleted_at, archived_at FROM categories WHERE deleted_at IS NULL"
         );
 
         if ledger_id.is_some() {
@@ -79,6 +85,10 @@ impl CategoryRepository for MySqlCategoryRepository {
             query.push_str(" AND type = ?");
         }
 
+        if !include_archived {
+            query.push_str(" AND archived_at IS NULL");
+        }
+
         query.push_str(" ORDER BY sort_order ASC, name ASC");
 
         let mut q = sqlx::query_as::<_, CategoryRow>(&query);
@@ -103,7 +113,7 @@ impl CategoryRepository for MySqlCategoryRepository {
         sqlx::query(
             r#"
             UPDATE categories SET name = COALESCE(?, name), icon = COALESCE(?, icon), sort_order = COALESCE(?, sort_order)
-            WHERE id = ?
+            WHERE id = ? AND deleted_at IS NULL
             "#,
         )
         .bind(&name)
@@ -120,7 +130,7 @@ impl CategoryRepository for MySqlCategoryRepository {
     }
 
     async fn delete(&self, id: Uuid) -> AppResult<()> {
-        sqlx::query("DELETE FROM categories WHERE id = ?")
+        sqlx::query("UPDATE categories SET deleted_at = NOW() WHERE id = ? AND deleted_at IS NULL")
             .bind(id.to_string())
             .execute(&self.pool)
             .await
@@ -170,6 +180,156 @@ impl CategoryRepository for MySqlCategoryRepository {
 
         Ok(())
     }
+
+    async fn set_parent(&self, id: Uuid, parent_id: Option<Uuid>) -> AppResult<Category> {
+        if let Some(parent_id) = parent_id {
+            // Walk up from the new parent, reaching `id` would close a loop
+            let creates_cycle: i64 = sqlx::query_scalar(
+                r#"
+                WITH RECURSIVE ancestors AS (
+                    SELECT id, parent_id FROM categories WHERE id = ?
+                    UNION ALL
+                    SELECT c.id, c.parent_id FROM categories c JOIN ancestors a ON c.id = a.parent_id
+                )
+                SELECT COUNT(*) FROM ancestors WHERE id = ?
+                "#,
+            )
+            .bind(parent_id.to_string())
+            .bind(id.to_string())
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| AppError::Database(e.to_string()))?;
+
+            if creates_cycle > 0 {
+                return Err(AppError::Validation("Category hierarchy cannot contain cycles".to_string()));
+            }
+        }
+
+        sqlx::query("UPDATE categories SET parent_id = ? WHERE id = ? AND deleted_at IS NULL")
+            .bind(parent_id.map(|id| id.to_string()))
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::Database(e.to_string()))?;
+
+        self.find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Category not found".to_string()))
+    }
+
+    async fn set_archived(&self, id: Uuid, archived: bool) -> AppResult<Category> {
+        let query = if archived {
+            "UPDATE categories SET archived_at = COALESCE(archived_at, NOW()) WHERE id = ? AND deleted_at IS NULL"
+        } else {
+            "UPDATE categories SET archived_at = NULL WHERE id = ? AND deleted_at IS NULL"
+        };
+
+        sqlx::query(query)
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::Database(e.to_string()))?;
+
+        self.find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Category not found".to_string()))
+    }
+
+    async fn merge(&self, _bill_repo: &dyn BillRepository, id: Uuid, target_id: Uuid, child_ids: &[Uuid]) -> AppResult<u64> {
+        // Bills live in the same database, one transaction covers them and the categories
+        let mut tx = self.pool.begin().await.map_err(|e| AppError::Database(e.to_string()))?;
+
+        // Trashing first locks the category, a concurrent merge of it finds nothing left to trash
+        let trashed = sqlx::query("UPDATE categories SET deleted_at = NOW() WHERE id = ? AND deleted_at IS NULL")
+            .bind(id.to_string())
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| AppError::Database(e.to_string()))?;
+        if trashed.rows_affected() == 0 {
+            return Err(AppError::NotFound("Category not found".to_string()));
+        }
+
+        let moved = sqlx::query("UPDATE bills SET category_id = ?, updated_at = NOW() WHERE category_id = ?")
+            .bind(target_id.to_string())
+            .bind(id.to_string())
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| AppError::Database(e.to_string()))?
+            .rows_affected();
+
+        for child_id in child_ids {
+            sqlx::query("UPDATE categories SET parent_id = ? WHERE id = ? AND deleted_at IS NULL")
+                .bind(target_id.to_string())
+                .bind(child_id.to_string())
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| AppError::Database(e.to_string()))?;
+        }
+
+        tx.commit().await.map_err(|e| AppError::Database(e.to_string()))?;
+        Ok(moved)
+    }
+
+    async fn find_trashed(&self, ledger_ids: &[Uuid]) -> AppResult<Vec<Category>> {
+        if ledger_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let placeholders = vec!["?"; ledger_ids.len()].join(", ");
+        let query = format!(
+            "SELECT id, name, icon, type, parent_id, ledger_id, sort_order, created_at, deleted_at, archived_at FROM categories WHERE deleted_at IS NOT NULL AND ledger_id IN ({}) ORDER BY deleted_at DESC",
+            placeholders
+        );
+
+        let mut q = sqlx::query_as::<_, CategoryRow>(&query);
+        for id in ledger_ids {
+            q = q.bind(id.to_string());
+        }
+
+        let rows = q
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+
+    async fn restore(&self, id: Uuid) -> AppResult<Category> {
+        sqlx::query("UPDATE categories SET deleted_at = NULL WHERE id = ? AND deleted_at IS NOT NULL")
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::Database(e.to_string()))?;
+
+        self.find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Category not found".to_string()))
+    }
+
+    async fn purge(&self, id: Uuid) -> AppResult<()> {
+        sqlx::query("DELETE FROM categories WHERE id = ?")
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn find_purgeable(&self, deleted_before: DateTime<Utc>) -> AppResult<Vec<Category>> {
+        let rows = sqlx::query_as::<_, CategoryRow>(
+            r#"
+            SELECT id, name, icon, type, parent_id, ledger_id, sort_order, created_at, deleted_at, archived_at
+            FROM categories WHERE deleted_at IS NOT NULL AND deleted_at < ?
+            "#,
+        )
+        .bind(deleted_before)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
 }
 
 #[derive(sqlx::FromRow)]
@@ -183,6 +343,8 @@ struct CategoryRow {
     ledger_id: Option<String>,
     sort_order: i32,
     created_at: chrono::DateTime<chrono::Utc>,
+    deleted_at: Option<chrono::DateTime<chrono::Utc>>,
+    archived_at: Option<chrono::DateTime<chrono::Utc>>,
 }
 
 impl From<CategoryRow> for Category {
@@ -199,6 +361,8 @@ impl From<CategoryRow> for Category {
             ledger_id: row.ledger_id.and_then(|id| Uuid::parse_str(&id).ok()),
             sort_order: row.sort_order,
             created_at: row.created_at,
+            deleted_at: row.deleted_at,
+            archived_at: row.archived_at,
         }
     }
 }
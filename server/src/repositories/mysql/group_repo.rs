@@ -4,7 +4,7 @@ use sqlx::MySqlPool;
 use uuid::Uuid;
 
 use crate::error::{AppError, AppResult};
-use crate::models::{CreateGroup, Group, GroupMember, GroupRole};
+use crate::models::{CreateGroup, Group, GroupCapability, GroupMember, GroupPolicy, GroupRole};
 use crate::repositories::traits::GroupRepository;
 
 pub struct MySqlGroupRepository {
@@ -229,6 +229,43 @@ impl GroupRepository for MySqlGroupRepository {
 
         Ok(result.map(|r| r.into()))
     }
+
+    async fn get_policy(&self, group_id: Uuid) -> AppResult<Option<GroupPolicy>> {
+        let result = sqlx::query_as::<_, GroupPolicyRow>(
+            r#"
+            SELECT group_id, admin_capabilities, member_capabilities, updated_at
+            FROM group_policies WHERE group_id = ?
+            "#,
+        )
+        .bind(group_id.to_string())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(result.map(|r| r.into()))
+    }
+
+    async fn save_policy(&self, policy: GroupPolicy) -> AppResult<GroupPolicy> {
+        sqlx::query(
+            r#"
+            INSERT INTO group_policies (group_id, admin_capabilities, member_capabilities, updated_at)
+            VALUES (?, ?, ?, ?)
+            ON DUPLICATE KEY UPDATE
+                admin_capabilities = VALUES(admin_capabilities),
+                member_capabilities = VALUES(member_capabilities),
+                updated_at = VALUES(updated_at)
+            "#,
+        )
+        .bind(policy.group_id.to_string())
+        .bind(join_capabilities(&policy.admin))
+        .bind(join_capabilities(&policy.member))
+        .bind(policy.updated_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(policy)
+    }
 }
 
 #[derive(sqlx::FromRow)]
@@ -280,3 +317,31 @@ impl From<GroupMemberRow> for GroupMember {
         }
     }
 }
+
+#[derive(sqlx::FromRow)]
+struct GroupPolicyRow {
+    group_id: String,
+    admin_capabilities: String,
+    member_capabilities: String,
+    updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<GroupPolicyRow> for GroupPolicy {
+    fn from(row: GroupPolicyRow) -> Self {
+        GroupPolicy {
+            group_id: Uuid::parse_str(&row.group_id).unwrap(),
+            admin: split_capabilities(&row.admin_capabilities),
+            member: split_capabilities(&row.member_capabilities),
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+/// Capabilities are stored as a comma separated list, e.g. `create,edit_own`
+fn join_capabilities(capabilities: &[GroupCapability]) -> String {
+    capabilities.iter().map(|c| c.to_string()).collect::<Vec<_>>().join(",")
+}
+
+fn split_capabilities(value: &str) -> Vec<GroupCapability> {
+    value.split(',').filter_map(|c| c.trim().parse().ok()).collect()
+}
@@ -1,4 +1,5 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use sqlx::MySqlPool;
 use uuid::Uuid;
 
@@ -50,8 +51,8 @@ impl LedgerRepository for MySqlLedgerRepository {
     async fn find_by_id(&self, id: Uuid) -> AppResult<Option<Ledger>> {
         let result = sqlx::query_as::<_, LedgerRow>(
             r#"
-            SELECT id, name, description, type, user_id, group_id, currency, created_at, updated_at
-            FROM ledgers WHERE id = ?
+            SELECT id, name, description, type, user_id, group_id, currency, created_at, updated_at, deleted_at
+            FROM ledgers WHERE id = ? AND deleted_at IS NULL
             "#,
         )
         .bind(id.to_string())
@@ -65,8 +66,8 @@ impl LedgerRepository for MySqlLedgerRepository {
     async fn find_by_user_id(&self, user_id: Uuid) -> AppResult<Vec<Ledger>> {
         let rows = sqlx::query_as::<_, LedgerRow>(
             r#"
-            SELECT id, name, description, type, user_id, group_id, currency, created_at, updated_at
-            FROM ledgers WHERE user_id = ? ORDER BY created_at DESC
+            SELECT id, name, description, type, user_id, group_id, currency, created_at, updated_at, deleted_at
+            FROM ledgers WHERE user_id = ? AND deleted_at IS NULL ORDER BY created_at DESC
             "#,
         )
         .bind(user_id.to_string())
@@ -80,8 +81,8 @@ impl LedgerRepository for MySqlLedgerRepository {
     async fn find_by_group_id(&self, group_id: Uuid) -> AppResult<Vec<Ledger>> {
         let rows = sqlx::query_as::<_, LedgerRow>(
             r#"
-            SELECT id, name, description, type, user_id, group_id, currency, created_at, updated_at
-            FROM ledgers WHERE group_id = ? ORDER BY created_at DESC
+            SELECT id, name, description, type, user_id, group_id, currency, created_at, updated_at, deleted_at
+            FROM ledgers WHERE group_id = ? AND deleted_at IS NULL ORDER BY created_at DESC
             "#,
         )
         .bind(group_id.to_string())
@@ -96,7 +97,7 @@ impl LedgerRepository for MySqlLedgerRepository {
         sqlx::query(
             r#"
             UPDATE ledgers SET name = COALESCE(?, name), description = COALESCE(?, description), updated_at = NOW()
-            WHERE id = ?
+            WHERE id = ? AND deleted_at IS NULL
             "#,
         )
         .bind(&name)
@@ -112,6 +113,58 @@ impl LedgerRepository for MySqlLedgerRepository {
     }
 
     async fn delete(&self, id: Uuid) -> AppResult<()> {
+        sqlx::query("UPDATE ledgers SET deleted_at = NOW() WHERE id = ? AND deleted_at IS NULL")
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn find_trashed_by_id(&self, id: Uuid) -> AppResult<Option<Ledger>> {
+        let result = sqlx::query_as::<_, LedgerRow>(
+            r#"
+            SELECT id, name, description, type, user_id, group_id, currency, created_at, updated_at, deleted_at
+            FROM ledgers WHERE id = ? AND deleted_at IS NOT NULL
+            "#,
+        )
+        .bind(id.to_string())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(result.map(|r| r.into()))
+    }
+
+    async fn find_trashed_by_user_id(&self, user_id: Uuid) -> AppResult<Vec<Ledger>> {
+        let rows = sqlx::query_as::<_, LedgerRow>(
+            r#"
+            SELECT id, name, description, type, user_id, group_id, currency, created_at, updated_at, deleted_at
+            FROM ledgers WHERE user_id = ? AND deleted_at IS NOT NULL ORDER BY deleted_at DESC
+            "#,
+        )
+        .bind(user_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+
+    async fn restore(&self, id: Uuid) -> AppResult<Ledger> {
+        sqlx::query("UPDATE ledgers SET deleted_at = NULL, updated_at = NOW() WHERE id = ? AND deleted_at IS NOT NULL")
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::Database(e.to_string()))?;
+
+        self.find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Ledger not found".to_string()))
+    }
+
+    async fn purge(&self, id: Uuid) -> AppResult<()> {
         sqlx::query("DELETE FROM ledgers WHERE id = ?")
             .bind(id.to_string())
             .execute(&self.pool)
@@ -120,6 +173,21 @@ impl LedgerRepository for MySqlLedgerRepository {
 
         Ok(())
     }
+
+    async fn find_purgeable(&self, deleted_before: DateTime<Utc>) -> AppResult<Vec<Ledger>> {
+        let rows = sqlx::query_as::<_, LedgerRow>(
+            r#"
+            SELECT id, name, description, type, user_id, group_id, currency, created_at, updated_at, deleted_at
+            FROM ledgers WHERE deleted_at IS NOT NULL AND deleted_at < ?
+            "#,
+        )
+        .bind(deleted_before)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
 }
 
 #[derive(sqlx::FromRow)]
@@ -134,6 +202,7 @@ struct LedgerRow {
     currency: String,
     created_at: chrono::DateTime<chrono::Utc>,
     updated_at: chrono::DateTime<chrono::Utc>,
+    deleted_at: Option<chrono::DateTime<chrono::Utc>>,
 }
 
 impl From<LedgerRow> for Ledger {
@@ -151,6 +220,7 @@ impl From<LedgerRow> for Ledger {
             currency: row.currency,
             created_at: row.created_at,
             updated_at: row.updated_at,
+            deleted_at: row.deleted_at,
         }
     }
 }
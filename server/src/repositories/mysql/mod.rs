@@ -3,9 +3,23 @@ pub mod ledger_repo;
 pub mod bill_repo;
 pub mod category_repo;
 pub mod group_repo;
+pub mod attachment_repo;
+pub mod audit_repo;
+pub mod rule_repo;
+pub mod saved_view_repo;
+pub mod notification_repo;
+pub mod asset_repo;
+pub mod net_worth_repo;
 
 pub use user_repo::MySqlUserRepository;
 pub use ledger_repo::MySqlLedgerRepository;
 pub use bill_repo::MySqlBillRepository;
 pub use category_repo::MySqlCategoryRepository;
 pub use group_repo::MySqlGroupRepository;
+pub use attachment_repo::MySqlAttachmentRepository;
+pub use audit_repo::MySqlAuditRepository;
+pub use rule_repo::MySqlRuleRepository;
+pub use saved_view_repo::MySqlSavedViewRepository;
+pub use notification_repo::MySqlNotificationRepository;
+pub use asset_repo::MySqlAssetRepository;
+pub use net_worth_repo::MySqlNetWorthRepository;
@@ -0,0 +1,101 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use sqlx::MySqlPool;
+use uuid::Uuid;
+
+use crate::error::{AppError, AppResult};
+use crate::models::{CreateNetWorthSnapshot, NetWorthSnapshot};
+use crate::repositories::traits::NetWorthRepository;
+
+pub struct MySqlNetWorthRepository {
+    pool: MySqlPool,
+}
+
+impl MySqlNetWorthRepository {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl NetWorthRepository for MySqlNetWorthRepository {
+    async fn save_day(&self, user_id: Uuid, snapshot_date: NaiveDate, snapshots: Vec<CreateNetWorthSnapshot>) -> AppResult<Vec<NetWorthSnapshot>> {
+        let mut tx = self.pool.begin().await.map_err(|e| AppError::Database(e.to_string()))?;
+
+        sqlx::query("DELETE FROM net_worth_snapshots WHERE user_id = ? AND snapshot_date = ?")
+            .bind(user_id.to_string())
+            .bind(snapshot_date)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| AppError::Database(e.to_string()))?;
+
+        let mut saved = Vec::new();
+        for snapshot in snapshots {
+            let new_snapshot = NetWorthSnapshot::new(snapshot);
+
+            sqlx::query(
+                r#"
+                INSERT INTO net_worth_snapshots (id, user_id, snapshot_date, currency, assets, liabilities, created_at)
+                VALUES (?, ?, ?, ?, ?, ?, ?)
+                "#,
+            )
+            .bind(new_snapshot.id.to_string())
+            .bind(new_snapshot.user_id.to_string())
+            .bind(new_snapshot.snapshot_date)
+            .bind(&new_snapshot.currency)
+            .bind(new_snapshot.assets)
+            .bind(new_snapshot.liabilities)
+            .bind(new_snapshot.created_at)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| AppError::Database(e.to_string()))?;
+
+            saved.push(new_snapshot);
+        }
+
+        tx.commit().await.map_err(|e| AppError::Database(e.to_string()))?;
+        Ok(saved)
+    }
+
+    async fn find_by_user_id(&self, user_id: Uuid, start_date: NaiveDate) -> AppResult<Vec<NetWorthSnapshot>> {
+        let rows = sqlx::query_as::<_, NetWorthSnapshotRow>(
+            r#"
+            SELECT id, user_id, snapshot_date, currency, assets, liabilities, created_at
+            FROM net_worth_snapshots WHERE user_id = ? AND snapshot_date >= ?
+            ORDER BY snapshot_date ASC, currency ASC
+            "#,
+        )
+        .bind(user_id.to_string())
+        .bind(start_date)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct NetWorthSnapshotRow {
+    id: String,
+    user_id: String,
+    snapshot_date: NaiveDate,
+    currency: String,
+    assets: f64,
+    liabilities: f64,
+    created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<NetWorthSnapshotRow> for NetWorthSnapshot {
+    fn from(row: NetWorthSnapshotRow) -> Self {
+        NetWorthSnapshot {
+            id: Uuid::parse_str(&row.id).unwrap(),
+            user_id: Uuid::parse_str(&row.user_id).unwrap(),
+            snapshot_date: row.snapshot_date,
+            currency: row.currency,
+            assets: row.assets,
+            liabilities: row.liabilities,
+            created_at: row.created_at,
+        }
+    }
+}
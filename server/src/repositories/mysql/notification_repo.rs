@@ -0,0 +1,151 @@
+use async_trait::async_trait;
+use sqlx::MySqlPool;
+use uuid::Uuid;
+
+use crate::error::{AppError, AppResult};
+use crate::models::{CreateNotification, Notification};
+use crate::repositories::traits::NotificationRepository;
+
+pub struct MySqlNotificationRepository {
+    pool: MySqlPool,
+}
+
+impl MySqlNotificationRepository {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl NotificationRepository for MySqlNotificationRepository {
+    async fn create(&self, notification: CreateNotification) -> AppResult<Notification> {
+        let new_notification = Notification::new(notification);
+
+        sqlx::query(
+            r#"
+            INSERT INTO notifications (id, user_id, kind, ledger_id, bill_id, data, created_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(new_notification.id.to_string())
+        .bind(new_notification.user_id.to_string())
+        .bind(new_notification.kind.to_string())
+        .bind(new_notification.ledger_id.map(|id| id.to_string()))
+        .bind(new_notification.bill_id.map(|id| id.to_string()))
+        .bind(new_notification.data.to_string())
+        .bind(new_notification.created_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(new_notification)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> AppResult<Option<Notification>> {
+        let row = sqlx::query_as::<_, NotificationRow>(
+            r#"
+            SELECT id, user_id, kind, ledger_id, bill_id, CAST(data AS CHAR) AS data, read_at, created_at
+            FROM notifications WHERE id = ?
+            "#,
+        )
+        .bind(id.to_string())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(e.to_string()))?;
+
+        row.map(Notification::try_from).transpose()
+    }
+
+    async fn find_by_user_id(&self, user_id: Uuid, unread_only: bool, page: u32, page_size: u32) -> AppResult<(Vec<Notification>, u64)> {
+        let offset = (page.max(1) - 1) * page_size;
+        let unread = if unread_only { " AND read_at IS NULL" } else { "" };
+
+        let query = format!(
+            r#"
+            SELECT id, user_id, kind, ledger_id, bill_id, CAST(data AS CHAR) AS data, read_at, created_at
+            FROM notifications WHERE user_id = ?{} ORDER BY created_at DESC LIMIT ? OFFSET ?
+            "#,
+            unread
+        );
+        let rows = sqlx::query_as::<_, NotificationRow>(&query)
+            .bind(user_id.to_string())
+            .bind(page_size as i64)
+            .bind(offset as i64)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| AppError::Database(e.to_string()))?;
+
+        let count_query = format!("SELECT COUNT(*) FROM notifications WHERE user_id = ?{}", unread);
+        let total: i64 = sqlx::query_scalar(&count_query)
+            .bind(user_id.to_string())
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| AppError::Database(e.to_string()))?;
+
+        let notifications = rows.into_iter().map(Notification::try_from).collect::<AppResult<Vec<_>>>()?;
+        Ok((notifications, total as u64))
+    }
+
+    async fn count_unread(&self, user_id: Uuid) -> AppResult<u64> {
+        let total: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM notifications WHERE user_id = ? AND read_at IS NULL")
+            .bind(user_id.to_string())
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(total as u64)
+    }
+
+    async fn mark_read(&self, id: Uuid) -> AppResult<()> {
+        sqlx::query("UPDATE notifications SET read_at = NOW(6) WHERE id = ? AND read_at IS NULL")
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn mark_all_read(&self, user_id: Uuid) -> AppResult<()> {
+        sqlx::query("UPDATE notifications SET read_at = NOW(6) WHERE user_id = ? AND read_at IS NULL")
+            .bind(user_id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct NotificationRow {
+    id: String,
+    user_id: String,
+    kind: String,
+    ledger_id: Option<String>,
+    bill_id: Option<String>,
+    data: String,
+    read_at: Option<chrono::DateTime<chrono::Utc>>,
+    created_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Fails on a kind this version does not know, e.g. one written by a newer server
+impl TryFrom<NotificationRow> for Notification {
+    type Error = AppError;
+
+    fn try_from(row: NotificationRow) -> AppResult<Self> {
+        Ok(Notification {
+            id: Uuid::parse_str(&row.id).unwrap(),
+            user_id: Uuid::parse_str(&row.user_id).unwrap(),
+            kind: row
+                .kind
+                .parse()
+                .map_err(|e| AppError::Internal(format!("Notification {}: {}", row.id, e)))?,
+            ledger_id: row.ledger_id.and_then(|id| Uuid::parse_str(&id).ok()),
+            bill_id: row.bill_id.and_then(|id| Uuid::parse_str(&id).ok()),
+            data: serde_json::from_str(&row.data).unwrap_or(serde_json::Value::Null),
+            read_at: row.read_at,
+            created_at: row.created_at,
+        })
+    }
+}
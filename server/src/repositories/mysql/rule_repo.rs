@@ -0,0 +1,147 @@
+use async_trait::async_trait;
+use sqlx::MySqlPool;
+use uuid::Uuid;
+
+use crate::error::{AppError, AppResult};
+use crate::models::{CategoryRule, CreateCategoryRule, UpdateCategoryRule};
+use crate::repositories::traits::RuleRepository;
+
+pub struct MySqlRuleRepository {
+    pool: MySqlPool,
+}
+
+impl MySqlRuleRepository {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl RuleRepository for MySqlRuleRepository {
+    async fn create(&self, rule: CreateCategoryRule) -> AppResult<CategoryRule> {
+        let new_rule = CategoryRule::new(rule);
+
+        sqlx::query(
+            r#"
+            INSERT INTO category_rules (id, ledger_id, name, priority, enabled, conditions, actions, created_by, created_at, updated_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(new_rule.id.to_string())
+        .bind(new_rule.ledger_id.to_string())
+        .bind(&new_rule.name)
+        .bind(new_rule.priority)
+        .bind(new_rule.enabled)
+        .bind(serde_json::to_string(&new_rule.conditions).unwrap())
+        .bind(serde_json::to_string(&new_rule.actions).unwrap())
+        .bind(new_rule.created_by.to_string())
+        .bind(new_rule.created_at)
+        .bind(new_rule.updated_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(new_rule)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> AppResult<Option<CategoryRule>> {
+        let row = sqlx::query_as::<_, RuleRow>(
+            r#"
+            SELECT id, ledger_id, name, priority, enabled, CAST(conditions AS CHAR) AS conditions,
+                   CAST(actions AS CHAR) AS actions, created_by, created_at, updated_at
+            FROM category_rules WHERE id = ?
+            "#,
+        )
+        .bind(id.to_string())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(row.map(|r| r.into()))
+    }
+
+    async fn find_by_ledger_id(&self, ledger_id: Uuid) -> AppResult<Vec<CategoryRule>> {
+        let rows = sqlx::query_as::<_, RuleRow>(
+            r#"
+            SELECT id, ledger_id, name, priority, enabled, CAST(conditions AS CHAR) AS conditions,
+                   CAST(actions AS CHAR) AS actions, created_by, created_at, updated_at
+            FROM category_rules WHERE ledger_id = ? ORDER BY priority ASC, created_at ASC
+            "#,
+        )
+        .bind(ledger_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+
+    async fn update(&self, id: Uuid, rule: UpdateCategoryRule) -> AppResult<CategoryRule> {
+        sqlx::query(
+            r#"
+            UPDATE category_rules SET
+                name = COALESCE(?, name),
+                priority = COALESCE(?, priority),
+                enabled = COALESCE(?, enabled),
+                conditions = COALESCE(?, conditions),
+                actions = COALESCE(?, actions),
+                updated_at = NOW()
+            WHERE id = ?
+            "#,
+        )
+        .bind(&rule.name)
+        .bind(rule.priority)
+        .bind(rule.enabled)
+        .bind(rule.conditions.map(|c| serde_json::to_string(&c).unwrap()))
+        .bind(rule.actions.map(|a| serde_json::to_string(&a).unwrap()))
+        .bind(id.to_string())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(e.to_string()))?;
+
+        self.find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Rule not found".to_string()))
+    }
+
+    async fn delete(&self, id: Uuid) -> AppResult<()> {
+        sqlx::query("DELETE FROM category_rules WHERE id = ?")
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct RuleRow {
+    id: String,
+    ledger_id: String,
+    name: String,
+    priority: i32,
+    enabled: bool,
+    conditions: String,
+    actions: String,
+    created_by: String,
+    created_at: chrono::DateTime<chrono::Utc>,
+    updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<RuleRow> for CategoryRule {
+    fn from(row: RuleRow) -> Self {
+        CategoryRule {
+            id: Uuid::parse_str(&row.id).unwrap(),
+            ledger_id: Uuid::parse_str(&row.ledger_id).unwrap(),
+            name: row.name,
+            priority: row.priority,
+            enabled: row.enabled,
+            conditions: serde_json::from_str(&row.conditions).unwrap_or_default(),
+            actions: serde_json::from_str(&row.actions).unwrap_or_default(),
+            created_by: Uuid::parse_str(&row.created_by).unwrap(),
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
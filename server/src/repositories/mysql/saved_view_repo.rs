@@ -0,0 +1,143 @@
+use async_trait::async_trait;
+use sqlx::MySqlPool;
+use uuid::Uuid;
+
+use crate::error::{AppError, AppResult};
+use crate::models::{CreateSavedView, SavedView, UpdateSavedView};
+use crate::repositories::traits::SavedViewRepository;
+
+pub struct MySqlSavedViewRepository {
+    pool: MySqlPool,
+}
+
+impl MySqlSavedViewRepository {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl SavedViewRepository for MySqlSavedViewRepository {
+    async fn create(&self, view: CreateSavedView) -> AppResult<SavedView> {
+        let new_view = SavedView::new(view);
+
+        sqlx::query(
+            r#"
+            INSERT INTO saved_views (id, user_id, ledger_id, name, filter, shared, created_at, updated_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(new_view.id.to_string())
+        .bind(new_view.user_id.to_string())
+        .bind(new_view.ledger_id.to_string())
+        .bind(&new_view.name)
+        .bind(serde_json::to_string(&new_view.filter).unwrap())
+        .bind(new_view.shared)
+        .bind(new_view.created_at)
+        .bind(new_view.updated_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(new_view)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> AppResult<Option<SavedView>> {
+        let row = sqlx::query_as::<_, SavedViewRow>(
+            r#"
+            SELECT id, user_id, ledger_id, name, CAST(filter AS CHAR) AS filter, shared, created_at, updated_at
+            FROM saved_views WHERE id = ?
+            "#,
+        )
+        .bind(id.to_string())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(e.to_string()))?;
+
+        row.map(SavedView::try_from).transpose()
+    }
+
+    async fn find_by_ledger_id(&self, ledger_id: Uuid) -> AppResult<Vec<SavedView>> {
+        let rows = sqlx::query_as::<_, SavedViewRow>(
+            r#"
+            SELECT id, user_id, ledger_id, name, CAST(filter AS CHAR) AS filter, shared, created_at, updated_at
+            FROM saved_views WHERE ledger_id = ? ORDER BY name ASC, created_at ASC
+            "#,
+        )
+        .bind(ledger_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(e.to_string()))?;
+
+        rows.into_iter().map(SavedView::try_from).collect()
+    }
+
+    async fn update(&self, id: Uuid, view: UpdateSavedView) -> AppResult<SavedView> {
+        sqlx::query(
+            r#"
+            UPDATE saved_views SET
+                name = COALESCE(?, name),
+                filter = COALESCE(?, filter),
+                shared = COALESCE(?, shared),
+                updated_at = NOW()
+            WHERE id = ?
+            "#,
+        )
+        .bind(&view.name)
+        .bind(view.filter.map(|f| serde_json::to_string(&f).unwrap()))
+        .bind(view.shared)
+        .bind(id.to_string())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(e.to_string()))?;
+
+        self.find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("View not found".to_string()))
+    }
+
+    async fn delete(&self, id: Uuid) -> AppResult<()> {
+        sqlx::query("DELETE FROM saved_views WHERE id = ?")
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct SavedViewRow {
+    id: String,
+    user_id: String,
+    ledger_id: String,
+    name: String,
+    filter: String,
+    shared: bool,
+    created_at: chrono::DateTime<chrono::Utc>,
+    updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// A filter that no longer parses is an error, treating it as empty would match every bill
+impl TryFrom<SavedViewRow> for SavedView {
+    type Error = AppError;
+
+    fn try_from(row: SavedViewRow) -> AppResult<Self> {
+        let filter = serde_json::from_str(&row.filter).map_err(|e| {
+            tracing::error!("Saved view {} has an unreadable filter: {}", row.id, e);
+            AppError::Internal(format!("Saved view {} has an unreadable filter", row.id))
+        })?;
+
+        Ok(SavedView {
+            id: Uuid::parse_str(&row.id).unwrap(),
+            user_id: Uuid::parse_str(&row.user_id).unwrap(),
+            ledger_id: Uuid::parse_str(&row.ledger_id).unwrap(),
+            name: row.name,
+            filter,
+            shared: row.shared,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        })
+    }
+}
@@ -6,6 +6,38 @@ use crate::error::{AppError, AppResult};
 use crate::models::{CreateUser, UpdateUser, User};
 use crate::repositories::traits::UserRepository;
 
+type UserRow = (
+    String,
+    String,
+    String,
+    Option<String>,
+    Option<String>,
+    Option<String>,
+    Option<i32>,
+    Option<u32>,
+    Option<u32>,
+    chrono::DateTime<chrono::Utc>,
+    chrono::DateTime<chrono::Utc>,
+);
+
+fn user_from_row(
+    (id, email, password_hash, nickname, avatar, locale, utc_offset, week_start, month_start_day, created_at, updated_at): UserRow,
+) -> User {
+    User {
+        id: Uuid::parse_str(&id).unwrap(),
+        email,
+        password_hash,
+        nickname,
+        avatar,
+        locale: locale.and_then(|l| l.parse().ok()),
+        utc_offset,
+        week_start,
+        month_start_day,
+        created_at,
+        updated_at,
+    }
+}
+
 pub struct MySqlUserRepository {
     pool: MySqlPool,
 }
@@ -41,9 +73,9 @@ impl UserRepository for MySqlUserRepository {
     }
 
     async fn find_by_id(&self, id: Uuid) -> AppResult<Option<User>> {
-        let result = sqlx::query_as::<_, (String, String, String, Option<String>, Option<String>, chrono::DateTime<chrono::Utc>, chrono::DateTime<chrono::Utc>)>(
+        let result = sqlx::query_as::<_, UserRow>(
             r#"
-            SELECT id, email, password_hash, nickname, avatar, created_at, updated_at
+            SELECT id, email, password_hash, nickname, avatar, locale, utc_offset, week_start, month_start_day, created_at, updated_at
             FROM users WHERE id = ?
             "#,
         )
@@ -52,21 +84,13 @@ impl UserRepository for MySqlUserRepository {
         .await
         .map_err(|e| AppError::Database(e.to_string()))?;
 
-        Ok(result.map(|(id, email, password_hash, nickname, avatar, created_at, updated_at)| User {
-            id: Uuid::parse_str(&id).unwrap(),
-            email,
-            password_hash,
-            nickname,
-            avatar,
-            created_at,
-            updated_at,
-        }))
+        Ok(result.map(user_from_row))
     }
 
     async fn find_by_email(&self, email: &str) -> AppResult<Option<User>> {
-        let result = sqlx::query_as::<_, (String, String, String, Option<String>, Option<String>, chrono::DateTime<chrono::Utc>, chrono::DateTime<chrono::Utc>)>(
+        let result = sqlx::query_as::<_, UserRow>(
             r#"
-            SELECT id, email, password_hash, nickname, avatar, created_at, updated_at
+            SELECT id, email, password_hash, nickname, avatar, locale, utc_offset, week_start, month_start_day, created_at, updated_at
             FROM users WHERE email = ?
             "#,
         )
@@ -75,26 +99,38 @@ impl UserRepository for MySqlUserRepository {
         .await
         .map_err(|e| AppError::Database(e.to_string()))?;
 
-        Ok(result.map(|(id, email, password_hash, nickname, avatar, created_at, updated_at)| User {
-            id: Uuid::parse_str(&id).unwrap(),
-            email,
-            password_hash,
-            nickname,
-            avatar,
-            created_at,
-            updated_at,
-        }))
+        Ok(result.map(user_from_row))
+    }
+
+    async fn find_all(&self) -> AppResult<Vec<User>> {
+        let rows = sqlx::query_as::<_, UserRow>(
+            r#"
+            SELECT id, email, password_hash, nickname, avatar, locale, utc_offset, week_start, month_start_day, created_at, updated_at
+            FROM users
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(rows.into_iter().map(user_from_row).collect())
     }
 
     async fn update(&self, id: Uuid, user: UpdateUser) -> AppResult<User> {
         sqlx::query(
             r#"
-            UPDATE users SET nickname = COALESCE(?, nickname), avatar = COALESCE(?, avatar), updated_at = NOW()
+            UPDATE users SET nickname = COALESCE(?, nickname), avatar = COALESCE(?, avatar), locale = COALESCE(?, locale),
+                utc_offset = COALESCE(?, utc_offset), week_start = COALESCE(?, week_start),
+                month_start_day = COALESCE(?, month_start_day), updated_at = NOW()
             WHERE id = ?
             "#,
         )
         .bind(&user.nickname)
         .bind(&user.avatar)
+        .bind(user.locale.map(|l| l.to_string()))
+        .bind(user.utc_offset)
+        .bind(user.week_start)
+        .bind(user.month_start_day)
         .bind(id.to_string())
         .execute(&self.pool)
         .await
@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::error::AppResult;
+use crate::models::{Asset, CreateAsset, UpdateAsset};
+
+#[async_trait]
+pub trait AssetRepository: Send + Sync {
+    async fn create(&self, asset: CreateAsset) -> AppResult<Asset>;
+    async fn find_by_id(&self, id: Uuid) -> AppResult<Option<Asset>>;
+    /// A user's assets and liabilities, by name
+    async fn find_by_user_id(&self, user_id: Uuid) -> AppResult<Vec<Asset>>;
+    async fn update(&self, id: Uuid, asset: UpdateAsset) -> AppResult<Asset>;
+    async fn delete(&self, id: Uuid) -> AppResult<()>;
+}
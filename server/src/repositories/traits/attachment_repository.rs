@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::error::AppResult;
+use crate::models::{Attachment, CreateAttachment};
+
+#[async_trait]
+pub trait AttachmentRepository: Send + Sync {
+    async fn create(&self, attachment: CreateAttachment) -> AppResult<Attachment>;
+    async fn find_by_id(&self, id: Uuid) -> AppResult<Option<Attachment>>;
+    async fn find_by_bill_id(&self, bill_id: Uuid) -> AppResult<Vec<Attachment>>;
+    async fn delete(&self, id: Uuid) -> AppResult<()>;
+    async fn delete_by_bill_id(&self, bill_id: Uuid) -> AppResult<()>;
+}
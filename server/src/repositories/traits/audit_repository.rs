@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::error::AppResult;
+use crate::models::{AuditEntityType, AuditEvent, CreateAuditEvent};
+
+/// Audit events are append-only: there is no update or delete
+#[async_trait]
+pub trait AuditRepository: Send + Sync {
+    async fn create(&self, event: CreateAuditEvent) -> AppResult<AuditEvent>;
+    /// All events of one entity, oldest first
+    async fn find_by_entity(&self, entity_type: AuditEntityType, entity_id: Uuid) -> AppResult<Vec<AuditEvent>>;
+    /// Events within a group, newest first
+    async fn find_by_group_id(&self, group_id: Uuid, page: u32, page_size: u32) -> AppResult<(Vec<AuditEvent>, u64)>;
+}
@@ -1,9 +1,13 @@
 use async_trait::async_trait;
-use chrono::NaiveDate;
+use chrono::{DateTime, NaiveDate, Utc};
 use uuid::Uuid;
 
 use crate::error::AppResult;
-use crate::models::{Bill, BillType, CreateBill, UpdateBill};
+use crate::models::{Bill, BillSort, BillType, CreateBill, UpdateBill};
+use crate::utils::Calendar;
+
+/// Page size `find_all_by_filter` fetches with
+const SCAN_PAGE_SIZE: u32 = 500;
 
 #[derive(Debug, Clone)]
 pub struct BillFilter {
@@ -11,12 +15,45 @@ pub struct BillFilter {
     pub start_date: Option<NaiveDate>,
     pub end_date: Option<NaiveDate>,
     pub bill_type: Option<BillType>,
-    pub category_id: Option<Uuid>,
-    pub user_id: Option<Uuid>,
+    pub category_ids: Vec<Uuid>,  // Any of these, empty for all
+    pub user_ids: Vec<Uuid>,  // Created by any of these, empty for all
+    pub search: Vec<SearchTerm>,  // Every term must match
+    pub min_amount: Option<f64>,
+    pub max_amount: Option<f64>,
+    pub has_note: Option<bool>,  // Whitespace-only notes count as none
+    pub sort: BillSort,
     pub page: u32,
     pub page_size: u32,
 }
 
+impl Default for BillFilter {
+    fn default() -> Self {
+        Self {
+            ledger_id: Uuid::nil(),
+            start_date: None,
+            end_date: None,
+            bill_type: None,
+            category_ids: Vec::new(),
+            user_ids: Vec::new(),
+            search: Vec::new(),
+            min_amount: None,
+            max_amount: None,
+            has_note: None,
+            sort: BillSort::default(),
+            page: 1,
+            page_size: 20,
+        }
+    }
+}
+
+/// One search keyword: a bill matches when its note contains the word
+/// or its category is one of the categories whose name matched it
+#[derive(Debug, Clone)]
+pub struct SearchTerm {
+    pub word: String,  // Lowercase
+    pub category_ids: Vec<Uuid>,
+}
+
 #[derive(Debug, Clone)]
 pub struct BillStatistics {
     pub total_income: f64,
@@ -35,6 +72,59 @@ pub struct CategoryStatistics {
     pub percentage: f64,
 }
 
+/// What one member recorded in a ledger
+#[derive(Debug, Clone)]
+pub struct MemberStatistics {
+    pub user_id: Uuid,
+    pub total_income: f64,
+    pub total_expense: f64,
+    pub count: u32,
+    pub categories: Vec<MemberCategoryStatistics>,  // Largest amount first
+}
+
+#[derive(Debug, Clone)]
+pub struct MemberCategoryStatistics {
+    pub category_id: Uuid,
+    pub bill_type: BillType,
+    pub amount: f64,
+    pub count: u32,
+}
+
+impl MemberStatistics {
+    /// Builds per-member totals from per member and category sums, biggest spender first
+    pub fn from_categories(rows: Vec<(Uuid, MemberCategoryStatistics)>) -> Vec<Self> {
+        let mut members: Vec<MemberStatistics> = Vec::new();
+        for (user_id, category) in rows {
+            let position = match members.iter().position(|m| m.user_id == user_id) {
+                Some(position) => position,
+                None => {
+                    members.push(MemberStatistics {
+                        user_id,
+                        total_income: 0.0,
+                        total_expense: 0.0,
+                        count: 0,
+                        categories: Vec::new(),
+                    });
+                    members.len() - 1
+                }
+            };
+            let member = &mut members[position];
+            match category.bill_type {
+                BillType::Income => member.total_income += category.amount,
+                BillType::Expense => member.total_expense += category.amount,
+            }
+            member.count += category.count;
+            member.categories.push(category);
+        }
+
+        for member in members.iter_mut() {
+            member.categories.sort_by(|a, b| b.amount.total_cmp(&a.amount));
+        }
+        members.sort_by(|a, b| b.total_expense.total_cmp(&a.total_expense).then(b.total_income.total_cmp(&a.total_income)));
+        members
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct DailyStatistics {
     pub date: NaiveDate,
@@ -42,6 +132,14 @@ pub struct DailyStatistics {
     pub expense: f64,
 }
 
+#[derive(Debug, Clone)]
+pub struct HourlyStatistics {
+    pub date: NaiveDate,
+    pub hour: Option<u32>,  // None for bills entered without a time
+    pub amount: f64,
+    pub count: u32,
+}
+
 #[derive(Debug, Clone)]
 pub struct TrendStatistics {
     pub period: String,  // e.g., "2025-01", "2025-W01", "2025-01-01"
@@ -55,18 +153,61 @@ pub trait BillRepository: Send + Sync {
     async fn create(&self, bill: CreateBill) -> AppResult<Bill>;
     async fn find_by_id(&self, id: Uuid) -> AppResult<Option<Bill>>;
     async fn find_by_filter(&self, filter: BillFilter) -> AppResult<(Vec<Bill>, u64)>;
+    /// Every bill matching the filter, fetched page by page; `page` and `page_size` are ignored
+    async fn find_all_by_filter(&self, filter: BillFilter) -> AppResult<Vec<Bill>> {
+        let mut filter = BillFilter {
+            page: 1,
+            page_size: SCAN_PAGE_SIZE,
+            ..filter
+        };
+        let mut all = Vec::new();
+        loop {
+            let (bills, total) = self.find_by_filter(filter.clone()).await?;
+            let done = bills.is_empty();
+            all.extend(bills);
+            if done || all.len() as u64 >= total {
+                return Ok(all);
+            }
+            filter.page += 1;
+        }
+    }
     async fn update(&self, id: Uuid, bill: UpdateBill) -> AppResult<Bill>;
+    /// Moves the bill to the trash; use `purge` to remove it permanently
     async fn delete(&self, id: Uuid) -> AppResult<()>;
 
+    // 批量操作，全部成功或全部不生效
+    async fn create_many(&self, bills: Vec<CreateBill>) -> AppResult<Vec<Bill>>;
+    async fn update_many(&self, updates: Vec<(Uuid, UpdateBill)>) -> AppResult<Vec<Bill>>;
+    async fn delete_many(&self, ids: &[Uuid]) -> AppResult<()>;
+
+    // 回收站
+    async fn find_trashed_by_id(&self, id: Uuid) -> AppResult<Option<Bill>>;
+    async fn find_trashed_by_user_id(&self, user_id: Uuid) -> AppResult<Vec<Bill>>;
+    async fn restore(&self, id: Uuid) -> AppResult<Bill>;
+    async fn purge(&self, id: Uuid) -> AppResult<()>;
+    /// Trashed bills deleted before the given time
+    async fn find_purgeable(&self, deleted_before: DateTime<Utc>) -> AppResult<Vec<Bill>>;
+    /// IDs of every bill in the ledger, trashed or not
+    async fn find_all_ids_by_ledger_id(&self, ledger_id: Uuid) -> AppResult<Vec<Uuid>>;
+    /// Number of bills (including trashed ones) that reference the category
+    async fn count_by_category_id(&self, category_id: Uuid) -> AppResult<u64>;
+    async fn reassign_category(&self, from_category_id: Uuid, to_category_id: Uuid) -> AppResult<u64>;
+
     // 基础统计
     async fn get_statistics(&self, ledger_id: Uuid, start_date: Option<NaiveDate>, end_date: Option<NaiveDate>) -> AppResult<BillStatistics>;
 
     // 分类统计
     async fn get_category_statistics(&self, ledger_id: Uuid, start_date: Option<NaiveDate>, end_date: Option<NaiveDate>, bill_type: Option<BillType>) -> AppResult<Vec<CategoryStatistics>>;
 
+    // 成员统计
+    async fn get_member_statistics(&self, ledger_id: Uuid, start_date: Option<NaiveDate>, end_date: Option<NaiveDate>) -> AppResult<Vec<MemberStatistics>>;
+
     // 每日统计
     async fn get_daily_statistics(&self, ledger_id: Uuid, start_date: NaiveDate, end_date: NaiveDate) -> AppResult<Vec<DailyStatistics>>;
 
-    // 趋势统计 (按月/周/日)
-    async fn get_trend_statistics(&self, ledger_id: Uuid, start_date: NaiveDate, end_date: NaiveDate, group_by: &str) -> AppResult<Vec<TrendStatistics>>;
+    // 趋势统计 (按月/周/日)，周和月的起点取自 calendar
+    async fn get_trend_statistics(&self, ledger_id: Uuid, start_date: NaiveDate, end_date: NaiveDate, group_by: &str, calendar: &Calendar) -> AppResult<Vec<TrendStatistics>>;
+
+    // 按日期和小时统计，只含有账单的日期和小时
+    async fn get_hourly_statistics(&self, ledger_id: Uuid, start_date: NaiveDate, end_date: NaiveDate, bill_type: BillType) -> AppResult<Vec<HourlyStatistics>>;
 }
@@ -1,16 +1,48 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 use crate::error::AppResult;
 use crate::models::{BillType, Category, CreateCategory};
+use crate::repositories::traits::BillRepository;
 
 #[async_trait]
 pub trait CategoryRepository: Send + Sync {
     async fn create(&self, category: CreateCategory) -> AppResult<Category>;
+    /// Also returns trashed categories so existing bills can still show their category
     async fn find_by_id(&self, id: Uuid) -> AppResult<Option<Category>>;
-    async fn find_by_ledger_id(&self, ledger_id: Option<Uuid>, category_type: Option<BillType>) -> AppResult<Vec<Category>>;
+    async fn find_by_ledger_id(
+        &self,
+        ledger_id: Option<Uuid>,
+        category_type: Option<BillType>,
+        include_archived: bool,
+    ) -> AppResult<Vec<Category>>;
     async fn update(&self, id: Uuid, name: Option<String>, icon: Option<String>, sort_order: Option<i32>) -> AppResult<Category>;
+    /// Moves the category to the trash; use `purge` to remove it permanently
     async fn delete(&self, id: Uuid) -> AppResult<()>;
 
     async fn init_default_categories(&self) -> AppResult<()>;
+
+    // 整理
+    /// Fails with a validation error when `parent_id` is the category itself or one of its descendants
+    async fn set_parent(&self, id: Uuid, parent_id: Option<Uuid>) -> AppResult<Category>;
+    async fn set_archived(&self, id: Uuid, archived: bool) -> AppResult<Category>;
+    /// Moves every bill of the category, trashed ones too, and its `child_ids` to `target_id`,
+    /// then moves the category to the trash. Returns how many bills moved.
+    /// Backends with transactions override this to do it all or nothing.
+    async fn merge(&self, bill_repo: &dyn BillRepository, id: Uuid, target_id: Uuid, child_ids: &[Uuid]) -> AppResult<u64> {
+        let moved = bill_repo.reassign_category(id, target_id).await?;
+        for child_id in child_ids {
+            self.set_parent(*child_id, Some(target_id)).await?;
+        }
+        self.delete(id).await?;
+        Ok(moved)
+    }
+
+    // 回收站
+    /// Trashed categories that belong to one of the ledgers, system categories are never listed
+    async fn find_trashed(&self, ledger_ids: &[Uuid]) -> AppResult<Vec<Category>>;
+    async fn restore(&self, id: Uuid) -> AppResult<Category>;
+    async fn purge(&self, id: Uuid) -> AppResult<()>;
+    async fn find_purgeable(&self, deleted_before: DateTime<Utc>) -> AppResult<Vec<Category>>;
 }
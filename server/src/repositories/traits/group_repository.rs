@@ -2,7 +2,7 @@ use async_trait::async_trait;
 use uuid::Uuid;
 
 use crate::error::AppResult;
-use crate::models::{CreateGroup, Group, GroupMember, GroupRole};
+use crate::models::{CreateGroup, Group, GroupMember, GroupPolicy, GroupRole};
 
 #[async_trait]
 pub trait GroupRepository: Send + Sync {
@@ -21,4 +21,9 @@ pub trait GroupRepository: Send + Sync {
     async fn update_member_role(&self, group_id: Uuid, user_id: Uuid, role: GroupRole) -> AppResult<()>;
     async fn get_members(&self, group_id: Uuid) -> AppResult<Vec<GroupMember>>;
     async fn get_member(&self, group_id: Uuid, user_id: Uuid) -> AppResult<Option<GroupMember>>;
+
+    // Permission policy
+    /// None until the owner saves a policy, callers fall back to `GroupPolicy::default_for`
+    async fn get_policy(&self, group_id: Uuid) -> AppResult<Option<GroupPolicy>>;
+    async fn save_policy(&self, policy: GroupPolicy) -> AppResult<GroupPolicy>;
 }
@@ -1,4 +1,5 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 use crate::error::AppResult;
@@ -11,5 +12,13 @@ pub trait LedgerRepository: Send + Sync {
     async fn find_by_user_id(&self, user_id: Uuid) -> AppResult<Vec<Ledger>>;
     async fn find_by_group_id(&self, group_id: Uuid) -> AppResult<Vec<Ledger>>;
     async fn update(&self, id: Uuid, name: Option<String>, description: Option<String>) -> AppResult<Ledger>;
+    /// Moves the ledger to the trash; use `purge` to remove it permanently
     async fn delete(&self, id: Uuid) -> AppResult<()>;
+
+    // 回收站
+    async fn find_trashed_by_id(&self, id: Uuid) -> AppResult<Option<Ledger>>;
+    async fn find_trashed_by_user_id(&self, user_id: Uuid) -> AppResult<Vec<Ledger>>;
+    async fn restore(&self, id: Uuid) -> AppResult<Ledger>;
+    async fn purge(&self, id: Uuid) -> AppResult<()>;
+    async fn find_purgeable(&self, deleted_before: DateTime<Utc>) -> AppResult<Vec<Ledger>>;
 }
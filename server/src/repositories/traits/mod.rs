@@ -3,9 +3,26 @@ mod group_repository;
 mod ledger_repository;
 mod bill_repository;
 mod category_repository;
+mod attachment_repository;
+mod audit_repository;
+mod rule_repository;
+mod saved_view_repository;
+mod notification_repository;
+mod asset_repository;
+mod net_worth_repository;
 
 pub use user_repository::UserRepository;
 pub use group_repository::GroupRepository;
 pub use ledger_repository::LedgerRepository;
-pub use bill_repository::{BillRepository, BillFilter, BillStatistics, CategoryStatistics, DailyStatistics, TrendStatistics};
+pub use bill_repository::{
+    BillFilter, BillRepository, BillStatistics, CategoryStatistics, DailyStatistics,
+    HourlyStatistics, MemberCategoryStatistics, MemberStatistics, SearchTerm, TrendStatistics,
+};
 pub use category_repository::CategoryRepository;
+pub use attachment_repository::AttachmentRepository;
+pub use audit_repository::AuditRepository;
+pub use rule_repository::RuleRepository;
+pub use saved_view_repository::SavedViewRepository;
+pub use notification_repository::NotificationRepository;
+pub use asset_repository::AssetRepository;
+pub use net_worth_repository::NetWorthRepository;
@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use uuid::Uuid;
+
+use crate::error::AppResult;
+use crate::models::{CreateNetWorthSnapshot, NetWorthSnapshot};
+
+#[async_trait]
+pub trait NetWorthRepository: Send + Sync {
+    /// Replaces whatever snapshots the user has for the day, all currencies at once
+    async fn save_day(&self, user_id: Uuid, snapshot_date: NaiveDate, snapshots: Vec<CreateNetWorthSnapshot>) -> AppResult<Vec<NetWorthSnapshot>>;
+    /// A user's snapshots from `start_date` on, oldest first
+    async fn find_by_user_id(&self, user_id: Uuid, start_date: NaiveDate) -> AppResult<Vec<NetWorthSnapshot>>;
+}
@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::error::AppResult;
+use crate::models::{CreateNotification, Notification};
+
+#[async_trait]
+pub trait NotificationRepository: Send + Sync {
+    async fn create(&self, notification: CreateNotification) -> AppResult<Notification>;
+    async fn find_by_id(&self, id: Uuid) -> AppResult<Option<Notification>>;
+    /// A user's notifications, newest first, plus the total matching
+    async fn find_by_user_id(&self, user_id: Uuid, unread_only: bool, page: u32, page_size: u32) -> AppResult<(Vec<Notification>, u64)>;
+    async fn count_unread(&self, user_id: Uuid) -> AppResult<u64>;
+    async fn mark_read(&self, id: Uuid) -> AppResult<()>;
+    async fn mark_all_read(&self, user_id: Uuid) -> AppResult<()>;
+}
@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::error::AppResult;
+use crate::models::{CategoryRule, CreateCategoryRule, UpdateCategoryRule};
+
+#[async_trait]
+pub trait RuleRepository: Send + Sync {
+    async fn create(&self, rule: CreateCategoryRule) -> AppResult<CategoryRule>;
+    async fn find_by_id(&self, id: Uuid) -> AppResult<Option<CategoryRule>>;
+    /// Rules of a ledger in evaluation order: priority, then oldest first
+    async fn find_by_ledger_id(&self, ledger_id: Uuid) -> AppResult<Vec<CategoryRule>>;
+    async fn update(&self, id: Uuid, rule: UpdateCategoryRule) -> AppResult<CategoryRule>;
+    async fn delete(&self, id: Uuid) -> AppResult<()>;
+}
@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::error::AppResult;
+use crate::models::{CreateSavedView, SavedView, UpdateSavedView};
+
+#[async_trait]
+pub trait SavedViewRepository: Send + Sync {
+    async fn create(&self, view: CreateSavedView) -> AppResult<SavedView>;
+    async fn find_by_id(&self, id: Uuid) -> AppResult<Option<SavedView>>;
+    /// Every user's views of a ledger, by name
+    async fn find_by_ledger_id(&self, ledger_id: Uuid) -> AppResult<Vec<SavedView>>;
+    async fn update(&self, id: Uuid, view: UpdateSavedView) -> AppResult<SavedView>;
+    async fn delete(&self, id: Uuid) -> AppResult<()>;
+}
@@ -0,0 +1,330 @@
+use chrono::{Duration, NaiveDate};
+use serde_json::json;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::error::AppResult;
+use crate::models::{Bill, BillType, CreateNotification, Ledger, NotificationKind};
+use crate::repositories::{BillFilter, BillRepository, GroupRepository, NotificationRepository};
+use crate::utils::round_cents;
+
+/// Days of history before a period that bills are compared with
+const HISTORY_DAYS: i64 = 180;
+/// Bills a category needs before its typical amount means anything
+const MIN_CATEGORY_BILLS: usize = 5;
+/// Robust z-score (median and MAD based) above which an amount is unusual
+const AMOUNT_Z: f64 = 3.5;
+/// An unusual amount is also at least this many times the median
+const AMOUNT_RATIO: f64 = 2.0;
+/// Preceding periods of the same length a category's spending is compared with
+const SPIKE_WINDOWS: i64 = 3;
+const SPIKE_RATIO: f64 = 2.0;
+/// Bills with a note the ledger needs before a note never seen before stands out
+const MIN_MERCHANT_BILLS: usize = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnomalyKind {
+    LargeAmount,  // A bill far above what its category usually costs
+    CategorySpike,  // A category costing much more than in the periods before
+    NewMerchant,  // A sizeable bill with a note the ledger has not seen before
+}
+
+impl std::fmt::Display for AnomalyKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnomalyKind::LargeAmount => write!(f, "large_amount"),
+            AnomalyKind::CategorySpike => write!(f, "category_spike"),
+            AnomalyKind::NewMerchant => write!(f, "new_merchant"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Anomaly {
+    pub kind: AnomalyKind,
+    pub bill: Option<Bill>,  // None for category spikes
+    pub category_id: Uuid,
+    pub date: NaiveDate,  // The bill's date, or the period's last day for spikes
+    pub amount: f64,  // The bill's amount, or the category's total over the period
+    pub expected: f64,  // The category's median bill, or its average over the preceding periods
+    pub score: f64,  // `amount` / `expected`
+    pub merchant: Option<String>,
+}
+
+/// Spots unusual spending in a ledger's expenses and alerts the ledger's users about new ones.
+/// Notes stand in for merchants, bills have no separate payee.
+pub struct AnomalyService {
+    bill_repo: Arc<dyn BillRepository>,
+    group_repo: Arc<dyn GroupRepository>,
+    notification_repo: Arc<dyn NotificationRepository>,
+}
+
+impl AnomalyService {
+    pub fn new(
+        bill_repo: Arc<dyn BillRepository>,
+        group_repo: Arc<dyn GroupRepository>,
+        notification_repo: Arc<dyn NotificationRepository>,
+    ) -> Self {
+        Self {
+            bill_repo,
+            group_repo,
+            notification_repo,
+        }
+    }
+
+    /// Anomalies among the bills between the dates, the latest and most unusual first
+    pub async fn detect(&self, ledger_id: Uuid, start_date: NaiveDate, end_date: NaiveDate) -> AppResult<Vec<Anomaly>> {
+        let length = (end_date - start_date).num_days() + 1;
+        let history_start = start_date - Duration::days(HISTORY_DAYS.max(length * SPIKE_WINDOWS));
+        let mut bills = self.scan(ledger_id, history_start, end_date).await?;
+        bills.sort_by(|a, b| a.bill_date.cmp(&b.bill_date).then(a.created_at.cmp(&b.created_at)));
+
+        let mut anomalies = Vec::new();
+        let mut by_category: HashMap<Uuid, Vec<f64>> = HashMap::new();
+        let mut merchants: HashSet<String> = HashSet::new();
+        let mut noted = 0;
+        for bill in &bills {
+            let merchant = merchant(bill.note.as_deref());
+            if bill.bill_date >= start_date {
+                let earlier = by_category.get(&bill.category_id).map_or(&[][..], Vec::as_slice);
+                if earlier.len() >= MIN_CATEGORY_BILLS {
+                    let median = median(earlier);
+                    let spread = median_deviation(earlier, median);
+                    let unusual = match spread > 0.0 {
+                        true => 0.6745 * (bill.amount - median) / spread > AMOUNT_Z,
+                        false => true,
+                    };
+                    if unusual && bill.amount >= median * AMOUNT_RATIO && median > 0.0 {
+                        anomalies.push(bill_anomaly(AnomalyKind::LargeAmount, bill, median, None));
+                    }
+
+                    if let Some(m) = merchant.as_ref().filter(|m| !merchants.contains(*m)) {
+                        if noted >= MIN_MERCHANT_BILLS && bill.amount >= median && median > 0.0 {
+                            anomalies.push(bill_anomaly(AnomalyKind::NewMerchant, bill, median, Some(m.clone())));
+                        }
+                    }
+                }
+            }
+
+            by_category.entry(bill.category_id).or_default().push(bill.amount);
+            if let Some(m) = merchant {
+                merchants.insert(m);
+                noted += 1;
+            }
+        }
+
+        anomalies.extend(category_spikes(&bills, start_date, end_date, length));
+        anomalies.sort_by(|a, b| b.date.cmp(&a.date).then(b.score.total_cmp(&a.score)));
+        Ok(anomalies)
+    }
+
+    /// Checks just saved bills of one ledger in the background and notifies the ledger's users about unusual ones.
+    /// A whole batch shares one scan of the ledger's history. The bills are saved already,
+    /// so a failure is logged rather than turned into an error response.
+    pub fn notify_if_unusual(self: &Arc<Self>, bills: Vec<Bill>, ledger: Ledger) {
+        let service = self.clone();
+        tokio::spawn(async move {
+            if let Err(e) = service.notify(&bills, &ledger).await {
+                tracing::warn!("Failed to check bills of ledger {} for anomalies: {}", ledger.id, e);
+            }
+        });
+    }
+
+    async fn notify(&self, bills: &[Bill], ledger: &Ledger) -> AppResult<()> {
+        let expenses: Vec<&Bill> = bills.iter().filter(|b| b.bill_type == BillType::Expense).collect();
+        let (Some(start_date), Some(end_date)) = (
+            expenses.iter().map(|b| b.bill_date).min(),
+            expenses.iter().map(|b| b.bill_date).max(),
+        ) else {
+            return Ok(());
+        };
+        let ids: HashSet<Uuid> = expenses.iter().map(|b| b.id).collect();
+
+        let anomalies: Vec<Anomaly> = self
+            .detect(ledger.id, start_date, end_date)
+            .await?
+            .into_iter()
+            .filter(|a| a.bill.as_ref().is_some_and(|b| ids.contains(&b.id)))
+            .collect();
+        if anomalies.is_empty() {
+            return Ok(());
+        }
+
+        let recipients = match ledger.group_id {
+            Some(group_id) => self.group_repo.get_members(group_id).await?.into_iter().map(|m| m.user_id).collect(),
+            None => ledger.user_id.into_iter().collect::<Vec<_>>(),
+        };
+        for user_id in recipients {
+            for anomaly in &anomalies {
+                self.notification_repo
+                    .create(CreateNotification {
+                        user_id,
+                        kind: NotificationKind::Anomaly,
+                        ledger_id: Some(ledger.id),
+                        bill_id: anomaly.bill.as_ref().map(|b| b.id),
+                        data: json!({
+                            "anomaly": anomaly.kind.to_string(),
+                            "category_id": anomaly.category_id.to_string(),
+                            "amount": round_cents(anomaly.amount),
+                            "expected": round_cents(anomaly.expected),
+                            "score": round_cents(anomaly.score),
+                            "merchant": anomaly.merchant,
+                        }),
+                    })
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn scan(&self, ledger_id: Uuid, start_date: NaiveDate, end_date: NaiveDate) -> AppResult<Vec<Bill>> {
+        self.bill_repo
+            .find_all_by_filter(BillFilter {
+                ledger_id,
+                start_date: Some(start_date),
+                end_date: Some(end_date),
+                bill_type: Some(BillType::Expense),
+                ..Default::default()
+            })
+            .await
+    }
+}
+
+fn bill_anomaly(kind: AnomalyKind, bill: &Bill, expected: f64, merchant: Option<String>) -> Anomaly {
+    Anomaly {
+        kind,
+        bill: Some(bill.clone()),
+        category_id: bill.category_id,
+        date: bill.bill_date,
+        amount: bill.amount,
+        expected,
+        score: bill.amount / expected,
+        merchant,
+    }
+}
+
+/// Categories whose total over the period is well above their average over the preceding periods
+fn category_spikes(bills: &[Bill], start_date: NaiveDate, end_date: NaiveDate, length: i64) -> Vec<Anomaly> {
+    let baseline_start = start_date - Duration::days(length * SPIKE_WINDOWS);
+    let mut totals: HashMap<Uuid, (f64, f64)> = HashMap::new();
+    for bill in bills.iter().filter(|b| b.bill_date >= baseline_start) {
+        let entry = totals.entry(bill.category_id).or_default();
+        match bill.bill_date >= start_date {
+            true => entry.0 += bill.amount,
+            false => entry.1 += bill.amount,
+        }
+    }
+
+    totals
+        .into_iter()
+        .filter_map(|(category_id, (current, before))| {
+            let expected = before / SPIKE_WINDOWS as f64;
+            (expected > 0.0 && current >= expected * SPIKE_RATIO).then(|| Anomaly {
+                kind: AnomalyKind::CategorySpike,
+                bill: None,
+                category_id,
+                date: end_date,
+                amount: current,
+                expected,
+                score: current / expected,
+                merchant: None,
+            })
+        })
+        .collect()
+}
+
+/// A note lowercased with its whitespace collapsed, None when blank
+fn merchant(note: Option<&str>) -> Option<String> {
+    let words: Vec<&str> = note?.split_whitespace().collect();
+    (!words.is_empty()).then(|| words.join(" ").to_lowercase())
+}
+
+fn median(values: &[f64]) -> f64 {
+    let mut sorted = values.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    let middle = sorted.len() / 2;
+    match sorted.len() % 2 {
+        0 => (sorted[middle - 1] + sorted[middle]) / 2.0,
+        _ => sorted[middle],
+    }
+}
+
+/// Median absolute deviation
+fn median_deviation(values: &[f64], median_value: f64) -> f64 {
+    let deviations: Vec<f64> = values.iter().map(|v| (v - median_value).abs()).collect();
+    median(&deviations)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::CreateBill;
+    use crate::repositories::memory::{MemoryBillRepository, MemoryGroupRepository, MemoryNotificationRepository};
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn median_takes_the_middle_of_sorted_values() {
+        assert_eq!(median(&[3.0, 1.0, 2.0]), 2.0);
+        assert_eq!(median(&[4.0, 1.0, 3.0, 2.0]), 2.5);
+        assert_eq!(median_deviation(&[1.0, 2.0, 3.0, 4.0, 100.0], 3.0), 1.0);
+    }
+
+    #[test]
+    fn merchant_normalises_the_note() {
+        assert_eq!(merchant(Some("  Corner   Cafe ")), Some("corner cafe".to_string()));
+        assert_eq!(merchant(Some("   ")), None);
+        assert_eq!(merchant(None), None);
+    }
+
+    #[tokio::test]
+    async fn large_amounts_stand_out_from_the_category() {
+        let bill_repo = Arc::new(MemoryBillRepository::new());
+        let ledger_id = Uuid::new_v4();
+        let food = Uuid::new_v4();
+        let amounts = [
+            ("2026-09-01", 30.0),
+            ("2026-09-02", 32.0),
+            ("2026-09-03", 28.0),
+            ("2026-09-04", 35.0),
+            ("2026-09-05", 30.0),
+            ("2026-10-01", 30.0),  // Usual
+            ("2026-10-02", 300.0),  // Unusual
+        ];
+        for (day, amount) in amounts {
+            bill_repo
+                .create(CreateBill {
+                    ledger_id,
+                    category_id: food,
+                    user_id: Uuid::nil(),
+                    bill_type: BillType::Expense,
+                    amount,
+                    note: None,
+                    bill_date: date(day),
+                    bill_time: None,
+                })
+                .await
+                .unwrap();
+        }
+
+        let service = AnomalyService::new(
+            bill_repo,
+            Arc::new(MemoryGroupRepository::new()),
+            Arc::new(MemoryNotificationRepository::new()),
+        );
+        let anomalies: Vec<Anomaly> = service
+            .detect(ledger_id, date("2026-10-01"), date("2026-10-31"))
+            .await
+            .unwrap()
+            .into_iter()
+            .filter(|a| a.kind == AnomalyKind::LargeAmount)
+            .collect();
+        assert_eq!(anomalies.len(), 1);
+        assert_eq!(anomalies[0].amount, 300.0);
+        assert_eq!(anomalies[0].expected, 30.0);
+        assert_eq!(anomalies[0].score, 10.0);
+    }
+}
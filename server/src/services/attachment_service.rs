@@ -0,0 +1,206 @@
+use std::io::Cursor;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::config::StorageConfig;
+use crate::error::{AppError, AppResult};
+use crate::models::{Attachment, CreateAttachment};
+use crate::repositories::AttachmentRepository;
+use crate::storage::BlobStore;
+
+const THUMBNAIL_CONTENT_TYPE: &str = "image/jpeg";
+
+pub struct AttachmentService {
+    attachment_repo: Arc<dyn AttachmentRepository>,
+    blob_store: Arc<dyn BlobStore>,
+    max_file_size: u64,
+    allowed_types: Vec<String>,
+    thumbnail_size: u32,
+}
+
+impl AttachmentService {
+    pub fn new(
+        attachment_repo: Arc<dyn AttachmentRepository>,
+        blob_store: Arc<dyn BlobStore>,
+        config: &StorageConfig,
+    ) -> Self {
+        Self {
+            attachment_repo,
+            blob_store,
+            max_file_size: config.max_file_size,
+            allowed_types: config.allowed_types.clone(),
+            thumbnail_size: config.thumbnail_size,
+        }
+    }
+
+    pub fn max_file_size(&self) -> u64 {
+        self.max_file_size
+    }
+
+    pub async fn upload(&self, bill_id: Uuid, user_id: Uuid, file_name: &str, data: Vec<u8>) -> AppResult<Attachment> {
+        if data.is_empty() {
+            return Err(AppError::Validation("File is empty".to_string()));
+        }
+        if data.len() as u64 > self.max_file_size {
+            return Err(AppError::Validation(format!(
+                "File is too large, the limit in bytes is: {}",
+                self.max_file_size
+            )));
+        }
+
+        // Trust the file contents rather than the client-supplied content type
+        let content_type = detect_content_type(&data)
+            .filter(|t| self.allowed_types.iter().any(|a| a == t))
+            .ok_or_else(|| AppError::Validation("Unsupported file type".to_string()))?;
+
+        let id = Uuid::new_v4();
+        let storage_key = Attachment::storage_key_for(bill_id, id);
+        let size = data.len() as u64;
+
+        let thumbnail = if content_type.starts_with("image/") {
+            self.generate_thumbnail(&data).await
+        } else {
+            None
+        };
+
+        self.blob_store.put(&storage_key, data, content_type).await?;
+
+        let thumbnail_key = match thumbnail {
+            Some(bytes) => {
+                let key = Attachment::thumbnail_key_for(bill_id, id);
+                match self.blob_store.put(&key, bytes, THUMBNAIL_CONTENT_TYPE).await {
+                    Ok(()) => Some(key),
+                    Err(e) => {
+                        tracing::warn!("Failed to store thumbnail for attachment {}: {}", id, e);
+                        None
+                    }
+                }
+            }
+            None => None,
+        };
+
+        let result = self
+            .attachment_repo
+            .create(CreateAttachment {
+                id,
+                bill_id,
+                user_id,
+                file_name: sanitize_file_name(file_name),
+                content_type: content_type.to_string(),
+                size,
+                storage_key: storage_key.clone(),
+                thumbnail_key: thumbnail_key.clone(),
+            })
+            .await;
+
+        // Don't leave orphaned blobs behind if the metadata insert fails
+        if result.is_err() {
+            let _ = self.blob_store.delete(&storage_key).await;
+            if let Some(key) = thumbnail_key {
+                let _ = self.blob_store.delete(&key).await;
+            }
+        }
+
+        result
+    }
+
+    pub async fn find_by_id(&self, id: Uuid) -> AppResult<Option<Attachment>> {
+        self.attachment_repo.find_by_id(id).await
+    }
+
+    pub async fn find_by_bill_id(&self, bill_id: Uuid) -> AppResult<Vec<Attachment>> {
+        self.attachment_repo.find_by_bill_id(bill_id).await
+    }
+
+    pub async fn read(&self, attachment: &Attachment) -> AppResult<Vec<u8>> {
+        self.blob_store.get(&attachment.storage_key).await
+    }
+
+    pub async fn read_thumbnail(&self, attachment: &Attachment) -> AppResult<(Vec<u8>, &'static str)> {
+        let key = attachment
+            .thumbnail_key
+            .as_ref()
+            .ok_or_else(|| AppError::NotFound("Thumbnail not found".to_string()))?;
+        Ok((self.blob_store.get(key).await?, THUMBNAIL_CONTENT_TYPE))
+    }
+
+    pub async fn delete(&self, attachment: &Attachment) -> AppResult<()> {
+        self.attachment_repo.delete(attachment.id).await?;
+        self.delete_blobs(attachment).await;
+        Ok(())
+    }
+
+    /// Remove all attachments of a bill. Call before deleting the bill itself,
+    /// since the MySQL foreign key cascade would otherwise drop the metadata
+    /// and leave the blobs unreachable.
+    pub async fn delete_for_bill(&self, bill_id: Uuid) -> AppResult<()> {
+        let attachments = self.attachment_repo.find_by_bill_id(bill_id).await?;
+        self.attachment_repo.delete_by_bill_id(bill_id).await?;
+        for attachment in &attachments {
+            self.delete_blobs(attachment).await;
+        }
+        Ok(())
+    }
+
+    async fn delete_blobs(&self, attachment: &Attachment) {
+        if let Err(e) = self.blob_store.delete(&attachment.storage_key).await {
+            tracing::warn!("Failed to delete blob {}: {}", attachment.storage_key, e);
+        }
+        if let Some(ref key) = attachment.thumbnail_key {
+            if let Err(e) = self.blob_store.delete(key).await {
+                tracing::warn!("Failed to delete blob {}: {}", key, e);
+            }
+        }
+    }
+
+    async fn generate_thumbnail(&self, data: &[u8]) -> Option<Vec<u8>> {
+        let data = data.to_vec();
+        let size = self.thumbnail_size;
+
+        // Image decoding is CPU bound, keep it off the async workers
+        let result = tokio::task::spawn_blocking(move || -> Result<Vec<u8>, image::ImageError> {
+            let img = image::load_from_memory(&data)?;
+            let thumb = img.thumbnail(size, size).to_rgb8();
+            let mut buf = Cursor::new(Vec::new());
+            thumb.write_to(&mut buf, image::ImageOutputFormat::Jpeg(80))?;
+            Ok(buf.into_inner())
+        })
+        .await;
+
+        match result {
+            Ok(Ok(bytes)) => Some(bytes),
+            Ok(Err(e)) => {
+                tracing::warn!("Failed to generate thumbnail: {}", e);
+                None
+            }
+            Err(e) => {
+                tracing::warn!("Thumbnail task failed: {}", e);
+                None
+            }
+        }
+    }
+}
+
+/// Detect the content type from the file signature
+fn detect_content_type(data: &[u8]) -> Option<&'static str> {
+    if data.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        Some("image/jpeg")
+    } else if data.starts_with(&[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A]) {
+        Some("image/png")
+    } else if data.starts_with(b"%PDF-") {
+        Some("application/pdf")
+    } else {
+        None
+    }
+}
+
+/// Strip any directory components and control characters from a client file name
+fn sanitize_file_name(name: &str) -> String {
+    let base = name.rsplit(['/', '\\']).next().unwrap_or("");
+    let cleaned: String = base.chars().filter(|c| !c.is_control()).take(255).collect();
+    if cleaned.trim().is_empty() {
+        "attachment".to_string()
+    } else {
+        cleaned
+    }
+}
@@ -0,0 +1,139 @@
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::error::AppResult;
+use crate::models::{AuditAction, AuditEntityType, AuditEvent, CreateAuditEvent};
+use crate::repositories::AuditRepository;
+
+/// The id is already on the event, and the timestamps change on every write,
+/// so they would only add noise to a diff
+const IGNORED_FIELDS: &[&str] = &["id", "created_at", "updated_at", "deleted_at"];
+
+/// Records who changed what, as a field-level before/after diff
+pub struct AuditService {
+    audit_repo: Arc<dyn AuditRepository>,
+}
+
+impl AuditService {
+    pub fn new(audit_repo: Arc<dyn AuditRepository>) -> Self {
+        Self { audit_repo }
+    }
+
+    pub async fn record_create<T: Serialize + Sync>(
+        &self,
+        actor_id: Uuid,
+        entity_type: AuditEntityType,
+        entity_id: Uuid,
+        group_id: Option<Uuid>,
+        after: &T,
+    ) {
+        let changes = diff(&Value::Null, &to_value(after));
+        self.record(actor_id, entity_type, entity_id, AuditAction::Create, group_id, changes)
+            .await;
+    }
+
+    pub async fn record_update<T: Serialize + Sync>(
+        &self,
+        actor_id: Uuid,
+        entity_type: AuditEntityType,
+        entity_id: Uuid,
+        group_id: Option<Uuid>,
+        before: &T,
+        after: &T,
+    ) {
+        let changes = diff(&to_value(before), &to_value(after));
+        // Nothing actually changed, don't clutter the history
+        if changes.as_object().map(|m| m.is_empty()).unwrap_or(true) {
+            return;
+        }
+        self.record(actor_id, entity_type, entity_id, AuditAction::Update, group_id, changes)
+            .await;
+    }
+
+    pub async fn record_delete<T: Serialize + Sync>(
+        &self,
+        actor_id: Uuid,
+        entity_type: AuditEntityType,
+        entity_id: Uuid,
+        group_id: Option<Uuid>,
+        before: &T,
+    ) {
+        let changes = diff(&to_value(before), &Value::Null);
+        self.record(actor_id, entity_type, entity_id, AuditAction::Delete, group_id, changes)
+            .await;
+    }
+
+    pub async fn record_restore(
+        &self,
+        actor_id: Uuid,
+        entity_type: AuditEntityType,
+        entity_id: Uuid,
+        group_id: Option<Uuid>,
+    ) {
+        self.record(actor_id, entity_type, entity_id, AuditAction::Restore, group_id, json!({}))
+            .await;
+    }
+
+    pub async fn history(&self, entity_type: AuditEntityType, entity_id: Uuid) -> AppResult<Vec<AuditEvent>> {
+        self.audit_repo.find_by_entity(entity_type, entity_id).await
+    }
+
+    pub async fn group_activity(&self, group_id: Uuid, page: u32, page_size: u32) -> AppResult<(Vec<AuditEvent>, u64)> {
+        self.audit_repo.find_by_group_id(group_id, page, page_size).await
+    }
+
+    /// The change has already been applied by the time it is recorded, so a
+    /// failure here is logged rather than turned into an error response.
+    async fn record(
+        &self,
+        actor_id: Uuid,
+        entity_type: AuditEntityType,
+        entity_id: Uuid,
+        action: AuditAction,
+        group_id: Option<Uuid>,
+        changes: Value,
+    ) {
+        let result = self
+            .audit_repo
+            .create(CreateAuditEvent {
+                entity_type,
+                entity_id,
+                action,
+                actor_id,
+                group_id,
+                changes,
+            })
+            .await;
+
+        if let Err(e) = result {
+            tracing::warn!("Failed to record audit event for {} {}: {}", entity_type, entity_id, e);
+        }
+    }
+}
+
+fn to_value<T: Serialize>(value: &T) -> Value {
+    serde_json::to_value(value).unwrap_or(Value::Null)
+}
+
+/// Build `{ field: { "before": .., "after": .. } }` for every field that differs
+fn diff(before: &Value, after: &Value) -> Value {
+    let empty = Map::new();
+    let before = before.as_object().unwrap_or(&empty);
+    let after = after.as_object().unwrap_or(&empty);
+
+    let mut changes = Map::new();
+    for key in before.keys().chain(after.keys()) {
+        if IGNORED_FIELDS.contains(&key.as_str()) || changes.contains_key(key) {
+            continue;
+        }
+        let old = before.get(key).unwrap_or(&Value::Null);
+        let new = after.get(key).unwrap_or(&Value::Null);
+        if old != new {
+            changes.insert(key.clone(), json!({ "before": old, "after": new }));
+        }
+    }
+
+    Value::Object(changes)
+}
@@ -52,7 +52,7 @@ impl AuthService {
             .user_repo
             .find_by_email(&email)
             .await?
-            .ok_or_else(|| AppError::Unauthorized)?;
+            .ok_or(AppError::Unauthorized)?;
 
         // Verify password
         if !verify_password(&password, &user.password_hash)? {
@@ -73,7 +73,7 @@ impl AuthService {
         self.user_repo
             .find_by_id(user_id)
             .await?
-            .ok_or_else(|| AppError::Unauthorized)?;
+            .ok_or(AppError::Unauthorized)?;
 
         // Generate new access token
         self.jwt_util.generate_access_token(user_id)
@@ -0,0 +1,135 @@
+use chrono::{Duration, NaiveDate};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::error::AppResult;
+use crate::models::{Bill, BillType, CreateBill};
+use crate::repositories::{BillFilter, BillRepository};
+use crate::utils::{to_cents, tokenize};
+
+/// Flags bills that look like another bill entered twice: same type, category and amount,
+/// dates at most `window_days` apart and similar notes
+pub struct DuplicateService {
+    bill_repo: Arc<dyn BillRepository>,
+    window_days: i64,
+    note_similarity: f64,
+}
+
+impl DuplicateService {
+    pub fn new(bill_repo: Arc<dyn BillRepository>, window_days: i64, note_similarity: f64) -> Self {
+        Self {
+            bill_repo,
+            window_days,
+            note_similarity,
+        }
+    }
+
+    pub fn window_days(&self) -> i64 {
+        self.window_days
+    }
+
+    /// Existing bills the new one would probably duplicate
+    pub async fn find_matches(&self, bill: &CreateBill) -> AppResult<Vec<Bill>> {
+        let window = Duration::days(self.window_days);
+        let candidates = self
+            .bill_repo
+            .find_all_by_filter(BillFilter {
+                ledger_id: bill.ledger_id,
+                start_date: Some(bill.bill_date - window),
+                end_date: Some(bill.bill_date + window),
+                bill_type: Some(bill.bill_type),
+                category_ids: vec![bill.category_id],
+                ..Default::default()
+            })
+            .await?;
+
+        let tokens = note_tokens(bill.note.as_deref());
+        Ok(candidates
+            .into_iter()
+            .filter(|c| same_amount(c.amount, bill.amount))
+            .filter(|c| similarity(&tokens, &note_tokens(c.note.as_deref())) >= self.note_similarity)
+            .collect())
+    }
+
+    /// Groups of probable duplicates among the ledger's bills, the group with the latest bill first
+    pub async fn find_clusters(
+        &self,
+        ledger_id: Uuid,
+        start_date: Option<NaiveDate>,
+        end_date: Option<NaiveDate>,
+        window_days: i64,
+    ) -> AppResult<Vec<Vec<Bill>>> {
+        let bills = self
+            .bill_repo
+            .find_all_by_filter(BillFilter {
+                ledger_id,
+                start_date,
+                end_date,
+                ..Default::default()
+            })
+            .await?;
+
+        // Only bills with the same type, category and amount can be duplicates of each other
+        let mut groups: HashMap<(BillType, Uuid, i64), Vec<usize>> = HashMap::new();
+        for (index, bill) in bills.iter().enumerate() {
+            groups.entry((bill.bill_type, bill.category_id, to_cents(bill.amount))).or_default().push(index);
+        }
+
+        let tokens: Vec<HashSet<String>> = bills.iter().map(|b| note_tokens(b.note.as_deref())).collect();
+        let window = Duration::days(window_days);
+        let mut parent: Vec<usize> = (0..bills.len()).collect();
+
+        for members in groups.values_mut() {
+            members.sort_by_key(|i| bills[*i].bill_date);
+            for (position, &a) in members.iter().enumerate() {
+                for &b in &members[position + 1..] {
+                    if bills[b].bill_date - bills[a].bill_date > window {
+                        break;
+                    }
+                    if similarity(&tokens[a], &tokens[b]) >= self.note_similarity {
+                        let (root_a, root_b) = (find_root(&mut parent, a), find_root(&mut parent, b));
+                        parent[root_b] = root_a;
+                    }
+                }
+            }
+        }
+
+        let mut clusters: HashMap<usize, Vec<Bill>> = HashMap::new();
+        for (index, bill) in bills.into_iter().enumerate() {
+            let root = find_root(&mut parent, index);
+            clusters.entry(root).or_default().push(bill);
+        }
+
+        let mut clusters: Vec<Vec<Bill>> = clusters.into_values().filter(|c| c.len() > 1).collect();
+        for cluster in clusters.iter_mut() {
+            cluster.sort_by(|a, b| b.bill_date.cmp(&a.bill_date).then(b.created_at.cmp(&a.created_at)));
+        }
+        clusters.sort_by(|a, b| b[0].bill_date.cmp(&a[0].bill_date).then(b[0].created_at.cmp(&a[0].created_at)));
+        Ok(clusters)
+    }
+}
+
+fn find_root(parent: &mut [usize], mut index: usize) -> usize {
+    while parent[index] != index {
+        parent[index] = parent[parent[index]];
+        index = parent[index];
+    }
+    index
+}
+
+fn same_amount(a: f64, b: f64) -> bool {
+    (a - b).abs() < 0.005
+}
+
+fn note_tokens(note: Option<&str>) -> HashSet<String> {
+    note.map(tokenize).unwrap_or_default().into_iter().collect()
+}
+
+/// Jaccard index of the note words, two empty notes count as identical
+fn similarity(a: &HashSet<String>, b: &HashSet<String>) -> f64 {
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    a.intersection(b).count() as f64 / a.union(b).count() as f64
+}
@@ -0,0 +1,389 @@
+use chrono::{Datelike, Duration, Months, NaiveDate};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::error::AppResult;
+use crate::models::{Bill, BillType};
+use crate::repositories::{BillFilter, BillRepository};
+use crate::utils::{round_cents, to_cents};
+
+/// Days of history the averages are taken from
+const HISTORY_DAYS: i64 = 182;
+/// A bill repeats monthly when the same one shows up in this many months in a row
+const MIN_OCCURRENCES: usize = 3;
+/// Gaps between two occurrences of a monthly bill, in days
+const MONTHLY_GAP: std::ops::RangeInclusive<i64> = 25..=35;
+/// z-score of the 80% confidence band
+const BAND_Z: f64 = 1.2816;
+
+/// A bill that came back every month with the same category and amount
+#[derive(Debug, Clone)]
+pub struct RecurringItem {
+    pub bill_type: BillType,
+    pub category_id: Uuid,
+    pub amount: f64,
+    pub note: Option<String>,
+    pub day_of_month: u32,
+    pub occurrences: usize,
+    pub last_date: NaiveDate,
+}
+
+/// What a category brings in or costs on an average day, recurring bills left out
+#[derive(Debug, Clone)]
+pub struct CategoryAverage {
+    pub bill_type: BillType,
+    pub category_id: Uuid,
+    pub daily_average: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct ForecastDay {
+    pub date: NaiveDate,
+    pub income: f64,
+    pub expense: f64,
+    pub balance: f64,  // Projected balance at the end of the day
+    pub lower: f64,  // 80% confidence band of the balance
+    pub upper: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct Forecast {
+    pub opening_balance: f64,  // Balance of all bills up to and including today
+    pub history_start: NaiveDate,
+    pub history_end: NaiveDate,
+    pub days: Vec<ForecastDay>,
+    pub recurring: Vec<RecurringItem>,
+    pub categories: Vec<CategoryAverage>,
+}
+
+/// Projects a ledger's balance from its monthly recurring bills plus the weekday-adjusted
+/// average of everything else
+pub struct ForecastService {
+    bill_repo: Arc<dyn BillRepository>,
+}
+
+impl ForecastService {
+    pub fn new(bill_repo: Arc<dyn BillRepository>) -> Self {
+        Self { bill_repo }
+    }
+
+    /// The `days` days after `today`
+    pub async fn forecast(&self, ledger_id: Uuid, today: NaiveDate, days: u32) -> AppResult<Forecast> {
+        let history_end = today - Duration::days(1);
+        let daily = self
+            .bill_repo
+            .get_daily_statistics(ledger_id, today - Duration::days(HISTORY_DAYS), history_end)
+            .await?;
+        // A young ledger is averaged over its own age only
+        let history_start = daily
+            .iter()
+            .filter(|d| d.income != 0.0 || d.expense != 0.0)
+            .map(|d| d.date)
+            .min()
+            .unwrap_or(today);
+
+        let bills = self.scan(ledger_id, history_start, history_end).await?;
+        let (recurring, recurring_ids) = find_recurring(&bills, today);
+
+        let history_days = (history_end - history_start).num_days().max(0) + 1;
+        let mut totals: HashMap<(BillType, Uuid), f64> = HashMap::new();
+        let mut discretionary: HashMap<NaiveDate, (f64, f64)> = HashMap::new();
+        for bill in bills.iter().filter(|b| !recurring_ids.contains(&b.id)) {
+            *totals.entry((bill.bill_type, bill.category_id)).or_default() += bill.amount;
+            let day = discretionary.entry(bill.bill_date).or_default();
+            match bill.bill_type {
+                BillType::Income => day.0 += bill.amount,
+                BillType::Expense => day.1 += bill.amount,
+            }
+        }
+
+        let mut categories: Vec<CategoryAverage> = totals
+            .into_iter()
+            .map(|((bill_type, category_id), total)| CategoryAverage {
+                bill_type,
+                category_id,
+                daily_average: total / history_days as f64,
+            })
+            .collect();
+        categories.sort_by(|a, b| b.daily_average.total_cmp(&a.daily_average));
+
+        // Weekday factors and the spread of the daily net amount, over every day of the history
+        let history: Vec<(NaiveDate, f64, f64)> = history_start
+            .iter_days()
+            .take(history_days as usize)
+            .map(|date| {
+                let (income, expense) = discretionary.get(&date).copied().unwrap_or_default();
+                (date, income, expense)
+            })
+            .collect();
+        let income_factors = weekday_factors(history.iter().map(|(date, income, _)| (*date, *income)));
+        let expense_factors = weekday_factors(history.iter().map(|(date, _, expense)| (*date, *expense)));
+        let spread = std_dev(history.iter().map(|(_, income, expense)| income - expense));
+
+        let average = |bill_type: BillType| -> f64 {
+            categories
+                .iter()
+                .filter(|c| c.bill_type == bill_type)
+                .fold(0.0, |sum, c| sum + c.daily_average)
+        };
+        let (average_income, average_expense) = (average(BillType::Income), average(BillType::Expense));
+
+        let opening_balance = self.bill_repo.get_statistics(ledger_id, None, Some(today)).await?.balance;
+        let mut balance = opening_balance;
+        let mut forecast_days = Vec::new();
+        for step in 1..=days {
+            let date = today + Duration::days(step as i64);
+            let weekday = date.weekday().num_days_from_monday() as usize;
+            let mut income = average_income * income_factors[weekday];
+            let mut expense = average_expense * expense_factors[weekday];
+            for item in recurring.iter().filter(|item| is_due(item, date)) {
+                match item.bill_type {
+                    BillType::Income => income += item.amount,
+                    BillType::Expense => expense += item.amount,
+                }
+            }
+
+            balance += income - expense;
+            let band = BAND_Z * spread * (step as f64).sqrt();
+            forecast_days.push(ForecastDay {
+                date,
+                income: round_cents(income),
+                expense: round_cents(expense),
+                balance: round_cents(balance),
+                lower: round_cents(balance - band),
+                upper: round_cents(balance + band),
+            });
+        }
+
+        Ok(Forecast {
+            opening_balance,
+            history_start,
+            history_end,
+            days: forecast_days,
+            recurring,
+            categories,
+        })
+    }
+
+    async fn scan(&self, ledger_id: Uuid, start_date: NaiveDate, end_date: NaiveDate) -> AppResult<Vec<Bill>> {
+        self.bill_repo
+            .find_all_by_filter(BillFilter {
+                ledger_id,
+                start_date: Some(start_date),
+                end_date: Some(end_date),
+                ..Default::default()
+            })
+            .await
+    }
+}
+
+/// Bills with the same type, category and amount about a month apart, still going in the last
+/// month, plus the IDs of the bills they were found in
+fn find_recurring(bills: &[Bill], today: NaiveDate) -> (Vec<RecurringItem>, HashSet<Uuid>) {
+    let mut groups: HashMap<(BillType, Uuid, i64), Vec<&Bill>> = HashMap::new();
+    for bill in bills {
+        groups.entry((bill.bill_type, bill.category_id, to_cents(bill.amount))).or_default().push(bill);
+    }
+
+    let mut items = Vec::new();
+    let mut ids = HashSet::new();
+    for group in groups.values_mut() {
+        group.sort_by_key(|b| b.bill_date);
+        // The latest run of monthly gaps
+        let mut run = vec![group[group.len() - 1]];
+        for bill in group.iter().rev().skip(1) {
+            if !MONTHLY_GAP.contains(&(run[run.len() - 1].bill_date - bill.bill_date).num_days()) {
+                break;
+            }
+            run.push(bill);
+        }
+
+        let last = run[0];
+        if run.len() < MIN_OCCURRENCES || today - last.bill_date > Duration::days(*MONTHLY_GAP.end()) {
+            continue;
+        }
+        ids.extend(run.iter().map(|b| b.id));
+        items.push(RecurringItem {
+            bill_type: last.bill_type,
+            category_id: last.category_id,
+            amount: last.amount,
+            note: last.note.clone(),
+            day_of_month: last.bill_date.day(),
+            occurrences: run.len(),
+            last_date: last.bill_date,
+        });
+    }
+
+    items.sort_by(|a, b| a.day_of_month.cmp(&b.day_of_month).then(b.amount.total_cmp(&a.amount)));
+    (items, ids)
+}
+
+/// Whether a recurring bill comes back on `date`, short months move it to their last day
+fn is_due(item: &RecurringItem, date: NaiveDate) -> bool {
+    if date <= item.last_date {
+        return false;
+    }
+    let month_end = date
+        .with_day(1)
+        .and_then(|first| first.checked_add_months(Months::new(1)))
+        .and_then(|next| next.pred_opt())
+        .map_or(31, |end| end.day());
+    date.day() == item.day_of_month.min(month_end)
+}
+
+/// How each weekday, Monday first, compares with the average day
+fn weekday_factors(days: impl Iterator<Item = (NaiveDate, f64)>) -> [f64; 7] {
+    let mut sums = [0.0; 7];
+    let mut counts = [0u32; 7];
+    for (date, amount) in days {
+        let weekday = date.weekday().num_days_from_monday() as usize;
+        sums[weekday] += amount;
+        counts[weekday] += 1;
+    }
+
+    let total: f64 = sums.iter().sum();
+    let days: u32 = counts.iter().sum();
+    if total <= 0.0 {
+        return [1.0; 7];
+    }
+    let mean = total / days as f64;
+    std::array::from_fn(|i| if counts[i] > 0 { sums[i] / counts[i] as f64 / mean } else { 1.0 })
+}
+
+fn std_dev(values: impl Iterator<Item = f64>) -> f64 {
+    let values: Vec<f64> = values.collect();
+    if values.len() < 2 {
+        return 0.0;
+    }
+    let mean = values.iter().sum::<f64>() / values.len() as f64;
+    let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (values.len() - 1) as f64;
+    variance.sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::CreateBill;
+    use crate::repositories::memory::MemoryBillRepository;
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    fn bill(bill_type: BillType, category_id: Uuid, amount: f64, day: &str) -> Bill {
+        Bill::new(Uuid::nil(), category_id, Uuid::nil(), bill_type, amount, None, date(day), None)
+    }
+
+    fn recurring(day_of_month: u32, last_date: &str) -> RecurringItem {
+        RecurringItem {
+            bill_type: BillType::Expense,
+            category_id: Uuid::nil(),
+            amount: 100.0,
+            note: None,
+            day_of_month,
+            occurrences: 3,
+            last_date: date(last_date),
+        }
+    }
+
+    #[test]
+    fn recurring_bills_need_three_months_in_a_row_still_going() {
+        let rent = Uuid::new_v4();
+        let gym = Uuid::new_v4();
+        let bills = vec![
+            bill(BillType::Expense, rent, 1000.0, "2026-07-05"),
+            bill(BillType::Expense, rent, 1000.0, "2026-08-05"),
+            bill(BillType::Expense, rent, 1000.0, "2026-09-04"),
+            // Only twice
+            bill(BillType::Expense, gym, 50.0, "2026-08-01"),
+            bill(BillType::Expense, gym, 50.0, "2026-09-01"),
+            // Stopped months ago
+            bill(BillType::Expense, gym, 80.0, "2026-03-01"),
+            bill(BillType::Expense, gym, 80.0, "2026-04-01"),
+            bill(BillType::Expense, gym, 80.0, "2026-05-01"),
+        ];
+
+        let (items, ids) = find_recurring(&bills, date("2026-09-20"));
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].category_id, rent);
+        assert_eq!(items[0].day_of_month, 4);
+        assert_eq!(items[0].occurrences, 3);
+        assert_eq!(ids, bills[..3].iter().map(|b| b.id).collect());
+    }
+
+    #[test]
+    fn recurring_run_starts_after_the_last_gap() {
+        let rent = Uuid::new_v4();
+        let bills = vec![
+            bill(BillType::Expense, rent, 1000.0, "2026-03-05"),
+            bill(BillType::Expense, rent, 1000.0, "2026-06-05"),
+            bill(BillType::Expense, rent, 1000.0, "2026-07-05"),
+            bill(BillType::Expense, rent, 1000.0, "2026-08-05"),
+        ];
+
+        let (items, ids) = find_recurring(&bills, date("2026-08-20"));
+        assert_eq!(items[0].occurrences, 3);
+        assert!(!ids.contains(&bills[0].id));
+    }
+
+    #[test]
+    fn recurring_bills_move_to_the_end_of_short_months() {
+        let item = recurring(31, "2026-01-31");
+        assert!(is_due(&item, date("2026-02-28")));
+        assert!(!is_due(&item, date("2026-03-30")));
+        assert!(is_due(&item, date("2026-03-31")));
+        // Not again on or before the last occurrence
+        assert!(!is_due(&item, date("2026-01-31")));
+    }
+
+    #[test]
+    fn weekday_factors_compare_with_the_average_day() {
+        // Two weeks from a Monday, spending only on Mondays
+        let days = date("2026-10-05").iter_days().take(14).map(|d| {
+            let amount = if d.weekday() == chrono::Weekday::Mon { 70.0 } else { 0.0 };
+            (d, amount)
+        });
+        let factors = weekday_factors(days);
+        assert_eq!(factors[0], 7.0);
+        assert!(factors[1..].iter().all(|f| *f == 0.0));
+
+        let quiet = date("2026-10-05").iter_days().take(14).map(|d| (d, 0.0));
+        assert_eq!(weekday_factors(quiet), [1.0; 7]);
+    }
+
+    #[tokio::test]
+    async fn projection_adds_recurring_bills_on_their_day() {
+        let repo = Arc::new(MemoryBillRepository::new());
+        let ledger_id = Uuid::new_v4();
+        let salary = Uuid::new_v4();
+        for day in ["2026-07-10", "2026-08-10", "2026-09-10"] {
+            repo.create(CreateBill {
+                ledger_id,
+                category_id: salary,
+                user_id: Uuid::nil(),
+                bill_type: BillType::Income,
+                amount: 3000.0,
+                note: None,
+                bill_date: date(day),
+                bill_time: None,
+            })
+            .await
+            .unwrap();
+        }
+
+        let forecast = ForecastService::new(repo).forecast(ledger_id, date("2026-09-20"), 30).await.unwrap();
+        assert_eq!(forecast.opening_balance, 9000.0);
+        assert_eq!(forecast.history_start, date("2026-07-10"));
+        assert_eq!(forecast.recurring.len(), 1);
+        // Everything is recurring, nothing is left to average
+        assert!(forecast.categories.is_empty());
+
+        let payday = forecast.days.iter().position(|d| d.date == date("2026-10-10")).unwrap();
+        assert!(forecast.days[..payday].iter().all(|d| d.balance == 9000.0));
+        assert_eq!(forecast.days[payday].income, 3000.0);
+        assert_eq!(forecast.days[payday].balance, 12000.0);
+        assert_eq!(forecast.days.last().unwrap().balance, 12000.0);
+        assert_eq!(forecast.days[payday].lower, forecast.days[payday].upper);
+    }
+}
@@ -1,3 +1,25 @@
+pub mod anomaly_service;
+pub mod attachment_service;
+pub mod audit_service;
 pub mod auth_service;
+pub mod duplicate_service;
+pub mod forecast_service;
+pub mod net_worth_service;
+pub mod permission_service;
+pub mod quick_entry_service;
+pub mod rule_service;
+pub mod suggestion_service;
+pub mod trash_service;
 
+pub use anomaly_service::{Anomaly, AnomalyService};
+pub use attachment_service::AttachmentService;
+pub use audit_service::AuditService;
 pub use auth_service::AuthService;
+pub use duplicate_service::DuplicateService;
+pub use forecast_service::{Forecast, ForecastService};
+pub use net_worth_service::{NetWorth, NetWorthService};
+pub use permission_service::{LedgerAccess, PermissionService};
+pub use quick_entry_service::{CategorySource, QuickEntry, QuickEntryService};
+pub use rule_service::RuleService;
+pub use suggestion_service::{CategorySuggestion, SuggestionService};
+pub use trash_service::{PurgeSummary, TrashService};
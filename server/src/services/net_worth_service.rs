@@ -0,0 +1,197 @@
+use chrono::NaiveDate;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::error::AppResult;
+use crate::models::{AssetKind, CreateNetWorthSnapshot, NetWorthSnapshot, User};
+use crate::repositories::{
+    AssetRepository, BillRepository, LedgerRepository, NetWorthRepository, UserRepository,
+};
+use crate::utils::round_cents;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NetWorthSource {
+    Ledger,  // A personal ledger's balance
+    Asset,  // A manually valued asset or liability
+}
+
+impl std::fmt::Display for NetWorthSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NetWorthSource::Ledger => write!(f, "ledger"),
+            NetWorthSource::Asset => write!(f, "asset"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NetWorthItem {
+    pub source: NetWorthSource,
+    pub id: Uuid,
+    pub name: String,
+    pub currency: String,
+    pub value: f64,  // What it adds to the net worth, negative for debts
+}
+
+#[derive(Debug, Clone)]
+pub struct NetWorth {
+    pub date: NaiveDate,
+    pub items: Vec<NetWorthItem>,
+    pub totals: Vec<CreateNetWorthSnapshot>,  // One per currency
+}
+
+/// Works out a user's net worth from their personal ledgers' balances and their assets and liabilities,
+/// and keeps a snapshot of it at the end of every month.
+/// Group ledgers are left out, they belong to every member at once.
+pub struct NetWorthService {
+    user_repo: Arc<dyn UserRepository>,
+    ledger_repo: Arc<dyn LedgerRepository>,
+    bill_repo: Arc<dyn BillRepository>,
+    asset_repo: Arc<dyn AssetRepository>,
+    net_worth_repo: Arc<dyn NetWorthRepository>,
+}
+
+impl NetWorthService {
+    pub fn new(
+        user_repo: Arc<dyn UserRepository>,
+        ledger_repo: Arc<dyn LedgerRepository>,
+        bill_repo: Arc<dyn BillRepository>,
+        asset_repo: Arc<dyn AssetRepository>,
+        net_worth_repo: Arc<dyn NetWorthRepository>,
+    ) -> Self {
+        Self {
+            user_repo,
+            ledger_repo,
+            bill_repo,
+            asset_repo,
+            net_worth_repo,
+        }
+    }
+
+    /// Net worth with the ledgers' bills up to and including `date`, assets at their current value
+    pub async fn net_worth(&self, user_id: Uuid, date: NaiveDate) -> AppResult<NetWorth> {
+        let mut items = Vec::new();
+        for ledger in self.ledger_repo.find_by_user_id(user_id).await? {
+            let balance = self.bill_repo.get_statistics(ledger.id, None, Some(date)).await?.balance;
+            items.push(NetWorthItem {
+                source: NetWorthSource::Ledger,
+                id: ledger.id,
+                name: ledger.name,
+                currency: ledger.currency,
+                value: balance,
+            });
+        }
+        for asset in self.asset_repo.find_by_user_id(user_id).await? {
+            items.push(NetWorthItem {
+                source: NetWorthSource::Asset,
+                id: asset.id,
+                name: asset.name,
+                currency: asset.currency,
+                value: match asset.kind {
+                    AssetKind::Asset => asset.value,
+                    AssetKind::Liability => -asset.value,
+                },
+            });
+        }
+
+        let mut currencies: BTreeMap<&str, (f64, f64)> = BTreeMap::new();
+        for item in &items {
+            let entry = currencies.entry(&item.currency).or_default();
+            if item.value >= 0.0 {
+                entry.0 += item.value;
+            } else {
+                entry.1 -= item.value;
+            }
+        }
+        let totals = currencies
+            .into_iter()
+            .map(|(currency, (assets, liabilities))| CreateNetWorthSnapshot {
+                user_id,
+                snapshot_date: date,
+                currency: currency.to_string(),
+                assets: round_cents(assets),
+                liabilities: round_cents(liabilities),
+            })
+            .collect();
+
+        Ok(NetWorth { date, items, totals })
+    }
+
+    /// Saves the user's net worth on `date`, replacing an earlier snapshot of the same day
+    pub async fn record(&self, user_id: Uuid, date: NaiveDate) -> AppResult<Vec<NetWorthSnapshot>> {
+        let net_worth = self.net_worth(user_id, date).await?;
+        self.net_worth_repo.save_day(user_id, date, net_worth.totals).await
+    }
+
+    /// Records a snapshot for every user whose month, in their own calendar, ends today.
+    /// Running again later the same day refreshes the snapshot, so the last run of the day wins.
+    /// A user who fails is logged and skipped so the others still get their snapshot.
+    pub async fn record_month_ends(&self) -> AppResult<usize> {
+        let mut recorded = 0;
+        for user in self.user_repo.find_all().await? {
+            match self.record_month_end(&user).await {
+                Ok(true) => recorded += 1,
+                Ok(false) => {}
+                Err(e) => tracing::warn!("Failed to record net worth of user {}: {}", user.id, e),
+            }
+        }
+        Ok(recorded)
+    }
+
+    /// Today's snapshot when the user's month ends today. Otherwise the previous month's,
+    /// if its last day passed without one, e.g. while the server was down.
+    /// A backfilled snapshot has the ledgers' balances as of that day but the assets' current values,
+    /// and only the latest month is caught up, longer outages leave gaps.
+    async fn record_month_end(&self, user: &User) -> AppResult<bool> {
+        let calendar = user.calendar();
+        let today = calendar.today();
+        let Some((month_start, month_end)) = calendar.period("month", today) else {
+            return Ok(false);
+        };
+
+        let date = match month_start.pred_opt() {
+            _ if month_end == today => today,
+            Some(last_end) if last_end >= user.created_at.date_naive() => {
+                let missed = !self
+                    .net_worth_repo
+                    .find_by_user_id(user.id, last_end)
+                    .await?
+                    .iter()
+                    .any(|s| s.snapshot_date == last_end);
+                // Without a personal ledger or asset nothing gets saved, so the month would look missed forever
+                if !missed || !self.has_holdings(user.id).await? {
+                    return Ok(false);
+                }
+                last_end
+            }
+            _ => return Ok(false),
+        };
+
+        Ok(!self.record(user.id, date).await?.is_empty())
+    }
+
+    /// Whether the user has anything a snapshot would value
+    async fn has_holdings(&self, user_id: Uuid) -> AppResult<bool> {
+        Ok(!self.ledger_repo.find_by_user_id(user_id).await?.is_empty()
+            || !self.asset_repo.find_by_user_id(user_id).await?.is_empty())
+    }
+
+    /// Run `record_month_ends` every `interval_secs` seconds in the background
+    pub fn spawn_snapshot_job(self: Arc<Self>, interval_secs: u64) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(std::time::Duration::from_secs(interval_secs));
+            loop {
+                interval.tick().await;
+                match self.record_month_ends().await {
+                    Ok(recorded) => {
+                        if recorded > 0 {
+                            tracing::info!("Recorded month-end net worth of {} users", recorded);
+                        }
+                    }
+                    Err(e) => tracing::warn!("Failed to record net worth snapshots: {}", e),
+                }
+            }
+        })
+    }
+}
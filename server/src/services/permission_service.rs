@@ -0,0 +1,151 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::error::{AppError, AppResult};
+use crate::models::{Bill, GroupCapability, GroupPolicy, GroupRole, Ledger};
+use crate::repositories::{GroupRepository, LedgerRepository};
+
+/// What a user may do in one ledger
+pub struct LedgerAccess {
+    pub ledger: Ledger,
+    pub user_id: Uuid,
+    pub capabilities: Vec<GroupCapability>,
+}
+
+impl LedgerAccess {
+    pub fn can(&self, capability: GroupCapability) -> bool {
+        !self.capabilities.contains(&GroupCapability::ViewOnly) && self.capabilities.contains(&capability)
+    }
+
+    pub fn require(&self, capability: GroupCapability) -> AppResult<()> {
+        if self.can(capability) {
+            Ok(())
+        } else {
+            Err(AppError::Forbidden(format!("Missing permission: {}", capability)))
+        }
+    }
+
+    pub fn require_edit_bill(&self, bill: &Bill) -> AppResult<()> {
+        if bill.user_id == self.user_id {
+            self.require(GroupCapability::EditOwn)
+        } else {
+            self.require(GroupCapability::EditAny)
+        }
+    }
+
+    pub fn require_delete_bill(&self, bill: &Bill) -> AppResult<()> {
+        if bill.user_id == self.user_id {
+            self.require(GroupCapability::EditOwn)
+        } else {
+            self.require(GroupCapability::DeleteAny)
+        }
+    }
+}
+
+/// Resolves ledger access from ledger ownership and group policies
+pub struct PermissionService {
+    ledger_repo: Arc<dyn LedgerRepository>,
+    group_repo: Arc<dyn GroupRepository>,
+}
+
+impl PermissionService {
+    pub fn new(ledger_repo: Arc<dyn LedgerRepository>, group_repo: Arc<dyn GroupRepository>) -> Self {
+        Self {
+            ledger_repo,
+            group_repo,
+        }
+    }
+
+    /// Load a ledger the user can at least view
+    pub async fn ledger_access(&self, ledger_id: Uuid, user_id: Uuid) -> AppResult<LedgerAccess> {
+        let ledger = self
+            .ledger_repo
+            .find_by_id(ledger_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Ledger not found".to_string()))?;
+
+        self.access_for(ledger, user_id).await
+    }
+
+    pub async fn access_for(&self, ledger: Ledger, user_id: Uuid) -> AppResult<LedgerAccess> {
+        let capabilities = match ledger.group_id {
+            Some(group_id) => {
+                let member = self
+                    .group_repo
+                    .get_member(group_id, user_id)
+                    .await?
+                    .ok_or_else(|| AppError::Forbidden("Access denied".to_string()))?;
+
+                if member.role == GroupRole::Owner {
+                    GroupCapability::ALL.to_vec()
+                } else {
+                    self.policy(group_id).await?.capabilities(member.role)
+                }
+            }
+            // Personal ledgers are only visible to their owner, who can do anything
+            None if ledger.user_id == Some(user_id) => GroupCapability::ALL.to_vec(),
+            None => return Err(AppError::Forbidden("Access denied".to_string())),
+        };
+
+        Ok(LedgerAccess {
+            ledger,
+            user_id,
+            capabilities,
+        })
+    }
+
+    /// The user's personal ledgers followed by the ledgers of every group they belong to
+    pub async fn accessible_ledgers(&self, user_id: Uuid) -> AppResult<Vec<LedgerAccess>> {
+        let mut ledgers = self.ledger_repo.find_by_user_id(user_id).await?;
+        for group in self.group_repo.find_by_user_id(user_id).await? {
+            ledgers.extend(self.ledger_repo.find_by_group_id(group.id).await?);
+        }
+
+        let mut accessible = Vec::new();
+        for ledger in ledgers {
+            accessible.push(self.access_for(ledger, user_id).await?);
+        }
+        Ok(accessible)
+    }
+
+    pub async fn policy(&self, group_id: Uuid) -> AppResult<GroupPolicy> {
+        Ok(self
+            .group_repo
+            .get_policy(group_id)
+            .await?
+            .unwrap_or_else(|| GroupPolicy::default_for(group_id)))
+    }
+
+    pub async fn save_policy(
+        &self,
+        group_id: Uuid,
+        admin: Vec<GroupCapability>,
+        member: Vec<GroupCapability>,
+    ) -> AppResult<GroupPolicy> {
+        let policy = GroupPolicy {
+            group_id,
+            admin: normalize(admin)?,
+            member: normalize(member)?,
+            updated_at: chrono::Utc::now(),
+        };
+        self.group_repo.save_policy(policy).await
+    }
+}
+
+/// Drop duplicates and reject view_only mixed with write capabilities
+fn normalize(capabilities: Vec<GroupCapability>) -> AppResult<Vec<GroupCapability>> {
+    let mut unique = Vec::new();
+    for c in capabilities {
+        if !unique.contains(&c) {
+            unique.push(c);
+        }
+    }
+
+    if unique.contains(&GroupCapability::ViewOnly) && unique.len() > 1 {
+        return Err(AppError::Validation(
+            "view_only cannot be combined with other capabilities".to_string(),
+        ));
+    }
+
+    Ok(unique)
+}
@@ -0,0 +1,352 @@
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use regex::{Captures, Regex};
+use std::sync::{Arc, OnceLock};
+use uuid::Uuid;
+
+use crate::error::AppResult;
+use crate::i18n::{default_category_name, Locale};
+use crate::models::{BillType, Category, RuleInput};
+use crate::repositories::CategoryRepository;
+use crate::services::{RuleService, SuggestionService};
+
+/// Words that say which way the money went
+const INCOME_HINTS: &[&str] = &["收入", "进账", "收到", "工资", "薪水", "奖金", "报销", "退款", "income", "salary", "bonus", "refund", "received", "earned"];
+const EXPENSE_HINTS: &[&str] = &["支出", "花了", "花费", "消费", "付款", "买了", "spent", "paid", "expense", "bought"];
+
+/// Common words for the system default categories, keyed by icon slug
+const CATEGORY_KEYWORDS: &[(&str, &[&str])] = &[
+    ("food", &["早饭", "早餐", "午饭", "午餐", "晚饭", "晚餐", "夜宵", "外卖", "咖啡", "奶茶", "水果", "零食", "breakfast", "lunch", "dinner", "coffee", "snack", "restaurant"]),
+    ("transport", &["打车", "出租车", "滴滴", "地铁", "公交", "高铁", "火车", "机票", "加油", "停车", "taxi", "uber", "subway", "metro", "bus", "train", "flight", "parking", "fuel"]),
+    ("shopping", &["淘宝", "京东", "超市", "衣服", "鞋", "shopping", "supermarket", "clothes", "amazon"]),
+    ("entertainment", &["电影", "游戏", "ktv", "演唱会", "旅游", "movie", "cinema", "game", "concert", "travel"]),
+    ("housing", &["房租", "水电", "电费", "水费", "燃气", "物业", "rent", "electricity", "utilities"]),
+    ("medical", &["医院", "药", "看病", "体检", "hospital", "medicine", "doctor", "pharmacy"]),
+    ("education", &["学费", "书", "课程", "培训", "tuition", "book", "course"]),
+    ("communication", &["话费", "流量", "宽带", "phone bill", "internet", "mobile"]),
+    ("salary", &["工资", "薪水", "salary", "paycheck"]),
+    ("bonus", &["奖金", "年终奖", "bonus"]),
+    ("investment", &["理财", "股票", "基金", "利息", "分红", "dividend", "interest", "stock"]),
+    ("red-packet", &["红包", "礼金", "gift"]),
+];
+
+/// Payment methods recognized in the text, ledgers don't track accounts so they stay in the note
+const ACCOUNT_KEYWORDS: &[(&str, &[&str])] = &[
+    ("wechat", &["微信", "wechat", "weixin"]),
+    ("alipay", &["支付宝", "alipay"]),
+    ("cash", &["现金", "cash"]),
+    ("credit_card", &["信用卡", "credit card"]),
+    ("bank_card", &["银行卡", "储蓄卡", "bank card", "debit card"]),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CategorySource {
+    Rule,
+    Name,
+    Keyword,
+    History,
+}
+
+impl std::fmt::Display for CategorySource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CategorySource::Rule => write!(f, "rule"),
+            CategorySource::Name => write!(f, "name"),
+            CategorySource::Keyword => write!(f, "keyword"),
+            CategorySource::History => write!(f, "history"),
+        }
+    }
+}
+
+/// A bill read from free text, fields the text did not mention are `None`
+#[derive(Debug, Clone)]
+pub struct QuickEntry {
+    pub amount: Option<f64>,
+    pub bill_type: BillType,
+    pub bill_date: NaiveDate,
+    pub category: Option<Category>,
+    pub category_source: Option<CategorySource>,
+    pub note: Option<String>,
+    pub account: Option<&'static str>,
+    pub confidence: f64,  // Lowest confidence among the parsed fields, 0..1
+}
+
+/// Turns text like "昨天 午饭 32.5 微信" or "taxi 45 yesterday" into a bill
+pub struct QuickEntryService {
+    category_repo: Arc<dyn CategoryRepository>,
+    rule_service: Arc<RuleService>,
+    suggestion_service: Arc<SuggestionService>,
+}
+
+impl QuickEntryService {
+    pub fn new(
+        category_repo: Arc<dyn CategoryRepository>,
+        rule_service: Arc<RuleService>,
+        suggestion_service: Arc<SuggestionService>,
+    ) -> Self {
+        Self {
+            category_repo,
+            rule_service,
+            suggestion_service,
+        }
+    }
+
+    /// Relative dates are resolved against `today`
+    pub async fn parse(&self, ledger_id: Uuid, text: &str, today: NaiveDate) -> AppResult<QuickEntry> {
+        let mut rest = text.to_string();
+
+        let explicit_date = take_date(&mut rest, today);
+        let (amount, amount_sign, amount_candidates) = take_amount(&mut rest);
+        let hinted_type = match amount_sign {
+            Some('+') => Some(BillType::Income),
+            Some('-') => Some(BillType::Expense),
+            _ => type_hint(&rest),
+        };
+        let account = ACCOUNT_KEYWORDS
+            .iter()
+            .find(|(_, words)| contains_any(&rest, words))
+            .map(|(account, _)| *account);
+        let mut note = clean_note(&rest);
+
+        // Rules run exactly as they would for a bill created through the form
+        let mut category_id = None;
+        let mut category_source = None;
+        let mut category_confidence = 0.0;
+        if let Some(amount) = amount {
+            let outcome = self
+                .rule_service
+                .evaluate(
+                    ledger_id,
+                    &RuleInput {
+                        note: note.as_deref(),
+                        amount,
+                        bill_type: hinted_type.unwrap_or(BillType::Expense),
+                        bill_date: explicit_date.unwrap_or(today),
+                    },
+                )
+                .await?;
+            if let Some(outcome) = outcome {
+                if outcome.note.is_some() {
+                    note = outcome.note;
+                }
+                if outcome.category_id.is_some() {
+                    category_id = outcome.category_id;
+                    category_source = Some(CategorySource::Rule);
+                    category_confidence = 1.0;
+                }
+            }
+        }
+
+        let categories = self.category_repo.find_by_ledger_id(Some(ledger_id), hinted_type, false).await?;
+        let mut category = category_id.and_then(|id| categories.iter().find(|c| c.id == id).cloned());
+
+        if category.is_none() {
+            if let Some(found) = note.as_deref().and_then(|n| match_category_name(&categories, n)) {
+                category = Some(found.clone());
+                category_source = Some(CategorySource::Name);
+                category_confidence = 0.9;
+            } else if let Some(found) = note.as_deref().and_then(|n| match_category_keyword(&categories, n)) {
+                category = Some(found.clone());
+                category_source = Some(CategorySource::Keyword);
+                category_confidence = 0.7;
+            }
+        }
+
+        if category.is_none() {
+            let (suggestions, _) = self
+                .suggestion_service
+                .suggest(ledger_id, note.as_deref(), amount, hinted_type.unwrap_or(BillType::Expense), 1)
+                .await?;
+            if let Some(best) = suggestions.first() {
+                category = categories.iter().find(|c| c.id == best.category_id).cloned();
+                category_source = Some(CategorySource::History);
+                category_confidence = best.confidence;
+            }
+        }
+
+        // Without a hint the category decides, expense is only a guess
+        let (bill_type, type_confidence) = match (hinted_type, &category) {
+            (Some(bill_type), _) => (bill_type, 1.0),
+            (None, Some(category)) => (category.category_type, 0.9),
+            (None, None) => (BillType::Expense, 0.7),
+        };
+        let amount_confidence = match (amount, amount_candidates) {
+            (None, _) => 0.0,
+            (Some(_), 1) => 1.0,
+            (Some(_), _) => 0.7,
+        };
+        let date_confidence = if explicit_date.is_some() { 1.0 } else { 0.9 };
+
+        let confidence = [amount_confidence, type_confidence, date_confidence, category_confidence]
+            .into_iter()
+            .fold(1.0, f64::min);
+
+        Ok(QuickEntry {
+            amount,
+            bill_type,
+            bill_date: explicit_date.unwrap_or(today),
+            category,
+            category_source,
+            note,
+            account,
+            confidence,
+        })
+    }
+}
+
+fn contains_any(text: &str, words: &[&str]) -> bool {
+    let text = text.to_lowercase();
+    words.iter().any(|word| text.contains(word))
+}
+
+fn type_hint(text: &str) -> Option<BillType> {
+    match (contains_any(text, INCOME_HINTS), contains_any(text, EXPENSE_HINTS)) {
+        (true, false) => Some(BillType::Income),
+        (false, true) => Some(BillType::Expense),
+        _ => None,
+    }
+}
+
+/// Longest category name mentioned in the note, system categories also match their translated names
+fn match_category_name<'a>(categories: &'a [Category], note: &str) -> Option<&'a Category> {
+    let note = note.to_lowercase();
+    categories
+        .iter()
+        .flat_map(|category| {
+            let mut names = vec![category.name.to_lowercase()];
+            if let (true, Some(icon)) = (category.is_system(), &category.icon) {
+                names.extend(
+                    Locale::ALL
+                        .iter()
+                        .filter_map(|locale| default_category_name(icon, *locale))
+                        .map(str::to_lowercase),
+                );
+            }
+            names.into_iter().map(move |name| (category, name))
+        })
+        .filter(|(_, name)| !name.is_empty() && note.contains(name.as_str()))
+        .max_by_key(|(_, name)| name.chars().count())
+        .map(|(category, _)| category)
+}
+
+fn match_category_keyword<'a>(categories: &'a [Category], note: &str) -> Option<&'a Category> {
+    CATEGORY_KEYWORDS
+        .iter()
+        .filter(|(_, words)| contains_any(note, words))
+        .find_map(|(icon, _)| categories.iter().find(|c| c.is_system() && c.icon.as_deref() == Some(*icon)))
+}
+
+fn take_amount(text: &mut String) -> (Option<f64>, Option<char>, usize) {
+    static AMOUNT: OnceLock<Regex> = OnceLock::new();
+    let pattern = AMOUNT.get_or_init(|| {
+        Regex::new(r"(?i)([+-])?\s*([¥￥$])?\s*(\d+(?:\.\d{1,2})?)\s*(元|块钱|块|rmb|yuan)?").unwrap()
+    });
+
+    let matches: Vec<Captures> = pattern.captures_iter(text).collect();
+    // A number with a currency sign or unit beats a bare one, otherwise the last number wins
+    let Some(chosen) = matches
+        .iter()
+        .find(|c| c.get(2).is_some() || c.get(4).is_some())
+        .or_else(|| matches.last())
+    else {
+        return (None, None, 0);
+    };
+
+    let amount = chosen[3].parse::<f64>().ok().filter(|a| *a > 0.0);
+    let sign = chosen.get(1).and_then(|s| s.as_str().chars().next());
+    let range = chosen.get(0).unwrap().range();
+    let candidates = matches.len();
+
+    text.replace_range(range, " ");
+    (amount, sign, candidates)
+}
+
+/// Removes the first date expression from `text` and resolves it
+fn take_date(text: &mut String, today: NaiveDate) -> Option<NaiveDate> {
+    static PATTERNS: OnceLock<Vec<Regex>> = OnceLock::new();
+    let patterns = PATTERNS.get_or_init(|| {
+        [
+            r"(\d{4})[-/.](\d{1,2})[-/.](\d{1,2})",
+            r"(\d{1,2})月(\d{1,2})[日号]",
+            r"(\d{1,2})/(\d{1,2})",
+            r"(?i)(\d+)\s*(?:天前|days?\s+ago)",
+            r"(?i)(上)?(?:周|星期|礼拜)([一二三四五六日天])|\blast\s+(monday|tuesday|wednesday|thursday|friday|saturday|sunday)\b",
+            r"(?i)大前天|前天|一昨日|昨天|昨日|今天|今日|明天|\b(?:day before yesterday|yesterday|today|tomorrow)\b",
+        ]
+        .iter()
+        .map(|p| Regex::new(p).unwrap())
+        .collect()
+    });
+
+    for (index, pattern) in patterns.iter().enumerate() {
+        let Some(caps) = pattern.captures(text) else {
+            continue;
+        };
+        let number = |i: usize| caps.get(i).and_then(|m| m.as_str().parse::<u32>().ok());
+
+        let date = match index {
+            0 => NaiveDate::from_ymd_opt(number(1)? as i32, number(2)?, number(3)?),
+            1 | 2 => month_day(today, number(1)?, number(2)?),
+            3 => today.checked_sub_signed(Duration::days(number(1)? as i64)),
+            4 => {
+                let weekday = match caps.get(2).or_else(|| caps.get(3)).map(|m| m.as_str().to_lowercase()).as_deref() {
+                    Some("一" | "monday") => Weekday::Mon,
+                    Some("二" | "tuesday") => Weekday::Tue,
+                    Some("三" | "wednesday") => Weekday::Wed,
+                    Some("四" | "thursday") => Weekday::Thu,
+                    Some("五" | "friday") => Weekday::Fri,
+                    Some("六" | "saturday") => Weekday::Sat,
+                    _ => Weekday::Sun,
+                };
+                let previous_week = caps.get(1).is_some() || caps.get(3).is_some();
+                Some(past_weekday(today, weekday, previous_week))
+            }
+            _ => {
+                let offset = match caps[0].to_lowercase().as_str() {
+                    "大前天" => -3,
+                    "前天" | "一昨日" | "day before yesterday" => -2,
+                    "昨天" | "昨日" | "yesterday" => -1,
+                    "明天" | "tomorrow" => 1,
+                    _ => 0,
+                };
+                Some(today + Duration::days(offset))
+            }
+        };
+
+        if let Some(date) = date {
+            let range = caps.get(0).unwrap().range();
+            text.replace_range(range, " ");
+            return Some(date);
+        }
+    }
+    None
+}
+
+/// A month and day without a year is the most recent such date
+fn month_day(today: NaiveDate, month: u32, day: u32) -> Option<NaiveDate> {
+    let date = NaiveDate::from_ymd_opt(today.year(), month, day)?;
+    if date > today {
+        NaiveDate::from_ymd_opt(today.year() - 1, month, day)
+    } else {
+        Some(date)
+    }
+}
+
+/// "周三" is the latest Wednesday up to today, "上周三"/"last wednesday" the one in the week before
+fn past_weekday(today: NaiveDate, weekday: Weekday, previous_week: bool) -> NaiveDate {
+    if previous_week {
+        let this_week_monday = today - Duration::days(today.weekday().num_days_from_monday() as i64);
+        this_week_monday - Duration::days(7 - weekday.num_days_from_monday() as i64)
+    } else {
+        let back = (7 + today.weekday().num_days_from_monday() - weekday.num_days_from_monday()) % 7;
+        today - Duration::days(back as i64)
+    }
+}
+
+fn clean_note(text: &str) -> Option<String> {
+    let note = text
+        .split(|c: char| c.is_whitespace() || matches!(c, ',' | '，' | '、' | ';' | '；'))
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+    Some(note).filter(|n| !n.is_empty())
+}
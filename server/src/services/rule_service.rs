@@ -0,0 +1,88 @@
+use regex::Regex;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use uuid::Uuid;
+
+use crate::error::{AppError, AppResult};
+use crate::models::{CategoryRule, RuleActions, RuleConditions, RuleInput, RuleOutcome};
+use crate::repositories::{CategoryRepository, RuleRepository};
+
+/// Evaluates a ledger's auto-categorization rules against incoming bills
+pub struct RuleService {
+    rule_repo: Arc<dyn RuleRepository>,
+    category_repo: Arc<dyn CategoryRepository>,
+    /// Compiled note regexes by rule, with the pattern they were compiled from
+    note_regexes: RwLock<HashMap<Uuid, (String, Option<Regex>)>>,
+}
+
+impl RuleService {
+    pub fn new(rule_repo: Arc<dyn RuleRepository>, category_repo: Arc<dyn CategoryRepository>) -> Self {
+        Self {
+            rule_repo,
+            category_repo,
+            note_regexes: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// The first enabled rule that matches wins
+    pub async fn evaluate(&self, ledger_id: Uuid, input: &RuleInput<'_>) -> AppResult<Option<RuleOutcome>> {
+        for rule in self.rule_repo.find_by_ledger_id(ledger_id).await? {
+            if !rule.enabled {
+                continue;
+            }
+            let note_regex = self.note_regex(&rule);
+            if let Some(mut outcome) = rule.apply(note_regex.as_ref(), input) {
+                // A category archived or trashed since the rule was saved is skipped, other actions still apply
+                if let Some(category_id) = outcome.category_id {
+                    if !self.category_fits(category_id, ledger_id, input).await? {
+                        outcome.category_id = None;
+                    }
+                }
+                return Ok(Some(outcome));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Checks a rule definition before it is saved or dry-run
+    pub async fn validate(&self, ledger_id: Uuid, conditions: &RuleConditions, actions: &RuleActions) -> AppResult<()> {
+        conditions.validate().map_err(AppError::Validation)?;
+
+        if actions.is_empty() {
+            return Err(AppError::Validation("Rule needs at least one action".to_string()));
+        }
+        if let Some(category_id) = actions.category_id {
+            let category = self
+                .category_repo
+                .find_by_id(category_id)
+                .await?
+                .filter(|c| c.is_usable_in(ledger_id))
+                .ok_or_else(|| AppError::Validation("Category not found in this ledger".to_string()))?;
+            if conditions.bill_type.is_some_and(|t| t != category.category_type) {
+                return Err(AppError::Validation("Categories have different types".to_string()));
+            }
+        }
+        Ok(())
+    }
+
+    /// The rule's note regex, compiled the first time the rule runs and again only when its pattern changes
+    fn note_regex(&self, rule: &CategoryRule) -> Option<Regex> {
+        let pattern = rule.conditions.note_regex.as_ref()?;
+        if let Some((compiled_from, regex)) = self.note_regexes.read().unwrap().get(&rule.id) {
+            if compiled_from == pattern {
+                return regex.clone();
+            }
+        }
+        let regex = rule.conditions.compile_note_regex();
+        self.note_regexes.write().unwrap().insert(rule.id, (pattern.clone(), regex.clone()));
+        regex
+    }
+
+    async fn category_fits(&self, category_id: Uuid, ledger_id: Uuid, input: &RuleInput<'_>) -> AppResult<bool> {
+        Ok(self
+            .category_repo
+            .find_by_id(category_id)
+            .await?
+            .is_some_and(|c| c.is_usable_in(ledger_id) && c.category_type == input.bill_type))
+    }
+}
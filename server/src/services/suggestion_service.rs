@@ -0,0 +1,180 @@
+use chrono::{DateTime, Duration, Utc};
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, RwLock};
+use uuid::Uuid;
+
+use crate::error::AppResult;
+use crate::models::BillType;
+use crate::repositories::{BillFilter, BillRepository, CategoryRepository};
+use crate::utils::tokenize;
+
+/// Models are rebuilt from the ledger's bills once they are this old
+const MODEL_TTL_MINUTES: i64 = 10;
+/// Upper bounds of the amount buckets, each bucket is one feature
+const AMOUNT_BUCKETS: [f64; 6] = [10.0, 30.0, 100.0, 300.0, 1000.0, 3000.0];
+
+#[derive(Debug, Clone)]
+pub struct CategorySuggestion {
+    pub category_id: Uuid,
+    pub confidence: f64,  // 0..1, sums to at most 1 across suggestions
+}
+
+/// Per-category counts of a multinomial naive Bayes model
+struct CategoryClass {
+    bill_type: BillType,
+    bills: u64,
+    features: HashMap<String, u64>,
+    total_features: u64,
+}
+
+struct LedgerModel {
+    trained_at: DateTime<Utc>,
+    bill_count: u64,
+    classes: HashMap<Uuid, CategoryClass>,
+    vocabulary: HashSet<String>,
+}
+
+/// Suggests categories for new bills from the ledger's own history
+pub struct SuggestionService {
+    bill_repo: Arc<dyn BillRepository>,
+    category_repo: Arc<dyn CategoryRepository>,
+    models: RwLock<HashMap<Uuid, Arc<LedgerModel>>>,
+}
+
+impl SuggestionService {
+    pub fn new(bill_repo: Arc<dyn BillRepository>, category_repo: Arc<dyn CategoryRepository>) -> Self {
+        Self {
+            bill_repo,
+            category_repo,
+            models: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Ranked candidates plus the number of bills the model learned from.
+    /// Categories no longer usable in the ledger are left out.
+    pub async fn suggest(
+        &self,
+        ledger_id: Uuid,
+        note: Option<&str>,
+        amount: Option<f64>,
+        bill_type: BillType,
+        limit: usize,
+    ) -> AppResult<(Vec<CategorySuggestion>, u64)> {
+        let model = self.model(ledger_id).await?;
+
+        let usable: HashSet<Uuid> = self
+            .category_repo
+            .find_by_ledger_id(Some(ledger_id), Some(bill_type), false)
+            .await?
+            .into_iter()
+            .map(|c| c.id)
+            .collect();
+
+        let mut suggestions = score(&model, &features(note, amount), bill_type);
+        suggestions.retain(|s| usable.contains(&s.category_id));
+        suggestions.truncate(limit);
+
+        Ok((suggestions, model.bill_count))
+    }
+
+    async fn model(&self, ledger_id: Uuid) -> AppResult<Arc<LedgerModel>> {
+        let cached = self.models.read().unwrap().get(&ledger_id).cloned();
+        if let Some(model) = cached {
+            if Utc::now() - model.trained_at < Duration::minutes(MODEL_TTL_MINUTES) {
+                return Ok(model);
+            }
+        }
+
+        let model = Arc::new(self.train(ledger_id).await?);
+        self.models.write().unwrap().insert(ledger_id, model.clone());
+        Ok(model)
+    }
+
+    async fn train(&self, ledger_id: Uuid) -> AppResult<LedgerModel> {
+        let mut model = LedgerModel {
+            trained_at: Utc::now(),
+            bill_count: 0,
+            classes: HashMap::new(),
+            vocabulary: HashSet::new(),
+        };
+
+        let bills = self
+            .bill_repo
+            .find_all_by_filter(BillFilter {
+                ledger_id,
+                ..Default::default()
+            })
+            .await?;
+
+        for bill in &bills {
+            let class = model.classes.entry(bill.category_id).or_insert_with(|| CategoryClass {
+                bill_type: bill.bill_type,
+                bills: 0,
+                features: HashMap::new(),
+                total_features: 0,
+            });
+            class.bills += 1;
+            for feature in features(bill.note.as_deref(), Some(bill.amount)) {
+                *class.features.entry(feature.clone()).or_default() += 1;
+                class.total_features += 1;
+                model.vocabulary.insert(feature);
+            }
+        }
+        model.bill_count = bills.len() as u64;
+
+        Ok(model)
+    }
+}
+
+fn features(note: Option<&str>, amount: Option<f64>) -> Vec<String> {
+    let mut features = note.map(tokenize).unwrap_or_default();
+    if let Some(amount) = amount {
+        let bucket = AMOUNT_BUCKETS.iter().take_while(|bound| amount >= **bound).count();
+        features.push(format!("amount:{}", bucket));
+    }
+    features
+}
+
+/// Laplace-smoothed log probabilities turned into confidences with a softmax, best first
+fn score(model: &LedgerModel, features: &[String], bill_type: BillType) -> Vec<CategorySuggestion> {
+    let classes: Vec<(&Uuid, &CategoryClass)> = model
+        .classes
+        .iter()
+        .filter(|(_, class)| class.bill_type == bill_type)
+        .collect();
+    if classes.is_empty() {
+        return Vec::new();
+    }
+
+    let total_bills: u64 = classes.iter().map(|(_, class)| class.bills).sum();
+    let vocabulary = model.vocabulary.len() as f64;
+
+    let log_scores: Vec<(Uuid, f64)> = classes
+        .iter()
+        .map(|(category_id, class)| {
+            let prior = ((class.bills + 1) as f64 / (total_bills + classes.len() as u64) as f64).ln();
+            let likelihood: f64 = features
+                .iter()
+                .filter(|f| model.vocabulary.contains(*f))
+                .map(|f| {
+                    let count = class.features.get(f).copied().unwrap_or(0) as f64;
+                    ((count + 1.0) / (class.total_features as f64 + vocabulary)).ln()
+                })
+                .sum();
+            (**category_id, prior + likelihood)
+        })
+        .collect();
+
+    let max = log_scores.iter().map(|(_, s)| *s).fold(f64::NEG_INFINITY, f64::max);
+    let norm: f64 = log_scores.iter().map(|(_, s)| (s - max).exp()).sum();
+
+    let mut suggestions: Vec<CategorySuggestion> = log_scores
+        .into_iter()
+        .map(|(category_id, s)| CategorySuggestion {
+            category_id,
+            confidence: (s - max).exp() / norm,
+        })
+        .collect();
+    suggestions.sort_by(|a, b| b.confidence.partial_cmp(&a.confidence).unwrap_or(std::cmp::Ordering::Equal));
+    suggestions
+}
@@ -0,0 +1,105 @@
+use chrono::{DateTime, Duration, Utc};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::error::AppResult;
+use crate::repositories::{BillRepository, CategoryRepository, LedgerRepository};
+use crate::services::AttachmentService;
+
+/// Permanently removes trashed rows once the retention period has passed
+pub struct TrashService {
+    bill_repo: Arc<dyn BillRepository>,
+    ledger_repo: Arc<dyn LedgerRepository>,
+    category_repo: Arc<dyn CategoryRepository>,
+    attachment_service: Arc<AttachmentService>,
+    retention_days: i64,
+}
+
+#[derive(Debug, Default)]
+pub struct PurgeSummary {
+    pub bills: usize,
+    pub ledgers: usize,
+    pub categories: usize,
+}
+
+impl TrashService {
+    pub fn new(
+        bill_repo: Arc<dyn BillRepository>,
+        ledger_repo: Arc<dyn LedgerRepository>,
+        category_repo: Arc<dyn CategoryRepository>,
+        attachment_service: Arc<AttachmentService>,
+        retention_days: i64,
+    ) -> Self {
+        Self {
+            bill_repo,
+            ledger_repo,
+            category_repo,
+            attachment_service,
+            retention_days,
+        }
+    }
+
+    /// When an item deleted at `deleted_at` will be purged
+    pub fn purge_at(&self, deleted_at: DateTime<Utc>) -> DateTime<Utc> {
+        deleted_at + Duration::days(self.retention_days)
+    }
+
+    pub async fn purge_expired(&self) -> AppResult<PurgeSummary> {
+        let cutoff = Utc::now() - Duration::days(self.retention_days);
+        let mut summary = PurgeSummary::default();
+
+        for bill in self.bill_repo.find_purgeable(cutoff).await? {
+            self.purge_bill(bill.id).await?;
+            summary.bills += 1;
+        }
+
+        // A purged ledger takes all of its bills with it
+        for ledger in self.ledger_repo.find_purgeable(cutoff).await? {
+            for bill_id in self.bill_repo.find_all_ids_by_ledger_id(ledger.id).await? {
+                self.purge_bill(bill_id).await?;
+                summary.bills += 1;
+            }
+            self.ledger_repo.purge(ledger.id).await?;
+            summary.ledgers += 1;
+        }
+
+        // Categories still referenced by bills are kept until those bills are gone
+        for category in self.category_repo.find_purgeable(cutoff).await? {
+            if self.bill_repo.count_by_category_id(category.id).await? > 0 {
+                continue;
+            }
+            self.category_repo.purge(category.id).await?;
+            summary.categories += 1;
+        }
+
+        Ok(summary)
+    }
+
+    /// Run `purge_expired` every `interval_secs` seconds in the background
+    pub fn spawn_purge_job(self: Arc<Self>, interval_secs: u64) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(std::time::Duration::from_secs(interval_secs));
+            loop {
+                interval.tick().await;
+                match self.purge_expired().await {
+                    Ok(summary) => {
+                        if summary.bills + summary.ledgers + summary.categories > 0 {
+                            tracing::info!(
+                                "Purged trash: {} bills, {} ledgers, {} categories",
+                                summary.bills,
+                                summary.ledgers,
+                                summary.categories
+                            );
+                        }
+                    }
+                    Err(e) => tracing::warn!("Failed to purge trash: {}", e),
+                }
+            }
+        })
+    }
+
+    async fn purge_bill(&self, bill_id: Uuid) -> AppResult<()> {
+        self.attachment_service.delete_for_bill(bill_id).await?;
+        self.bill_repo.purge(bill_id).await
+    }
+}
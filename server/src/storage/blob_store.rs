@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+
+use crate::error::AppResult;
+
+/// Binary object storage for uploaded files.
+///
+/// Keys are relative paths such as `bills/<bill_id>/<id>`.
+#[async_trait]
+pub trait BlobStore: Send + Sync {
+    async fn put(&self, key: &str, data: Vec<u8>, content_type: &str) -> AppResult<()>;
+    async fn get(&self, key: &str) -> AppResult<Vec<u8>>;
+    /// Deleting a missing key is not an error
+    async fn delete(&self, key: &str) -> AppResult<()>;
+}
@@ -0,0 +1,57 @@
+use async_trait::async_trait;
+use std::path::{Component, Path, PathBuf};
+
+use crate::error::{AppError, AppResult};
+use crate::storage::BlobStore;
+
+/// Stores blobs as plain files under a root directory
+pub struct LocalBlobStore {
+    root: PathBuf,
+}
+
+impl LocalBlobStore {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    fn path_for(&self, key: &str) -> AppResult<PathBuf> {
+        // Reject keys that could escape the root directory
+        let relative = Path::new(key);
+        if relative.components().any(|c| !matches!(c, Component::Normal(_))) {
+            return Err(AppError::Storage(format!("Invalid storage key: {}", key)));
+        }
+        Ok(self.root.join(relative))
+    }
+}
+
+#[async_trait]
+impl BlobStore for LocalBlobStore {
+    async fn put(&self, key: &str, data: Vec<u8>, _content_type: &str) -> AppResult<()> {
+        let path = self.path_for(key)?;
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .map_err(|e| AppError::Storage(e.to_string()))?;
+        }
+        tokio::fs::write(&path, data)
+            .await
+            .map_err(|e| AppError::Storage(e.to_string()))
+    }
+
+    async fn get(&self, key: &str) -> AppResult<Vec<u8>> {
+        let path = self.path_for(key)?;
+        tokio::fs::read(&path).await.map_err(|e| match e.kind() {
+            std::io::ErrorKind::NotFound => AppError::NotFound("File not found".to_string()),
+            _ => AppError::Storage(e.to_string()),
+        })
+    }
+
+    async fn delete(&self, key: &str) -> AppResult<()> {
+        let path = self.path_for(key)?;
+        match tokio::fs::remove_file(&path).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(AppError::Storage(e.to_string())),
+        }
+    }
+}
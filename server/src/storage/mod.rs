@@ -0,0 +1,7 @@
+mod blob_store;
+pub mod local;
+pub mod s3;
+
+pub use blob_store::BlobStore;
+pub use local::LocalBlobStore;
+pub use s3::S3BlobStore;
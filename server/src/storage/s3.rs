@@ -0,0 +1,150 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use reqwest::{Client, Method, StatusCode};
+use sha2::{Digest, Sha256};
+
+use crate::config::S3Config;
+use crate::error::{AppError, AppResult};
+use crate::storage::BlobStore;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// S3-compatible object storage (AWS S3, MinIO, ...)
+///
+/// Uses path-style addressing (`<endpoint>/<bucket>/<key>`) and AWS Signature V4,
+/// so it works against a local MinIO without any DNS setup.
+pub struct S3BlobStore {
+    client: Client,
+    endpoint: String,
+    host: String,
+    bucket: String,
+    region: String,
+    access_key: String,
+    secret_key: String,
+}
+
+impl S3BlobStore {
+    pub fn new(config: &S3Config) -> AppResult<Self> {
+        let url = reqwest::Url::parse(&config.endpoint)
+            .map_err(|e| AppError::Storage(format!("Invalid S3 endpoint: {}", e)))?;
+        let host = match (url.host_str(), url.port()) {
+            (Some(h), Some(p)) => format!("{}:{}", h, p),
+            (Some(h), None) => h.to_string(),
+            _ => return Err(AppError::Storage("Invalid S3 endpoint: missing host".to_string())),
+        };
+
+        Ok(Self {
+            client: Client::new(),
+            endpoint: config.endpoint.trim_end_matches('/').to_string(),
+            host,
+            bucket: config.bucket.clone(),
+            region: config.region.clone(),
+            access_key: config.access_key.clone(),
+            secret_key: config.secret_key.clone(),
+        })
+    }
+
+    async fn send(&self, method: Method, key: &str, body: Vec<u8>, content_type: Option<&str>) -> AppResult<reqwest::Response> {
+        let canonical_uri = format!("/{}/{}", self.bucket, uri_encode(key));
+        let payload_hash = hex::encode(Sha256::digest(&body));
+        let now = Utc::now();
+        let amz_date = now.format("%Y%m%dT%H%M%SZ").to_string();
+        let date_stamp = now.format("%Y%m%d").to_string();
+
+        let signed_headers = "host;x-amz-content-sha256;x-amz-date";
+        let canonical_request = format!(
+            "{}\n{}\n\nhost:{}\nx-amz-content-sha256:{}\nx-amz-date:{}\n\n{}\n{}",
+            method, canonical_uri, self.host, payload_hash, amz_date, signed_headers, payload_hash
+        );
+
+        let scope = format!("{}/{}/s3/aws4_request", date_stamp, self.region);
+        let string_to_sign = format!(
+            "AWS4-HMAC-SHA256\n{}\n{}\n{}",
+            amz_date,
+            scope,
+            hex::encode(Sha256::digest(canonical_request.as_bytes()))
+        );
+
+        let k_date = hmac_sha256(format!("AWS4{}", self.secret_key).as_bytes(), date_stamp.as_bytes());
+        let k_region = hmac_sha256(&k_date, self.region.as_bytes());
+        let k_service = hmac_sha256(&k_region, b"s3");
+        let k_signing = hmac_sha256(&k_service, b"aws4_request");
+        let signature = hex::encode(hmac_sha256(&k_signing, string_to_sign.as_bytes()));
+
+        let authorization = format!(
+            "AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders={}, Signature={}",
+            self.access_key, scope, signed_headers, signature
+        );
+
+        let mut request = self
+            .client
+            .request(method, format!("{}{}", self.endpoint, canonical_uri))
+            .header("x-amz-content-sha256", payload_hash)
+            .header("x-amz-date", amz_date)
+            .header("authorization", authorization);
+        if let Some(ct) = content_type {
+            request = request.header("content-type", ct);
+        }
+
+        request
+            .body(body)
+            .send()
+            .await
+            .map_err(|e| AppError::Storage(e.to_string()))
+    }
+}
+
+#[async_trait]
+impl BlobStore for S3BlobStore {
+    async fn put(&self, key: &str, data: Vec<u8>, content_type: &str) -> AppResult<()> {
+        let response = self.send(Method::PUT, key, data, Some(content_type)).await?;
+        if !response.status().is_success() {
+            return Err(AppError::Storage(format!("S3 put failed with status {}", response.status())));
+        }
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> AppResult<Vec<u8>> {
+        let response = self.send(Method::GET, key, Vec::new(), None).await?;
+        match response.status() {
+            StatusCode::NOT_FOUND => Err(AppError::NotFound("File not found".to_string())),
+            s if s.is_success() => Ok(response
+                .bytes()
+                .await
+                .map_err(|e| AppError::Storage(e.to_string()))?
+                .to_vec()),
+            s => Err(AppError::Storage(format!("S3 get failed with status {}", s))),
+        }
+    }
+
+    async fn delete(&self, key: &str) -> AppResult<()> {
+        let response = self.send(Method::DELETE, key, Vec::new(), None).await?;
+        // S3 answers 204 for both existing and missing keys; MinIO may answer 404
+        if response.status().is_success() || response.status() == StatusCode::NOT_FOUND {
+            Ok(())
+        } else {
+            Err(AppError::Storage(format!("S3 delete failed with status {}", response.status())))
+        }
+    }
+}
+
+fn hmac_sha256(key: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut mac = HmacSha256::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(data);
+    mac.finalize().into_bytes().to_vec()
+}
+
+/// Percent-encode an object key as required by SigV4, keeping `/` separators
+fn uri_encode(key: &str) -> String {
+    let mut encoded = String::with_capacity(key.len());
+    for byte in key.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' | b'/' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
@@ -1,5 +1,11 @@
 pub mod hash;
 pub mod jwt;
+pub mod money;
+pub mod period;
+pub mod text;
 
 pub use hash::*;
 pub use jwt::*;
+pub use money::*;
+pub use period::*;
+pub use text::*;
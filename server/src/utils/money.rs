@@ -0,0 +1,9 @@
+/// Rounds an amount to whole cents for responses and stored totals
+pub fn round_cents(amount: f64) -> f64 {
+    (amount * 100.0).round() / 100.0
+}
+
+/// An amount as a whole number of cents, for grouping bills of the same amount
+pub fn to_cents(amount: f64) -> i64 {
+    (amount * 100.0).round() as i64
+}
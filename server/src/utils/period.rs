@@ -0,0 +1,162 @@
+use chrono::{Datelike, Duration, FixedOffset, Months, NaiveDate, Utc, Weekday};
+
+/// How a user's days, weeks and months line up, every calendar period is worked out with it
+#[derive(Debug, Clone, Copy)]
+pub struct Calendar {
+    pub utc_offset: FixedOffset,  // Where the user's day starts
+    pub week_start: Weekday,
+    pub month_start_day: u32,  // 1-28, months run from this day to the day before it a month later
+}
+
+impl Default for Calendar {
+    fn default() -> Self {
+        Self {
+            utc_offset: FixedOffset::east_opt(0).unwrap(),
+            week_start: Weekday::Mon,
+            month_start_day: 1,
+        }
+    }
+}
+
+impl Calendar {
+    /// The user's current date
+    pub fn today(&self) -> NaiveDate {
+        Utc::now().with_timezone(&self.utc_offset).date_naive()
+    }
+
+    /// Resolves a relative period such as "last 3 months" to a date range, both ends inclusive.
+    /// Understands "today", "yesterday", "this/last week|month|year" (calendar periods)
+    /// and "last N days|weeks|months|years" (rolling, ending today).
+    pub fn resolve(&self, expr: &str, today: NaiveDate) -> Option<(NaiveDate, NaiveDate)> {
+        let expr = expr.trim().to_lowercase();
+        let words: Vec<&str> = expr.split_whitespace().collect();
+
+        match words.as_slice() {
+            ["today"] => Some((today, today)),
+            ["yesterday"] => {
+                let day = today.pred_opt()?;
+                Some((day, day))
+            }
+            ["this", unit] => self.period(unit, today),
+            ["last", unit] => {
+                let (start, _) = self.period(unit, today)?;
+                self.period(unit, start.pred_opt()?)
+            }
+            ["last", count, unit] => {
+                let count: u32 = count.parse().ok().filter(|n| (1..=3650).contains(n))?;
+                let start = match unit.trim_end_matches('s') {
+                    "day" => today - Duration::days(count as i64),
+                    "week" => today - Duration::weeks(count as i64),
+                    "month" => today.checked_sub_months(Months::new(count))?,
+                    "year" => today.checked_sub_months(Months::new(count.checked_mul(12)?))?,
+                    _ => return None,
+                };
+                Some((start.succ_opt()?, today))
+            }
+            _ => None,
+        }
+    }
+
+    /// The calendar day, week, month or year containing `day`.
+    /// A year is the twelve months starting with the one that starts in January.
+    pub fn period(&self, unit: &str, day: NaiveDate) -> Option<(NaiveDate, NaiveDate)> {
+        let start = self.period_start(unit, day)?;
+        let end = match unit {
+            "day" => start,
+            "week" => start + Duration::days(6),
+            "month" => start.checked_add_months(Months::new(1))?.pred_opt()?,
+            _ => start.checked_add_months(Months::new(12))?.pred_opt()?,
+        };
+        Some((start, end))
+    }
+
+    /// Label of the trend bucket `day` falls in: `2024-01-15`, `2024-W03`, `2024-01` or `2024`,
+    /// after the week, month or year it belongs to starts
+    pub fn bucket(&self, group_by: &str, day: NaiveDate) -> String {
+        match group_by {
+            "day" => day.format("%Y-%m-%d").to_string(),
+            "week" => {
+                let iso_week = self.period_start("week", day).unwrap_or(day).iso_week();
+                format!("{}-W{:02}", iso_week.year(), iso_week.week())
+            }
+            "year" => self.period_start("year", day).unwrap_or(day).format("%Y").to_string(),
+            _ => self.period_start("month", day).unwrap_or(day).format("%Y-%m").to_string(),
+        }
+    }
+
+    fn period_start(&self, unit: &str, day: NaiveDate) -> Option<NaiveDate> {
+        match unit {
+            "day" => Some(day),
+            "week" => {
+                let offset = (day.weekday().num_days_from_monday() + 7 - self.week_start.num_days_from_monday()) % 7;
+                Some(day - Duration::days(offset as i64))
+            }
+            "month" => {
+                let start = day.with_day(self.month_start_day)?;
+                if start <= day {
+                    Some(start)
+                } else {
+                    start.checked_sub_months(Months::new(1))
+                }
+            }
+            "year" => {
+                let start = NaiveDate::from_ymd_opt(day.year(), 1, self.month_start_day)?;
+                if start <= day {
+                    Some(start)
+                } else {
+                    start.checked_sub_months(Months::new(12))
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    fn calendar(week_start: Weekday, month_start_day: u32) -> Calendar {
+        Calendar {
+            week_start,
+            month_start_day,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn periods_follow_the_calendar() {
+        let default = Calendar::default();
+        // 2026-10-18 is a Sunday
+        assert_eq!(default.period("week", date("2026-10-18")), Some((date("2026-10-12"), date("2026-10-18"))));
+        assert_eq!(default.period("month", date("2026-02-10")), Some((date("2026-02-01"), date("2026-02-28"))));
+        assert_eq!(default.period("year", date("2026-10-18")), Some((date("2026-01-01"), date("2026-12-31"))));
+        assert_eq!(default.period("decade", date("2026-10-18")), None);
+
+        let sunday = calendar(Weekday::Sun, 1);
+        assert_eq!(sunday.period("week", date("2026-10-18")), Some((date("2026-10-18"), date("2026-10-24"))));
+    }
+
+    #[test]
+    fn months_can_start_mid_month() {
+        let payday = calendar(Weekday::Mon, 25);
+        assert_eq!(payday.period("month", date("2026-10-18")), Some((date("2026-09-25"), date("2026-10-24"))));
+        assert_eq!(payday.period("month", date("2026-10-25")), Some((date("2026-10-25"), date("2026-11-24"))));
+        assert_eq!(payday.period("year", date("2026-01-10")), Some((date("2025-01-25"), date("2026-01-24"))));
+    }
+
+    #[test]
+    fn buckets_are_labelled_by_where_the_period_starts() {
+        let payday = calendar(Weekday::Sun, 25);
+        assert_eq!(payday.bucket("day", date("2026-10-18")), "2026-10-18");
+        assert_eq!(payday.bucket("month", date("2026-10-18")), "2026-09");
+        assert_eq!(payday.bucket("year", date("2026-01-10")), "2025");
+        // The week starting Sunday 2026-01-04 is in ISO week 1
+        assert_eq!(payday.bucket("week", date("2026-01-10")), "2026-W01");
+        assert_eq!(Calendar::default().bucket("week", date("2026-01-04")), "2026-W01");
+    }
+}
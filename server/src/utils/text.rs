@@ -0,0 +1,20 @@
+use jieba_rs::Jieba;
+use std::sync::OnceLock;
+
+/// Loading the dictionary takes a moment, so one segmenter is shared by the whole process
+fn jieba() -> &'static Jieba {
+    static JIEBA: OnceLock<Jieba> = OnceLock::new();
+    JIEBA.get_or_init(Jieba::new)
+}
+
+/// Splits free text (bill notes, search queries) into lowercase words.
+/// Chinese is segmented with jieba in search mode, so "午饭外卖" yields both the phrase and its parts;
+/// punctuation and whitespace are dropped.
+pub fn tokenize(text: &str) -> Vec<String> {
+    jieba()
+        .cut_for_search(text, true)
+        .into_iter()
+        .map(|word| word.trim().to_lowercase())
+        .filter(|word| word.chars().any(char::is_alphanumeric))
+        .collect()
+}